@@ -0,0 +1,68 @@
+#![allow(unstable_name_collisions)]
+#![cfg_attr(feature = "no-float", allow(unused_imports))]
+
+use core::hint::black_box;
+use criterion::{criterion_group, criterion_main, Criterion};
+#[cfg(not(feature = "no-float"))]
+use isqrt::floating_point_and_karatsuba::SignedIsqrt;
+use rand::distributions::Uniform;
+use rand::{thread_rng, Rng};
+
+// Index a table with exactly `MAX.isqrt() + 1` entries using the result of `checked_isqrt`. The `assume` hints in
+// `checked_isqrt` tell the optimizer that the index is in bounds, so the bounds check can be elided. Passing the result
+// through `black_box` hides those hints from the optimizer, which keeps the bounds check.
+#[cfg(not(feature = "no-float"))]
+pub fn criterion_benchmark(c: &mut Criterion) {
+    macro_rules! benches {
+        ($($signed_type:ty, $table_len:literal);+) => {
+            $(
+                {
+                    static TABLE: [u32; $table_len] = {
+                        let mut table = [0; $table_len];
+                        let mut i = 0;
+                        while i < table.len() {
+                            table[i] = (i * i) as u32;
+                            i += 1;
+                        }
+                        table
+                    };
+                    assert_eq!(
+                        SignedIsqrt::isqrt(<$signed_type>::MAX) as usize + 1,
+                        TABLE.len(),
+                        "The table should have one entry per possible square root."
+                    );
+
+                    let mut randoms = thread_rng().sample_iter::<$signed_type, Uniform<$signed_type>>(
+                        Uniform::new_inclusive(0, <$signed_type>::MAX),
+                    );
+                    c.bench_function(concat!("index_with_hints_", stringify!($signed_type)), |b| {
+                        b.iter(|| {
+                            let n = black_box(randoms.next().unwrap());
+                            black_box(SignedIsqrt::checked_isqrt(n).map(|sqrt| TABLE[sqrt as usize]))
+                        })
+                    });
+
+                    let mut randoms = thread_rng().sample_iter::<$signed_type, Uniform<$signed_type>>(
+                        Uniform::new_inclusive(0, <$signed_type>::MAX),
+                    );
+                    c.bench_function(concat!("index_without_hints_", stringify!($signed_type)), |b| {
+                        b.iter(|| {
+                            let n = black_box(randoms.next().unwrap());
+                            black_box(SignedIsqrt::checked_isqrt(n).map(|sqrt| TABLE[black_box(sqrt) as usize]))
+                        })
+                    });
+                }
+            )*
+        };
+    }
+
+    benches!(i8, 12; i16, 182; i32, 46_341);
+}
+
+#[cfg(not(feature = "no-float"))]
+criterion_group!(benches, criterion_benchmark);
+#[cfg(not(feature = "no-float"))]
+criterion_main!(benches);
+// `no-float` leaves out the modules benched here.
+#[cfg(feature = "no-float")]
+fn main() {}
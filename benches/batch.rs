@@ -0,0 +1,78 @@
+#![allow(unstable_name_collisions)]
+#![cfg_attr(feature = "no-float", allow(unused_imports))]
+
+use core::hint::black_box;
+use criterion::{criterion_group, criterion_main, Criterion};
+use rand::{thread_rng, Rng};
+
+// Compares a loop over the scalar `floating_point` method with the `batch` module for each width, on 1M-element slices
+// and on 4096-element ones, which fit in L1 cache, so the square roots themselves dominate. The short `u32` slices also
+// measure the AVX2 version. Run with `--features portable_simd` to measure the `core::simd` version.
+//
+// On the short slices, the `core::simd` versions of `u8` and `u16` are within noise of the scalar loop, and `u32` is
+// about 20% faster. `u64` is about 25% slower on baseline x86-64, which has no vector conversions between `u64` and
+// `f64`, but twice as fast with `RUSTFLAGS="-C target-cpu=native"` on a CPU with AVX-512.
+#[cfg(not(feature = "no-float"))]
+pub fn criterion_benchmark(c: &mut Criterion) {
+    const LEN: usize = 1 << 20;
+    const SHORT_LEN: usize = 4096;
+
+    macro_rules! benches {
+        ($($isqrt_slice:ident $type:ident),+) => {
+            $(
+                benches!(@group $isqrt_slice $type, LEN, concat!(stringify!($type), "_slice"));
+                benches!(@group $isqrt_slice $type, SHORT_LEN, concat!(stringify!($type), "_slice_4096"));
+            )*
+        };
+        (@group $isqrt_slice:ident $type:ident, $len:expr, $name:expr) => {
+            {
+                let src: Vec<$type> = (0..$len).map(|_| thread_rng().gen()).collect();
+                let mut dst = vec![0; $len];
+
+                let mut group = c.benchmark_group($name);
+                group.bench_function("scalar", |b| {
+                    use isqrt::floating_point::UnsignedIsqrt;
+
+                    b.iter(|| {
+                        for (&n, sqrt) in black_box(&src).iter().zip(&mut dst) {
+                            *sqrt = UnsignedIsqrt::isqrt(n);
+                        }
+                        black_box(&mut dst);
+                    })
+                });
+                group.bench_function("batch", |b| {
+                    b.iter(|| {
+                        isqrt::batch::$isqrt_slice(black_box(&src), &mut dst);
+                        black_box(&mut dst);
+                    })
+                });
+                group.finish();
+            }
+        };
+    }
+
+    benches!(isqrt_slice_u8 u8, isqrt_slice_u16 u16, isqrt_slice_u32 u32, isqrt_slice_u64 u64);
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        let src: Vec<u32> = (0..SHORT_LEN).map(|_| thread_rng().gen()).collect();
+        let mut dst = vec![0; SHORT_LEN];
+
+        let mut group = c.benchmark_group("u32_slice_4096");
+        group.bench_function("avx2", |b| {
+            b.iter(|| {
+                isqrt::batch::avx2::isqrt_slice_u32(black_box(&src), &mut dst);
+                black_box(&mut dst);
+            })
+        });
+        group.finish();
+    }
+}
+
+#[cfg(not(feature = "no-float"))]
+criterion_group!(benches, criterion_benchmark);
+#[cfg(not(feature = "no-float"))]
+criterion_main!(benches);
+// `no-float` leaves out the modules benched here.
+#[cfg(feature = "no-float")]
+fn main() {}
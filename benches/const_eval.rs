@@ -0,0 +1,90 @@
+#![cfg_attr(feature = "no-float", allow(unused_imports))]
+
+use core::hint::black_box;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+// Compares `floating_point_and_karatsuba`'s `const` functions evaluated at compile time against the same calls made at
+// runtime, for the same inputs, one group per width.
+//
+// Methodology:
+//
+// - The inputs are a fixed `const` array per width, spread over the whole range of the type, so both sides see exactly
+//   the same values.
+// - `const` computes every square root inside a `const { }` block, so `const_eval_select` picks the Karatsuba method and
+//   the work happens while compiling. What's measured is only reading the finished results, which is the floor that any
+//   runtime method is measured against.
+// - `runtime` passes each input through `black_box` before calling the same function, so the optimizer can't fold the
+//   call into a constant and `const_eval_select` picks the runtime method: floating point with the `nightly` feature,
+//   and Karatsuba without it.
+// - Both sides pass every result through `black_box`, so neither can be skipped.
+//
+// The difference between the two is the cost of computing the square roots at runtime. `nightly` is a default feature,
+// so comparing a default run against one with `--no-default-features --features std,algo-floating-karatsuba` shows what
+// the floating-point runtime method saves over running the Karatsuba method at runtime.
+#[cfg(not(feature = "no-float"))]
+pub fn criterion_benchmark(c: &mut Criterion) {
+    use isqrt::floating_point_and_karatsuba::{
+        isqrt_u128, isqrt_u16, isqrt_u32, isqrt_u64, isqrt_u8,
+    };
+
+    const INPUT_COUNT: usize = 64;
+
+    macro_rules! benches {
+        ($($type:ty, $isqrt:ident);+) => {
+            $(
+                {
+                    const INPUTS: [$type; INPUT_COUNT] = {
+                        let mut inputs = [0; INPUT_COUNT];
+                        let mut i = 0;
+                        while i < INPUT_COUNT {
+                            // Evenly spaced from zero up, ending at `MAX`.
+                            inputs[i] = (<$type>::MAX / (INPUT_COUNT as $type - 1)) * i as $type;
+                            i += 1;
+                        }
+                        inputs[INPUT_COUNT - 1] = <$type>::MAX;
+                        inputs
+                    };
+
+                    let mut group = c.benchmark_group(concat!("const_eval_", stringify!($type)));
+
+                    group.bench_function("const", |b| {
+                        b.iter(|| {
+                            let sqrts = const {
+                                let mut sqrts = [0; INPUT_COUNT];
+                                let mut i = 0;
+                                while i < INPUT_COUNT {
+                                    sqrts[i] = $isqrt(INPUTS[i]);
+                                    i += 1;
+                                }
+                                sqrts
+                            };
+                            for sqrt in sqrts {
+                                black_box(sqrt);
+                            }
+                        })
+                    });
+
+                    group.bench_function("runtime", |b| {
+                        b.iter(|| {
+                            for n in INPUTS {
+                                black_box($isqrt(black_box(n)));
+                            }
+                        })
+                    });
+
+                    group.finish();
+                }
+            )*
+        };
+    }
+
+    benches!(u8, isqrt_u8; u16, isqrt_u16; u32, isqrt_u32; u64, isqrt_u64; u128, isqrt_u128);
+}
+
+#[cfg(not(feature = "no-float"))]
+criterion_group!(benches, criterion_benchmark);
+#[cfg(not(feature = "no-float"))]
+criterion_main!(benches);
+
+#[cfg(feature = "no-float")]
+fn main() {}
@@ -26,44 +26,239 @@ pub fn criterion_benchmark(c: &mut Criterion) {
     let mut random_i128s = random_iter!(i128);
     let mut random_u128s = random_iter!(u128);
 
+    // Inputs where an approximate square root is most likely to be off by one and need correcting: perfect squares,
+    // values about halfway between consecutive perfect squares, and perfect squares minus one.
+    //
+    // As in `tests.rs`, this uses the fact that the perfect square after `r * r` is `r * r + 2 * r + 1`, so adding `r`
+    // once gets about halfway to it and adding `r` again gets to one less than it. The roots are random so that the
+    // inputs cover the whole range of the type.
+    macro_rules! edge_inputs {
+        ($type:ty, $root_bits:expr) => {{
+            let roots: Vec<$type> = thread_rng()
+                .sample_iter(Uniform::new_inclusive(0, ((1 as $type) << $root_bits) - 1))
+                .take(1024)
+                .collect();
+            [
+                (
+                    "perfect squares",
+                    roots.iter().map(|&r| r * r).collect::<Vec<$type>>(),
+                ),
+                (
+                    "halfway between squares",
+                    roots.iter().map(|&r| r * r + r).collect(),
+                ),
+                (
+                    "squares minus one",
+                    roots.iter().map(|&r| r * r + r + r).collect(),
+                ),
+            ]
+        }};
+    }
+
     macro_rules! benches {
         (@signed [ $($module:ident : $method_name:expr);+ ] $signed_type:ty, $signed_randoms:ident) => {
+            let mut group = c.benchmark_group(stringify!($signed_type));
             $(
-                c.bench_function(concat!($method_name, "_", stringify!($signed_type)), |b| {
+                group.bench_function($method_name, |b| {
                     use isqrt::$module::SignedIsqrt;
 
-                    b.iter(|| black_box(black_box($signed_randoms.next().unwrap()).checked_isqrt()))
+                    b.iter(|| black_box(SignedIsqrt::checked_isqrt(black_box($signed_randoms.next().unwrap()))))
                 });
             )*
+            group.finish();
         };
         (@unsigned [ $($module:ident : $method_name:expr);+ ] $unsigned_type:ty, $unsigned_randoms:ident) => {
+            let mut group = c.benchmark_group(stringify!($unsigned_type));
             $(
-                c.bench_function(concat!($method_name, "_", stringify!($unsigned_type)), |b| {
+                group.bench_function($method_name, |b| {
                     use isqrt::$module::UnsignedIsqrt;
 
-                    b.iter(|| black_box(black_box($unsigned_randoms.next().unwrap()).isqrt()))
+                    b.iter(|| black_box(UnsignedIsqrt::isqrt(black_box($unsigned_randoms.next().unwrap()))))
                 });
             )*
+            group.finish();
+        };
+        (@edge [ $($module:ident : $method_name:expr);+ ] $type:ty, $root_bits:expr, $Trait:ident::$isqrt:ident) => {
+            for (kind, inputs) in edge_inputs!($type, $root_bits) {
+                let mut group = c.benchmark_group(format!("{} {}", stringify!($type), kind));
+                $(
+                    group.bench_function($method_name, |b| {
+                        use isqrt::$module::$Trait;
+
+                        let mut inputs = inputs.iter().copied().cycle();
+                        b.iter(|| black_box($Trait::$isqrt(black_box(inputs.next().unwrap()))))
+                    });
+                )*
+                group.finish();
+            }
         };
         (@bit_size [ $($module:ident : $method_name:expr);+ ] $signed_type:ty, $signed_randoms:ident, $unsigned_type:ty, $unsigned_randoms:ident) => {
             benches!(@signed [$($module: $method_name);*] $signed_type, $signed_randoms);
             benches!(@unsigned [$($module: $method_name);*] $unsigned_type, $unsigned_randoms);
         };
+        // The standard library's inherent methods, added to the same groups as the modules so they're compared
+        // directly. Without a trait in scope, method-call syntax picks the inherent methods.
+        (@std $type:ty, $randoms:ident, $isqrt:ident) => {
+            let mut group = c.benchmark_group(stringify!($type));
+            group.bench_function("std", |b| b.iter(|| black_box(black_box($randoms.next().unwrap()).$isqrt())));
+            group.finish();
+        };
+        (@std_edge $type:ty, $root_bits:expr, $isqrt:ident) => {
+            for (kind, inputs) in edge_inputs!($type, $root_bits) {
+                let mut group = c.benchmark_group(format!("{} {}", stringify!($type), kind));
+                group.bench_function("std", |b| {
+                    let mut inputs = inputs.iter().copied().cycle();
+                    b.iter(|| black_box(black_box(inputs.next().unwrap()).$isqrt()))
+                });
+                group.finish();
+            }
+        };
+        (std) => {
+            benches!(@std i8, random_i8s, checked_isqrt);
+            benches!(@std u8, random_u8s, isqrt);
+            benches!(@std i16, random_i16s, checked_isqrt);
+            benches!(@std u16, random_u16s, isqrt);
+            benches!(@std i32, random_i32s, checked_isqrt);
+            benches!(@std u32, random_u32s, isqrt);
+            benches!(@std i64, random_i64s, checked_isqrt);
+            benches!(@std u64, random_u64s, isqrt);
+            benches!(@std i128, random_i128s, checked_isqrt);
+            benches!(@std u128, random_u128s, isqrt);
+
+            benches!(@std_edge i8, 3, checked_isqrt);
+            benches!(@std_edge u8, 4, isqrt);
+            benches!(@std_edge i16, 7, checked_isqrt);
+            benches!(@std_edge u16, 8, isqrt);
+            benches!(@std_edge i32, 15, checked_isqrt);
+            benches!(@std_edge u32, 16, isqrt);
+            benches!(@std_edge i64, 31, checked_isqrt);
+            benches!(@std_edge u64, 32, isqrt);
+            benches!(@std_edge i128, 63, checked_isqrt);
+            benches!(@std_edge u128, 64, isqrt);
+        };
         ([ $($module:ident : $method_name:expr);+ ]) => {
             benches!(@bit_size [$($module: $method_name);*] i8, random_i8s, u8, random_u8s);
             benches!(@bit_size [$($module: $method_name);*] i16, random_i16s, u16, random_u16s);
             benches!(@bit_size [$($module: $method_name);*] i32, random_i32s, u32, random_u32s);
             benches!(@bit_size [$($module: $method_name);*] i64, random_i64s, u64, random_u64s);
             benches!(@bit_size [$($module: $method_name);*] i128, random_i128s, u128, random_u128s);
+
+            // The roots are small enough that the square after the largest root's square still fits in the type.
+            benches!(@edge [$($module: $method_name);*] i8, 3, SignedIsqrt::checked_isqrt);
+            benches!(@edge [$($module: $method_name);*] u8, 4, UnsignedIsqrt::isqrt);
+            benches!(@edge [$($module: $method_name);*] i16, 7, SignedIsqrt::checked_isqrt);
+            benches!(@edge [$($module: $method_name);*] u16, 8, UnsignedIsqrt::isqrt);
+            benches!(@edge [$($module: $method_name);*] i32, 15, SignedIsqrt::checked_isqrt);
+            benches!(@edge [$($module: $method_name);*] u32, 16, UnsignedIsqrt::isqrt);
+            benches!(@edge [$($module: $method_name);*] i64, 31, SignedIsqrt::checked_isqrt);
+            benches!(@edge [$($module: $method_name);*] u64, 32, UnsignedIsqrt::isqrt);
+            benches!(@edge [$($module: $method_name);*] i128, 63, SignedIsqrt::checked_isqrt);
+            benches!(@edge [$($module: $method_name);*] u128, 64, UnsignedIsqrt::isqrt);
         };
     }
 
-    benches!([
-        original: "original";
-        floating_point: "floating";
-        floating_point_and_karatsuba: "floating+karatsuba";
-        karatsuba: "karatsuba";
-        karatsuba_2: "karatsuba_2"/*; table: "table"; libgmp: "libgmp"*/]);
+    // Each module is benched on its own, so that the ones whose features are off can be left out.
+    #[cfg(feature = "algo-original")]
+    benches!([original: "original"]);
+    #[cfg(all(feature = "algo-floating", not(feature = "no-float")))]
+    benches!([floating_point: "floating"]);
+    #[cfg(all(feature = "algo-floating-karatsuba", not(feature = "no-float")))]
+    benches!([floating_point_and_karatsuba: "floating+karatsuba"]);
+    benches!([heron: "heron"]);
+    #[cfg(feature = "algo-karatsuba")]
+    benches!([karatsuba: "karatsuba"]);
+    #[cfg(feature = "algo-karatsuba-2")]
+    benches!([karatsuba_2: "karatsuba_2"]);
+    benches!([newton: "newton"]);
+    benches!([table: "table"]);
+    benches!(std);
+
+    #[cfg(all(feature = "algo-floating", not(feature = "no-float")))]
+    {
+        // The branchless variant of the floating-point `u64` method against the usual one. The inputs are above 2^52, where
+        // the usual method has to correct its result, and randomly either perfect squares, perfect squares minus one, or
+        // one less than the next perfect square, so that its corrections can't be predicted.
+        let mixed_near_squares: Vec<u64> = thread_rng()
+            .sample_iter(Uniform::new_inclusive(1_u64 << 26, u32::MAX as u64))
+            .take(1024)
+            .map(|r| match thread_rng().gen_range(0..3) {
+                0 => r * r,
+                1 => r * r - 1,
+                _ => r * r + r + r,
+            })
+            .collect();
+        let mut group = c.benchmark_group("u64 mixed near squares");
+        group.bench_function("floating", |b| {
+            use isqrt::floating_point::UnsignedIsqrt;
+
+            let mut inputs = mixed_near_squares.iter().copied().cycle();
+            b.iter(|| black_box(UnsignedIsqrt::isqrt(black_box(inputs.next().unwrap()))))
+        });
+        group.bench_function("floating branchless", |b| {
+            let mut inputs = mixed_near_squares.iter().copied().cycle();
+            b.iter(|| {
+                black_box(isqrt::floating_point::isqrt_u64_branchless(black_box(
+                    inputs.next().unwrap(),
+                )))
+            })
+        });
+        group.finish();
+
+        // Inputs below 2^52, where the floating-point `u64` method converts exactly and skips its correction.
+        let small_u64s: Vec<u64> = thread_rng()
+            .sample_iter(Uniform::new(0, 1_u64 << 52))
+            .take(1024)
+            .collect();
+        let mut group = c.benchmark_group("u64 below 2^52");
+        group.bench_function("floating", |b| {
+            use isqrt::floating_point::UnsignedIsqrt;
+
+            let mut inputs = small_u64s.iter().copied().cycle();
+            b.iter(|| black_box(UnsignedIsqrt::isqrt(black_box(inputs.next().unwrap()))))
+        });
+        group.bench_function("floating branchless", |b| {
+            let mut inputs = small_u64s.iter().copied().cycle();
+            b.iter(|| {
+                black_box(isqrt::floating_point::isqrt_u64_branchless(black_box(
+                    inputs.next().unwrap(),
+                )))
+            })
+        });
+        group.finish();
+
+        // The Newton variant of the floating-point `u128` method against the usual one and `karatsuba`, on inputs of at
+        // least 2^64, where it takes its Newton step.
+        let large_u128s: Vec<u128> = thread_rng()
+            .sample_iter(Uniform::new_inclusive(1_u128 << 64, u128::MAX))
+            .take(1024)
+            .collect();
+        let mut group = c.benchmark_group("u128 at least 2^64");
+        group.bench_function("floating", |b| {
+            use isqrt::floating_point::UnsignedIsqrt;
+
+            let mut inputs = large_u128s.iter().copied().cycle();
+            b.iter(|| black_box(UnsignedIsqrt::isqrt(black_box(inputs.next().unwrap()))))
+        });
+        group.bench_function("floating newton", |b| {
+            let mut inputs = large_u128s.iter().copied().cycle();
+            b.iter(|| {
+                black_box(isqrt::floating_point::isqrt_u128_newton(black_box(
+                    inputs.next().unwrap(),
+                )))
+            })
+        });
+        group.bench_function("karatsuba", |b| {
+            use isqrt::karatsuba::UnsignedIsqrt;
+
+            let mut inputs = large_u128s.iter().copied().cycle();
+            b.iter(|| black_box(UnsignedIsqrt::isqrt(black_box(inputs.next().unwrap()))))
+        });
+        group.finish();
+    }
+
+    // GMP is only a reference point, and only linked when it's installed, so it's benched on its own.
+    #[cfg(feature = "libgmp")]
+    benches!([libgmp: "libgmp"]);
 }
 
 criterion_group!(benches, criterion_benchmark);
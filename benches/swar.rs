@@ -0,0 +1,59 @@
+#![allow(unstable_name_collisions)]
+
+use core::hint::black_box;
+use criterion::{criterion_group, criterion_main, Criterion};
+use rand::distributions::Uniform;
+use rand::{thread_rng, Rng};
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let mut random_u64s =
+        thread_rng().sample_iter::<u64, Uniform<u64>>(Uniform::new_inclusive(u64::MIN, u64::MAX));
+
+    c.bench_function("swar_two_u32", |b| {
+        b.iter(|| {
+            black_box(isqrt::swar::isqrt_two_u32(black_box(
+                random_u64s.next().unwrap(),
+            )))
+        })
+    });
+
+    c.bench_function("swar_u8x4", |b| {
+        b.iter(|| {
+            black_box(isqrt::swar::isqrt_u8x4(black_box(
+                random_u64s.next().unwrap() as u32,
+            )))
+        })
+    });
+
+    c.bench_function("separate_u8x4", |b| {
+        b.iter(|| {
+            let bytes = black_box(random_u64s.next().unwrap() as u32).to_le_bytes();
+            black_box(u32::from_le_bytes(bytes.map(u8::isqrt)))
+        })
+    });
+
+    macro_rules! separate_benches {
+        ($($module:ident : $method_name:expr);+) => {
+            $(
+                c.bench_function(concat!($method_name, "_two_u32"), |b| {
+                    use isqrt::$module::UnsignedIsqrt;
+
+                    b.iter(|| {
+                        let packed = black_box(random_u64s.next().unwrap());
+                        let hi = UnsignedIsqrt::isqrt((packed >> 32) as u32) as u64;
+                        let lo = UnsignedIsqrt::isqrt(packed as u32) as u64;
+                        black_box((hi << 32) | lo)
+                    })
+                });
+            )*
+        };
+    }
+
+    separate_benches!(original: "original");
+    #[cfg(not(feature = "no-float"))]
+    separate_benches!(floating_point: "floating");
+    separate_benches!(karatsuba: "karatsuba");
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,68 @@
+#![allow(unstable_name_collisions)]
+
+use core::hint::black_box;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use rand::distributions::Uniform;
+use rand::{thread_rng, Rng};
+
+// Bigger than the L1 data cache of current desktop and server CPUs, so walking it evicts the lookup tables.
+const THRASH_BYTES: usize = 256 * 1024;
+const CACHE_LINE_BYTES: usize = 64;
+
+fn thrash(buffer: &mut [u8]) {
+    for line in buffer.chunks_mut(CACHE_LINE_BYTES) {
+        line[0] = line[0].wrapping_add(1);
+    }
+    black_box(buffer);
+}
+
+// Compares each module's `u64` square root with warm caches against the same call right after evicting the L1 cache.
+// The eviction happens in the untimed setup of each iteration, so the amount by which `cold` exceeds `warm` shows how
+// sensitive each module is to its lookup table missing in L1. Both are timed one call at a time, so they include the
+// same timer overhead.
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let mut random_u64s =
+        thread_rng().sample_iter::<u64, Uniform<u64>>(Uniform::new_inclusive(u64::MIN, u64::MAX));
+    let mut buffer = vec![0_u8; THRASH_BYTES];
+
+    macro_rules! benches {
+        ($($module:ident : $method_name:expr);+) => {
+            $(
+                let mut group = c.benchmark_group($method_name);
+                group.bench_function("warm", |b| {
+                    use isqrt::$module::UnsignedIsqrt;
+
+                    b.iter_batched(
+                        || random_u64s.next().unwrap(),
+                        |n| black_box(UnsignedIsqrt::isqrt(black_box(n))),
+                        BatchSize::PerIteration,
+                    )
+                });
+                group.bench_function("cold", |b| {
+                    use isqrt::$module::UnsignedIsqrt;
+
+                    b.iter_batched(
+                        || {
+                            thrash(&mut buffer);
+                            random_u64s.next().unwrap()
+                        },
+                        |n| black_box(UnsignedIsqrt::isqrt(black_box(n))),
+                        BatchSize::PerIteration,
+                    )
+                });
+                group.finish();
+            )*
+        };
+    }
+
+    #[cfg(not(feature = "no-float"))]
+    benches!(
+        floating_point: "floating";
+        floating_point_and_karatsuba: "floating+karatsuba");
+    benches!(
+        karatsuba: "karatsuba";
+        karatsuba_2: "karatsuba_2");
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
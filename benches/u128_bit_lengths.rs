@@ -0,0 +1,53 @@
+#![allow(unstable_name_collisions)]
+
+use core::hint::black_box;
+use criterion::{criterion_group, criterion_main, Criterion};
+use rand::{thread_rng, Rng};
+
+// Compares the modules on `u128` inputs with a fixed number of significant bits, to find out whether the short loop of
+// `original` ever beats Karatsuba for small values, and on a mix of bit lengths.
+pub fn criterion_benchmark(c: &mut Criterion) {
+    const SAMPLES: usize = 1024;
+
+    let mut rng = thread_rng();
+    let mut random_with_bits =
+        |bits: u32| -> u128 { (rng.gen::<u128>() >> (128 - bits)) | (1 << (bits - 1)) };
+
+    let mut inputs: Vec<(String, Vec<u128>)> = (8..=128)
+        .step_by(8)
+        .map(|bits| {
+            (
+                format!("u128_{bits}_bits"),
+                (0..SAMPLES).map(|_| random_with_bits(bits)).collect(),
+            )
+        })
+        .collect();
+    let mixed = (0..SAMPLES)
+        .map(|_| random_with_bits(thread_rng().gen_range(1..=128)))
+        .collect();
+    inputs.push(("u128_mixed_bits".to_string(), mixed));
+
+    macro_rules! benches {
+        ($($module:ident : $method_name:expr);+) => {
+            for (group_name, values) in &inputs {
+                let mut group = c.benchmark_group(group_name);
+                $(
+                    group.bench_function($method_name, |b| {
+                        use isqrt::$module::UnsignedIsqrt;
+
+                        let mut values = values.iter().cycle();
+                        b.iter(|| black_box(UnsignedIsqrt::isqrt(black_box(*values.next().unwrap()))))
+                    });
+                )*
+                group.finish();
+            }
+        };
+    }
+
+    benches!(
+        original: "original";
+        karatsuba: "karatsuba");
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,44 @@
+//! Chooses the modules that `default` forwards to for the target being built for, and passes the choice to the crate as
+//! `cfg` flags. See `src/default.rs` for the decision matrix.
+
+use std::env;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(isqrt_default_floating)");
+    println!("cargo:rustc-check-cfg=cfg(isqrt_default_floating_usize)");
+
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    let features = env::var("CARGO_CFG_TARGET_FEATURE").unwrap_or_default();
+    let has_feature = |feature: &str| features.split(',').any(|enabled| enabled == feature);
+    let pointer_width: u32 = env::var("CARGO_CFG_TARGET_POINTER_WIDTH")
+        .ok()
+        .and_then(|width| width.parse().ok())
+        .unwrap_or(64);
+
+    // Whether the target has `f64` square roots in hardware. Emulated ones are far slower than `karatsuba`, so targets
+    // not listed here are assumed not to. x87 is left out, as its extended precision breaks the rounding that the
+    // floating-point method relies on.
+    let hardware_f64 = match arch.as_str() {
+        "x86" | "x86_64" => has_feature("sse2"),
+        "aarch64" | "arm64ec" => has_feature("neon"),
+        // `vfp2` is the first VFP version with double precision. Cortex-M4F and similar only have single precision.
+        "arm" => has_feature("vfp2"),
+        "riscv32" | "riscv64" | "loongarch64" => has_feature("d"),
+        "wasm32" | "wasm64" => true,
+        _ => false,
+    };
+
+    let floating_enabled = env::var_os("CARGO_FEATURE_ALGO_FLOATING").is_some()
+        && env::var_os("CARGO_FEATURE_NO_FLOAT").is_none()
+        && env::var_os("CARGO_FEATURE_WASM_NO_FLOAT").is_none();
+
+    if floating_enabled && hardware_f64 {
+        println!("cargo:rustc-cfg=isqrt_default_floating");
+
+        // A 16-bit `usize` is in the range where `karatsuba`'s table lookup wins.
+        if pointer_width >= 32 {
+            println!("cargo:rustc-cfg=isqrt_default_floating_usize");
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! Checks that the modules agree on the square root of the `u128` decoded from each input, along with the square
+//! roots of its low halves, so that divergences in any width's correction branches or Karatsuba steps abort the fuzzer.
+//!
+//! Run with `cargo fuzz run crosscheck` on a nightly compiler.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+macro_rules! crosscheck {
+    ($n:expr; $first_module:ident $(, $module:ident)+) => {{
+        let n = $n;
+        let first_result = isqrt::$first_module::UnsignedIsqrt::isqrt(n);
+        $(
+            let result = isqrt::$module::UnsignedIsqrt::isqrt(n);
+            assert_eq!(
+                result,
+                first_result,
+                "`{}` and `{}` disagree on the square root of {n}.",
+                stringify!($module),
+                stringify!($first_module)
+            );
+        )+
+    }};
+}
+
+fuzz_target!(|data: &[u8]| {
+    // Decode the first 16 bytes as a little-endian `u128`, padding short inputs with zeros.
+    let mut bytes = [0; 16];
+    let len = data.len().min(bytes.len());
+    bytes[..len].copy_from_slice(&data[..len]);
+    let n = u128::from_le_bytes(bytes);
+
+    crosscheck!(n; original, floating_point, karatsuba, karatsuba_2, floating_point_and_karatsuba);
+    crosscheck!(n as u64; original, floating_point, karatsuba, karatsuba_2, floating_point_and_karatsuba);
+    crosscheck!(n as u32; original, floating_point, karatsuba, karatsuba_2, floating_point_and_karatsuba);
+    crosscheck!(n as u16; original, floating_point, karatsuba, karatsuba_2, floating_point_and_karatsuba);
+    crosscheck!(n as u8; original, floating_point, karatsuba, karatsuba_2, floating_point_and_karatsuba);
+});
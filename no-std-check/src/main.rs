@@ -0,0 +1,17 @@
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
+
+#[no_mangle]
+pub extern "C" fn isqrt_of_42() -> u64 {
+    isqrt::karatsuba::UnsignedIsqrt::isqrt(core::hint::black_box(42_u64))
+}
+
+#[no_mangle]
+pub extern "C" fn floating_isqrt_of_42() -> u64 {
+    isqrt::floating_point::UnsignedIsqrt::isqrt(core::hint::black_box(42_u64))
+}
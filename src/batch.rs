@@ -0,0 +1,450 @@
+//! Integer square roots of whole slices, using the floating-point method.
+//!
+//! With the `portable_simd` feature, the square roots are taken several at a time with `core::simd`. Otherwise, and for
+//! the elements left over at the end of a slice, they're taken one at a time. `isqrt_slice` takes a slice of any of the
+//! types, and `isqrt_slice_u8` through `isqrt_slice_u64` take one type each. `isqrt_in_place` and `isqrt_in_place_u8`
+//! through `isqrt_in_place_u64` overwrite each element with its square root instead of writing to a second slice.
+//!
+//! The `u64` vectors need conversions between `u64` and `f64` lanes, which x86-64 only has with AVX-512, so build with a
+//! `target-cpu` that has it before reaching for `isqrt_slice_u64` there.
+//!
+//! `u32` slices also have versions written with intrinsics, which work on stable Rust: `avx2` on x86-64, which checks
+//! for AVX2 at runtime, and `neon` on AArch64. `isqrt_slice_u32` uses whichever one the target has before anything else.
+
+#[cfg(feature = "portable_simd")]
+use core::simd::{
+    cmp::SimdOrd, cmp::SimdPartialOrd, num::SimdFloat, num::SimdUint, Select, Simd, SimdElement,
+};
+#[cfg(feature = "portable_simd")]
+use std::simd::StdFloat;
+
+use crate::floating_point::UnsignedIsqrt;
+
+/// Integer types whose slices `isqrt_slice` can take the square roots of.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+pub trait SimdIsqrt: Sized + crate::sealed::Sealed {
+    /// Writes the integer square root of each element of `src` to the same position in `dst`.
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    fn isqrt_slice(src: &[Self], dst: &mut [Self]);
+
+    /// Overwrites each element of `slice` with its integer square root.
+    fn isqrt_in_place(slice: &mut [Self]);
+}
+
+/// Writes the integer square root of each element of `src` to the same position in `dst`.
+///
+/// Panics if `src` and `dst` have different lengths.
+///
+/// ```
+/// let mut roots = [0_u16; 3];
+/// isqrt::batch::isqrt_slice(&[16, 17, 65_535], &mut roots);
+/// assert_eq!(roots, [4, 4, 255]);
+/// ```
+#[inline]
+pub fn isqrt_slice<T: SimdIsqrt>(src: &[T], dst: &mut [T]) {
+    T::isqrt_slice(src, dst);
+}
+
+/// Overwrites each element of `slice` with its integer square root.
+///
+/// ```
+/// let mut numbers = [16_u32, 17, 4_294_967_295];
+/// isqrt::batch::isqrt_in_place(&mut numbers);
+/// assert_eq!(numbers, [4, 4, 65_535]);
+/// ```
+#[inline]
+pub fn isqrt_in_place<T: SimdIsqrt>(slice: &mut [T]) {
+    T::isqrt_in_place(slice);
+}
+
+/// Writes the integer square root of each element of `src` to the same position in `dst`, or returns an error without
+/// writing anything if `src` and `dst` have different lengths.
+///
+/// ```
+/// use isqrt::batch::{try_isqrt_slice, SliceLenMismatch};
+///
+/// let mut roots = [0_u32; 2];
+/// assert_eq!(try_isqrt_slice(&[16, 17], &mut roots), Ok(()));
+/// assert_eq!(roots, [4, 4]);
+///
+/// let error = try_isqrt_slice(&[16, 17, 18], &mut roots).unwrap_err();
+/// assert_eq!(error, SliceLenMismatch { src_len: 3, dst_len: 2 });
+/// ```
+#[inline]
+pub fn try_isqrt_slice<T: SimdIsqrt>(src: &[T], dst: &mut [T]) -> Result<(), SliceLenMismatch> {
+    check_lengths(src, dst)?;
+    T::isqrt_slice(src, dst);
+    Ok(())
+}
+
+/// The error returned by [`try_isqrt_slice`] when the source and destination slices have different lengths.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SliceLenMismatch {
+    /// The length of the source slice.
+    pub src_len: usize,
+    /// The length of the destination slice.
+    pub dst_len: usize,
+}
+
+impl core::fmt::Display for SliceLenMismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "source and destination of batch integer square root must have the same length, but have lengths {} and {}",
+            self.src_len, self.dst_len
+        )
+    }
+}
+
+impl core::error::Error for SliceLenMismatch {}
+
+fn check_lengths<T>(src: &[T], dst: &[T]) -> Result<(), SliceLenMismatch> {
+    if src.len() == dst.len() {
+        Ok(())
+    } else {
+        Err(SliceLenMismatch {
+            src_len: src.len(),
+            dst_len: dst.len(),
+        })
+    }
+}
+
+/// Takes the square roots of as many whole vectors of `src` as possible, returning the leftover elements.
+#[cfg(feature = "portable_simd")]
+#[inline(always)]
+fn isqrt_vectors<'src, 'dst, T: SimdElement, const LANES: usize>(
+    src: &'src [T],
+    dst: &'dst mut [T],
+    isqrt: impl Fn(Simd<T, LANES>) -> Simd<T, LANES>,
+) -> (&'src [T], &'dst mut [T]) {
+    let mut src_vectors = src.chunks_exact(LANES);
+    let mut dst_vectors = dst.chunks_exact_mut(LANES);
+    for (n, sqrt) in (&mut src_vectors).zip(&mut dst_vectors) {
+        isqrt(Simd::from_slice(n)).copy_to_slice(sqrt);
+    }
+
+    (src_vectors.remainder(), dst_vectors.into_remainder())
+}
+
+/// Overwrites as many whole vectors of `slice` as possible with their square roots, returning the leftover elements.
+#[cfg(feature = "portable_simd")]
+#[inline(always)]
+fn isqrt_vectors_in_place<T: SimdElement, const LANES: usize>(
+    slice: &mut [T],
+    isqrt: impl Fn(Simd<T, LANES>) -> Simd<T, LANES>,
+) -> &mut [T] {
+    let mut vectors = slice.chunks_exact_mut(LANES);
+    for n in &mut vectors {
+        isqrt(Simd::from_slice(n)).copy_to_slice(n);
+    }
+
+    vectors.into_remainder()
+}
+
+macro_rules! isqrt_slice {
+    ($isqrt_slice:ident, $isqrt_in_place:ident, $type:ty, $lanes:literal, $vector_isqrt:expr $(, $arch:ident)?) => {
+        /// Writes the integer square root of each element of `src` to the same position in `dst`.
+        ///
+        /// Panics if `src` and `dst` have different lengths.
+        pub fn $isqrt_slice(src: &[$type], dst: &mut [$type]) {
+            if let Err(error) = check_lengths(src, dst) {
+                panic!("{error}");
+            }
+
+            $(
+                #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+                let (src, dst) = $arch::isqrt_vectors(src, dst);
+            )?
+
+            #[cfg(feature = "portable_simd")]
+            let (src, dst) = isqrt_vectors::<_, $lanes>(src, dst, $vector_isqrt);
+
+            for (&n, sqrt) in src.iter().zip(dst) {
+                *sqrt = UnsignedIsqrt::isqrt(n);
+            }
+        }
+
+        /// Overwrites each element of `slice` with its integer square root.
+        pub fn $isqrt_in_place(slice: &mut [$type]) {
+            $(
+                #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+                let slice = $arch::isqrt_vectors_in_place(slice);
+            )?
+
+            #[cfg(feature = "portable_simd")]
+            let slice = isqrt_vectors_in_place::<_, $lanes>(slice, $vector_isqrt);
+
+            for n in slice {
+                *n = UnsignedIsqrt::isqrt(*n);
+            }
+        }
+
+        impl SimdIsqrt for $type {
+            #[inline]
+            fn isqrt_slice(src: &[Self], dst: &mut [Self]) {
+                $isqrt_slice(src, dst);
+            }
+
+            #[inline]
+            fn isqrt_in_place(slice: &mut [Self]) {
+                $isqrt_in_place(slice);
+            }
+        }
+    };
+}
+
+// Every `u8` and `u16` is exact in `f32`, and every `u32` is exact in `f64`. Flooring the correctly rounded square
+// root of an exact integer gives its integer square root.
+//
+// The lane counts were the fastest of 4 through 32 in `batch`'s benchmarks on x86-64, where 16 `f32` lanes fill four
+// SSE registers. `u64` was within noise at 4 and 8 lanes, and `u32` slices mostly go through `arch` first anyway.
+//
+// SAFETY: the square roots are finite and in range.
+isqrt_slice!(isqrt_slice_u8, isqrt_in_place_u8, u8, 16, |n| unsafe {
+    n.cast::<f32>().sqrt().to_int_unchecked()
+});
+isqrt_slice!(isqrt_slice_u16, isqrt_in_place_u16, u16, 16, |n| unsafe {
+    n.cast::<f32>().sqrt().to_int_unchecked()
+});
+isqrt_slice!(
+    isqrt_slice_u32,
+    isqrt_in_place_u32,
+    u32,
+    8,
+    |n| unsafe { n.cast::<f64>().sqrt().to_int_unchecked() },
+    arch
+);
+
+// Large `u64`s aren't exact in `f64`, so, as in `floating_point`, the floored square root can be off by one either way
+// and is corrected in each lane.
+isqrt_slice!(isqrt_slice_u64, isqrt_in_place_u64, u64, 8, |n: Simd<
+    u64,
+    8,
+>| {
+    // Clamping keeps `sqrt * sqrt` from overflowing. It only changes a `2^32` that should be `2^32 - 1` anyway.
+    //
+    // SAFETY: the square root is finite and in range.
+    let sqrt = unsafe { n.cast::<f64>().sqrt().to_int_unchecked::<u64>() }
+        .simd_min(Simd::splat(u32::MAX as u64));
+
+    let sqrt = (sqrt * sqrt).simd_gt(n).select(sqrt - Simd::splat(1), sqrt);
+
+    // Now `sqrt * sqrt <= n`, so check whether `sqrt + 1` fits by comparing the remainder with `2 * sqrt`, which
+    // can't overflow like `(sqrt + 1)^2` can.
+    let remainder = n - sqrt * sqrt;
+    remainder
+        .simd_gt(sqrt + sqrt)
+        .select(sqrt + Simd::splat(1), sqrt)
+});
+
+#[cfg(target_arch = "x86_64")]
+use avx2 as arch;
+#[cfg(target_arch = "aarch64")]
+use neon as arch;
+
+/// The elements of a source and destination slice left over after taking the square roots of whole vectors.
+#[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+type Leftovers<'src, 'dst> = (&'src [u32], &'dst mut [u32]);
+
+/// Writes the integer square root of each element of `src` to the same position in `dst`, after taking as many as
+/// possible with `isqrt_vectors`.
+///
+/// Panics if `src` and `dst` have different lengths.
+#[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+fn isqrt_slice_u32_with<'src, 'dst>(
+    src: &'src [u32],
+    dst: &'dst mut [u32],
+    isqrt_vectors: fn(&'src [u32], &'dst mut [u32]) -> Leftovers<'src, 'dst>,
+) {
+    if let Err(error) = check_lengths(src, dst) {
+        panic!("{error}");
+    }
+
+    let (src, dst) = isqrt_vectors(src, dst);
+    for (&n, sqrt) in src.iter().zip(dst) {
+        *sqrt = UnsignedIsqrt::isqrt(n);
+    }
+}
+
+/// Overwrites each element of `slice` with its integer square root, after doing as many as possible with
+/// `isqrt_vectors_in_place`.
+#[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+fn isqrt_in_place_u32_with(
+    slice: &mut [u32],
+    isqrt_vectors_in_place: fn(&mut [u32]) -> &mut [u32],
+) {
+    for n in isqrt_vectors_in_place(slice) {
+        *n = UnsignedIsqrt::isqrt(*n);
+    }
+}
+
+/// Integer square roots of `u32` slices with AVX2, eight at a time, chosen at runtime.
+#[cfg(target_arch = "x86_64")]
+pub mod avx2 {
+    use core::arch::x86_64::{
+        __m128i, _mm256_add_pd, _mm256_cvtepi32_pd, _mm256_cvttpd_epi32, _mm256_set1_pd,
+        _mm256_sqrt_pd, _mm_loadu_si128, _mm_set1_epi32, _mm_storeu_si128, _mm_xor_si128,
+    };
+
+    const LANES: usize = 8;
+
+    /// Writes the integer square root of each element of `src` to the same position in `dst`.
+    ///
+    /// If the CPU supports AVX2, each eight elements are converted to `f64` in two halves of four, square rooted with
+    /// `vsqrtpd`, and truncated back. As in the scalar `floating_point` method, every `u32` is exact in `f64`, so no
+    /// correction is needed. Otherwise, and for the elements left over at the end, this uses the scalar method.
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    pub fn isqrt_slice_u32(src: &[u32], dst: &mut [u32]) {
+        super::isqrt_slice_u32_with(src, dst, isqrt_vectors);
+    }
+
+    /// Overwrites each element of `slice` with its integer square root, in the same way as `isqrt_slice_u32`.
+    pub fn isqrt_in_place_u32(slice: &mut [u32]) {
+        super::isqrt_in_place_u32_with(slice, isqrt_vectors_in_place);
+    }
+
+    /// Takes the square roots of as many whole vectors of `src` as possible if the CPU supports AVX2, returning the
+    /// leftover elements.
+    pub(super) fn isqrt_vectors<'src, 'dst>(
+        src: &'src [u32],
+        dst: &'dst mut [u32],
+    ) -> super::Leftovers<'src, 'dst> {
+        let done = if std::is_x86_feature_detected!("avx2") {
+            // SAFETY: AVX2 is available, and the slices have the same length.
+            unsafe { isqrt_avx2_vectors(src.as_ptr(), dst.as_mut_ptr(), src.len()) }
+        } else {
+            0
+        };
+
+        (&src[done..], &mut dst[done..])
+    }
+
+    /// Overwrites as many whole vectors of `slice` as possible with their square roots if the CPU supports AVX2,
+    /// returning the leftover elements.
+    pub(super) fn isqrt_vectors_in_place(slice: &mut [u32]) -> &mut [u32] {
+        let done = if std::is_x86_feature_detected!("avx2") {
+            let n = slice.as_mut_ptr();
+            // SAFETY: AVX2 is available, and each vector is loaded before it's stored over.
+            unsafe { isqrt_avx2_vectors(n, n, slice.len()) }
+        } else {
+            0
+        };
+
+        &mut slice[done..]
+    }
+
+    /// Takes the square roots of as many whole vectors of the `len` elements at `src` as possible, writing them to
+    /// `dst` and returning how many elements that was. `src` and `dst` may be the same.
+    ///
+    /// # Safety
+    ///
+    /// The CPU must support AVX2, `src` must be valid for reading `len` elements, and `dst` must be valid for writing
+    /// `len` elements.
+    #[target_feature(enable = "avx2")]
+    unsafe fn isqrt_avx2_vectors(src: *const u32, dst: *mut u32, len: usize) -> usize {
+        // `vcvtdq2pd` converts signed integers, so flip the top bit to subtract 2^31, then add it back as an `f64`.
+        let sign_bit = _mm_set1_epi32(i32::MIN);
+        let two_to_the_31 = _mm256_set1_pd(2_147_483_648.0);
+
+        let vectors = len / LANES;
+        for i in 0..vectors {
+            for half in [i * LANES, i * LANES + LANES / 2] {
+                // SAFETY: `half + 4 <= vectors * LANES`, which is within both buffers.
+                let n = _mm_loadu_si128(src.add(half).cast::<__m128i>());
+                let n = _mm256_add_pd(
+                    _mm256_cvtepi32_pd(_mm_xor_si128(n, sign_bit)),
+                    two_to_the_31,
+                );
+                // The square roots are below 2^16, so they're in `i32` range.
+                let sqrt = _mm256_cvttpd_epi32(_mm256_sqrt_pd(n));
+                _mm_storeu_si128(dst.add(half).cast::<__m128i>(), sqrt);
+            }
+        }
+
+        vectors * LANES
+    }
+}
+
+/// Integer square roots of `u32` slices with NEON, four at a time.
+#[cfg(target_arch = "aarch64")]
+pub mod neon {
+    use core::arch::aarch64::{
+        vcvtq_f64_u64, vcvtq_u64_f64, vget_low_u32, vld1q_u32, vmovl_high_u32, vmovl_u32,
+        vmovn_high_u64, vmovn_u64, vsqrtq_f64, vst1q_u32,
+    };
+
+    const LANES: usize = 4;
+
+    /// Writes the integer square root of each element of `src` to the same position in `dst`.
+    ///
+    /// If the CPU supports NEON, which every AArch64 Linux system does, each four elements are widened to `u64`,
+    /// converted to `f64` in two pairs, square rooted with `fsqrt`, and truncated back. As in the scalar
+    /// `floating_point` method, every `u32` is exact in `f64`, so no correction is needed. Otherwise, and for the
+    /// elements left over at the end, this uses the scalar method.
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    pub fn isqrt_slice_u32(src: &[u32], dst: &mut [u32]) {
+        super::isqrt_slice_u32_with(src, dst, isqrt_vectors);
+    }
+
+    /// Overwrites each element of `slice` with its integer square root, in the same way as `isqrt_slice_u32`.
+    pub fn isqrt_in_place_u32(slice: &mut [u32]) {
+        super::isqrt_in_place_u32_with(slice, isqrt_vectors_in_place);
+    }
+
+    /// Takes the square roots of as many whole vectors of `src` as possible if the CPU supports NEON, returning the
+    /// leftover elements.
+    pub(super) fn isqrt_vectors<'src, 'dst>(
+        src: &'src [u32],
+        dst: &'dst mut [u32],
+    ) -> super::Leftovers<'src, 'dst> {
+        let done = if std::arch::is_aarch64_feature_detected!("neon") {
+            // SAFETY: NEON is available, and the slices have the same length.
+            unsafe { isqrt_neon_vectors(src.as_ptr(), dst.as_mut_ptr(), src.len()) }
+        } else {
+            0
+        };
+
+        (&src[done..], &mut dst[done..])
+    }
+
+    /// Overwrites as many whole vectors of `slice` as possible with their square roots if the CPU supports NEON,
+    /// returning the leftover elements.
+    pub(super) fn isqrt_vectors_in_place(slice: &mut [u32]) -> &mut [u32] {
+        let done = if std::arch::is_aarch64_feature_detected!("neon") {
+            let n = slice.as_mut_ptr();
+            // SAFETY: NEON is available, and each vector is loaded before it's stored over.
+            unsafe { isqrt_neon_vectors(n, n, slice.len()) }
+        } else {
+            0
+        };
+
+        &mut slice[done..]
+    }
+
+    /// Takes the square roots of as many whole vectors of the `len` elements at `src` as possible, writing them to
+    /// `dst` and returning how many elements that was. `src` and `dst` may be the same.
+    ///
+    /// # Safety
+    ///
+    /// The CPU must support NEON, `src` must be valid for reading `len` elements, and `dst` must be valid for writing
+    /// `len` elements.
+    #[target_feature(enable = "neon")]
+    unsafe fn isqrt_neon_vectors(src: *const u32, dst: *mut u32, len: usize) -> usize {
+        let vectors = len / LANES;
+        for i in 0..vectors {
+            // SAFETY: `i * LANES + 4 <= vectors * LANES`, which is within both buffers.
+            let n = vld1q_u32(src.add(i * LANES));
+            let low = vsqrtq_f64(vcvtq_f64_u64(vmovl_u32(vget_low_u32(n))));
+            let high = vsqrtq_f64(vcvtq_f64_u64(vmovl_high_u32(n)));
+            // `fcvtzu` truncates, and the square roots are below 2^16, so narrowing keeps them whole.
+            let sqrt = vmovn_high_u64(vmovn_u64(vcvtq_u64_f64(low)), vcvtq_u64_f64(high));
+            vst1q_u32(dst.add(i * LANES), sqrt);
+        }
+
+        vectors * LANES
+    }
+}
@@ -0,0 +1,62 @@
+use crate::karatsuba::UnsignedIsqrt;
+
+/// Takes the integer square root of each `value_bits`-wide value packed into `data`, packing the roots into `out`.
+///
+/// Values are packed least-significant bit first: value `i` occupies bits `i * value_bits` through
+/// `(i + 1) * value_bits - 1` of `data`, where bit `j` is bit `j % 8` of byte `j / 8`. Every complete value in `data` is
+/// rooted, and any trailing bits that don't make up a complete value are ignored.
+///
+/// Roots are packed the same way, each `(value_bits + 1) / 2` bits wide, which is `value_bits / 2` for even widths and
+/// one more for odd widths (the square root of a 3-bit 7 is 2, for example). Bytes of `out` past the last root are left
+/// unchanged, as are the bits after the last root in its final byte.
+///
+/// Panics if `value_bits` isn't in `2..=16` or if `out` is too short to hold every root.
+pub fn isqrt_bitpacked(data: &[u8], value_bits: u32, out: &mut [u8]) {
+    assert!(
+        (2..=16).contains(&value_bits),
+        "bit-packed values must be between 2 and 16 bits wide"
+    );
+
+    let root_bits = (value_bits + 1) >> 1;
+    let count = data.len() * 8 / value_bits as usize;
+    assert!(
+        out.len() * 8 >= count * root_bits as usize,
+        "output is too short to hold the bit-packed square roots"
+    );
+
+    let value_mask = (1 << value_bits) - 1;
+
+    let mut data = data.iter();
+    let mut in_buffer: u32 = 0;
+    let mut in_bits = 0;
+
+    let mut out_index = 0;
+    let mut out_buffer: u32 = 0;
+    let mut out_bits = 0;
+
+    for _ in 0..count {
+        while in_bits < value_bits {
+            // `count` only includes complete values, so there's always another byte here.
+            in_buffer |= (*data.next().unwrap() as u32) << in_bits;
+            in_bits += 8;
+        }
+        let value = (in_buffer & value_mask) as u16;
+        in_buffer >>= value_bits;
+        in_bits -= value_bits;
+
+        out_buffer |= (UnsignedIsqrt::isqrt(value) as u32) << out_bits;
+        out_bits += root_bits;
+        while out_bits >= 8 {
+            out[out_index] = out_buffer as u8;
+            out_index += 1;
+            out_buffer >>= 8;
+            out_bits -= 8;
+        }
+    }
+
+    if out_bits > 0 {
+        // Keep the bits after the last root.
+        let keep_mask = !((1 << out_bits) - 1) as u8;
+        out[out_index] = (out[out_index] & keep_mask) | out_buffer as u8;
+    }
+}
@@ -0,0 +1,290 @@
+//! Integer square roots of arbitrarily long unsigned integers, given as little-endian byte slices.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::karatsuba::UnsignedIsqrt;
+
+/// Returns the integer square root of the unsigned integer whose little-endian bytes are `n`.
+///
+/// The result is also little-endian, with no trailing zero bytes, so the square root of zero is empty.
+pub fn isqrt_bytes_le(n: &[u8]) -> Vec<u8> {
+    let (sqrt, _) = sqrt_rem(&from_bytes_le(n));
+
+    let mut bytes: Vec<u8> = sqrt.iter().flat_map(|limb| limb.to_le_bytes()).collect();
+    while bytes.last() == Some(&0) {
+        bytes.pop();
+    }
+    bytes
+}
+
+// The numbers below are little-endian vectors of 32-bit limbs without trailing zero limbs, so zero is empty. Products
+// and partial quotients of two limbs fit in a `u64`.
+type Limb = u32;
+type DoubleLimb = u64;
+
+const LIMB_BITS: u32 = Limb::BITS;
+
+/// Returns the integer square root and remainder of `n`.
+///
+/// This is the Karatsuba square root of the `karatsuba` module, recursing on the top half of the bits until they fit
+/// in a `u128`.
+fn sqrt_rem(n: &[Limb]) -> (Vec<Limb>, Vec<Limb>) {
+    // Performs a Karatsuba square root.
+    // https://web.archive.org/web/20230511212802/https://inria.hal.science/inria-00072854v1/file/RR-3805.pdf
+
+    let bits = bit_len(n);
+    if bits <= u128::BITS {
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(to_u128(n));
+        return (from_u128(sqrt), from_u128(remainder));
+    }
+
+    // Split the bits into four quarters of `quarter_bits` bits each, with at least one of the top two bits of the top
+    // quarter set. To make that happen, shift left by an even number of bits, which doubles the square root's shift.
+    let quarter_bits = bits.div_ceil(4);
+    let precondition_shift = (4 * quarter_bits - bits) & !1;
+    let shifted = shl(n, precondition_shift);
+
+    let a0 = low_bits(&shifted, quarter_bits);
+    let a1 = low_bits(&shr(&shifted, quarter_bits), quarter_bits);
+    let (s_prime, r_prime) = sqrt_rem(&shr(&shifted, 2 * quarter_bits));
+
+    let (q, u) = div_rem(&add(&shl(&r_prime, quarter_bits), &a1), &shl(&s_prime, 1));
+
+    let mut s = add(&shl(&s_prime, quarter_bits), &q);
+    let r_plus_q_squared = add(&shl(&u, quarter_bits), &a0);
+    let q_squared = mul(&q, &q);
+    let mut r = if cmp(&r_plus_q_squared, &q_squared) == Ordering::Less {
+        // The square root is one less, and the remainder is `2 * s - 1` more, which is `2 * (s - 1) + 1`.
+        s = sub(&s, &[1]);
+        sub(&add(&r_plus_q_squared, &add(&shl(&s, 1), &[1])), &q_squared)
+    } else {
+        sub(&r_plus_q_squared, &q_squared)
+    };
+
+    if precondition_shift != 0 {
+        s = shr(&s, precondition_shift >> 1);
+        r = sub(n, &mul(&s, &s));
+    }
+
+    (s, r)
+}
+
+fn from_bytes_le(bytes: &[u8]) -> Vec<Limb> {
+    let mut n: Vec<Limb> = bytes
+        .chunks(4)
+        .map(|chunk| {
+            let mut limb = [0; 4];
+            limb[..chunk.len()].copy_from_slice(chunk);
+            Limb::from_le_bytes(limb)
+        })
+        .collect();
+    trim(&mut n);
+    n
+}
+
+fn from_u128(value: u128) -> Vec<Limb> {
+    from_bytes_le(&value.to_le_bytes())
+}
+
+/// Returns `n`, which must fit in a `u128`.
+fn to_u128(n: &[Limb]) -> u128 {
+    n.iter()
+        .rev()
+        .fold(0, |value, &limb| (value << LIMB_BITS) | limb as u128)
+}
+
+/// Removes trailing zero limbs.
+fn trim(n: &mut Vec<Limb>) {
+    while n.last() == Some(&0) {
+        n.pop();
+    }
+}
+
+fn bit_len(n: &[Limb]) -> u32 {
+    n.last().map_or(0, |&top| {
+        (n.len() as u32 - 1) * LIMB_BITS + (LIMB_BITS - top.leading_zeros())
+    })
+}
+
+fn cmp(a: &[Limb], b: &[Limb]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn shl(n: &[Limb], shift: u32) -> Vec<Limb> {
+    if n.is_empty() {
+        return Vec::new();
+    }
+
+    let (limb_shift, bit_shift) = ((shift / LIMB_BITS) as usize, shift % LIMB_BITS);
+    let mut shifted = vec![0; limb_shift];
+    let mut carry = 0;
+    for &limb in n {
+        let wide = (limb as DoubleLimb) << bit_shift;
+        shifted.push(wide as Limb | carry);
+        carry = (wide >> LIMB_BITS) as Limb;
+    }
+    shifted.push(carry);
+    trim(&mut shifted);
+    shifted
+}
+
+fn shr(n: &[Limb], shift: u32) -> Vec<Limb> {
+    let (limb_shift, bit_shift) = ((shift / LIMB_BITS) as usize, shift % LIMB_BITS);
+    let n = n.get(limb_shift..).unwrap_or_default();
+    let mut shifted: Vec<Limb> = (0..n.len())
+        .map(|i| {
+            let wide = n[i] as DoubleLimb
+                | (n.get(i + 1).copied().unwrap_or(0) as DoubleLimb) << LIMB_BITS;
+            (wide >> bit_shift) as Limb
+        })
+        .collect();
+    trim(&mut shifted);
+    shifted
+}
+
+/// Returns `n mod 2^bits`.
+fn low_bits(n: &[Limb], bits: u32) -> Vec<Limb> {
+    let (whole_limbs, partial_bits) = ((bits / LIMB_BITS) as usize, bits % LIMB_BITS);
+    let mut low = n[..n.len().min(whole_limbs)].to_vec();
+    if partial_bits != 0 {
+        if let Some(&limb) = n.get(whole_limbs) {
+            low.push(limb & ((1 << partial_bits) - 1));
+        }
+    }
+    trim(&mut low);
+    low
+}
+
+fn add(a: &[Limb], b: &[Limb]) -> Vec<Limb> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut sum = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0;
+    for (i, &limb) in long.iter().enumerate() {
+        let wide = limb as DoubleLimb + short.get(i).copied().unwrap_or(0) as DoubleLimb + carry;
+        sum.push(wide as Limb);
+        carry = wide >> LIMB_BITS;
+    }
+    sum.push(carry as Limb);
+    trim(&mut sum);
+    sum
+}
+
+/// Returns `a - b`, where `a` must be at least `b`.
+fn sub(a: &[Limb], b: &[Limb]) -> Vec<Limb> {
+    let mut difference = Vec::with_capacity(a.len());
+    let mut borrow = false;
+    for (i, &limb) in a.iter().enumerate() {
+        let (partial, borrowed_b) = limb.overflowing_sub(b.get(i).copied().unwrap_or(0));
+        let (limb_difference, borrowed_carry) = partial.overflowing_sub(borrow as Limb);
+        difference.push(limb_difference);
+        borrow = borrowed_b || borrowed_carry;
+    }
+    debug_assert!(!borrow && b.len() <= a.len());
+    trim(&mut difference);
+    difference
+}
+
+fn mul(a: &[Limb], b: &[Limb]) -> Vec<Limb> {
+    let mut product = vec![0; a.len() + b.len()];
+    for (i, &a_limb) in a.iter().enumerate() {
+        let mut carry = 0;
+        for (j, &b_limb) in b.iter().enumerate() {
+            // At most `(2^32 - 1)^2 + 2 * (2^32 - 1)`, which is `2^64 - 1`.
+            let wide =
+                a_limb as DoubleLimb * b_limb as DoubleLimb + product[i + j] as DoubleLimb + carry;
+            product[i + j] = wide as Limb;
+            carry = wide >> LIMB_BITS;
+        }
+        product[i + b.len()] = carry as Limb;
+    }
+    trim(&mut product);
+    product
+}
+
+/// Returns the quotient and remainder of `a / b`, where `b` must be nonzero.
+///
+/// This is Knuth's Algorithm D, from The Art of Computer Programming, Volume 2, Section 4.3.1.
+fn div_rem(a: &[Limb], b: &[Limb]) -> (Vec<Limb>, Vec<Limb>) {
+    const BASE: DoubleLimb = 1 << LIMB_BITS;
+
+    assert!(!b.is_empty(), "attempt to divide by zero");
+    if cmp(a, b) == Ordering::Less {
+        return (Vec::new(), a.to_vec());
+    }
+
+    if let [divisor] = *b {
+        let mut quotient = vec![0; a.len()];
+        let mut remainder: DoubleLimb = 0;
+        for (i, &limb) in a.iter().enumerate().rev() {
+            let numerator = remainder << LIMB_BITS | limb as DoubleLimb;
+            quotient[i] = (numerator / divisor as DoubleLimb) as Limb;
+            remainder = numerator % divisor as DoubleLimb;
+        }
+        trim(&mut quotient);
+        return (quotient, from_u128(remainder as u128));
+    }
+
+    // Shift so that the divisor's top limb has its top bit set, which keeps the estimated quotient limbs within two of
+    // the real ones. The dividend gets an extra limb, even if it's zero, so that there's always a top limb to divide.
+    let shift = b[b.len() - 1].leading_zeros();
+    let v = shl(b, shift);
+    let mut u = shl(a, shift);
+    u.resize(a.len() + 1, 0);
+
+    let n = v.len();
+    let mut quotient = vec![0; u.len() - n];
+    for j in (0..quotient.len()).rev() {
+        // Estimate the quotient limb from the top two limbs of the dividend and the top limb of the divisor, then
+        // correct the estimate with the next limb of each.
+        let numerator = (u[j + n] as DoubleLimb) << LIMB_BITS | u[j + n - 1] as DoubleLimb;
+        let mut q_hat = numerator / v[n - 1] as DoubleLimb;
+        let mut r_hat = numerator % v[n - 1] as DoubleLimb;
+        while q_hat >= BASE
+            || q_hat * v[n - 2] as DoubleLimb > (r_hat << LIMB_BITS | u[j + n - 2] as DoubleLimb)
+        {
+            q_hat -= 1;
+            r_hat += v[n - 1] as DoubleLimb;
+            if r_hat >= BASE {
+                break;
+            }
+        }
+
+        // Subtract `q_hat * v` from the dividend.
+        let mut borrow = false;
+        let mut carry = 0;
+        for i in 0..n {
+            let product = q_hat * v[i] as DoubleLimb + carry;
+            carry = product >> LIMB_BITS;
+            let (partial, borrowed_product) = u[i + j].overflowing_sub(product as Limb);
+            let (difference, borrowed_borrow) = partial.overflowing_sub(borrow as Limb);
+            u[i + j] = difference;
+            borrow = borrowed_product || borrowed_borrow;
+        }
+        let (partial, borrowed_carry) = u[j + n].overflowing_sub(carry as Limb);
+        let (difference, borrowed_borrow) = partial.overflowing_sub(borrow as Limb);
+        u[j + n] = difference;
+
+        // The estimate was still one too big, which is rare, so add `v` back.
+        if borrowed_carry || borrowed_borrow {
+            q_hat -= 1;
+            let mut carry = 0;
+            for i in 0..n {
+                let sum = u[i + j] as DoubleLimb + v[i] as DoubleLimb + carry;
+                u[i + j] = sum as Limb;
+                carry = sum >> LIMB_BITS;
+            }
+            u[j + n] = u[j + n].wrapping_add(carry as Limb);
+        }
+
+        quotient[j] = q_hat as Limb;
+    }
+
+    u.truncate(n);
+    trim(&mut u);
+    trim(&mut quotient);
+    (quotient, shr(&u, shift))
+}
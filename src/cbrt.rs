@@ -0,0 +1,79 @@
+//! Integer cube roots, computed with the floating-point method of the `floating_point` module.
+
+/// Integer cube roots of the signed integer types.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+pub trait SignedCbrt: Sized + crate::sealed::Sealed {
+    /// Returns the integer cube root, rounded toward zero, so that negative numbers have negative cube roots.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn icbrt(self) -> Self;
+    /// Returns the integer cube root, or `None` if `self` is negative, for parity with `checked_isqrt`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_icbrt(self) -> Option<Self>;
+}
+
+/// Integer cube roots of the unsigned integer types.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+pub trait UnsignedCbrt: Sized + crate::sealed::Sealed {
+    /// Returns the largest integer whose cube is at most `self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn icbrt(self) -> Self;
+}
+
+macro_rules! unsigned_cbrt {
+    ($($unsigned_type:ty),+) => {
+        $(
+            impl UnsignedCbrt for $unsigned_type {
+                #[inline]
+                fn icbrt(self) -> Self {
+                    // The cube root of the `f64` nearest to `self` is within one of the real cube root, even for
+                    // `u128`, whose cube roots have at most 43 bits, well within the 53-bit mantissa. So, as in
+                    // `floating_point`'s `u64` square root, the floored floating-point cube root is the answer plus
+                    // -1, 0, or 1.
+                    //
+                    // Cubes of results near the cube root of `MAX` can overflow. An overflowing cube is above `self`.
+                    let result = (self as f64).cbrt() as Self;
+                    let cube_exceeds_self = |root: Self| root.checked_pow(3).map_or(true, |cube| cube > self);
+
+                    if cube_exceeds_self(result) {
+                        result - 1
+                    } else if !cube_exceeds_self(result + 1) {
+                        result + 1
+                    } else {
+                        result
+                    }
+                }
+            }
+        )+
+    };
+}
+
+unsigned_cbrt!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! signed_cbrt {
+    ($($signed_type:ty),+) => {
+        $(
+            impl SignedCbrt for $signed_type {
+                #[inline]
+                fn icbrt(self) -> Self {
+                    // Cube roots are odd functions, so take the cube root of the magnitude and restore the sign. Even
+                    // `MIN`'s magnitude fits in the unsigned type.
+                    let result = UnsignedCbrt::icbrt(self.unsigned_abs()) as Self;
+                    if self < 0 {
+                        -result
+                    } else {
+                        result
+                    }
+                }
+
+                #[inline]
+                fn checked_icbrt(self) -> Option<Self> {
+                    (self >= 0).then(|| SignedCbrt::icbrt(self))
+                }
+            }
+        )+
+    };
+}
+
+signed_cbrt!(i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,109 @@
+//! Integer square roots of decimal strings too long for any integer type, computed two digits at a time as by hand.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// The error returned by [`isqrt_decimal`] when its argument isn't a nonempty string of ASCII digits.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct InvalidDigitError;
+
+impl core::fmt::Display for InvalidDigitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(
+            "argument of decimal integer square root must be a nonempty string of ASCII digits",
+        )
+    }
+}
+
+impl core::error::Error for InvalidDigitError {}
+
+/// Returns the integer square root of the number written in decimal as `digits`, also in decimal.
+///
+/// Leading zeros are allowed, and the result has none, so the square root of `"0"` or `"000"` is `"0"`.
+pub fn isqrt_decimal(digits: &str) -> Result<String, InvalidDigitError> {
+    if digits.is_empty() || !digits.bytes().all(|digit| digit.is_ascii_digit()) {
+        return Err(InvalidDigitError);
+    }
+
+    // The numbers below are little-endian vectors of decimal digits without leading zeros, so zero is empty.
+    //
+    // The digits are brought down two at a time, starting from the most-significant pair, which is a lone digit when
+    // there's an odd number of them. With `root` the square root of the digits so far and `remainder` the digits so far
+    // minus `root` squared, bringing down a pair makes the new remainder `100 * remainder + pair`. The next digit of the
+    // root is then the largest `x` for which `(20 * root + x) * x`, which is how much `(10 * root + x)^2` exceeds
+    // `100 * root^2`, is at most the new remainder.
+    let digits = digits.as_bytes();
+    let mut root = Vec::with_capacity(digits.len().div_ceil(2));
+    let mut remainder = Vec::new();
+    for pair in digits.rchunks(2).rev() {
+        remainder.splice(0..0, pair.iter().rev().map(|digit| digit - b'0'));
+        trim(&mut remainder);
+
+        // `(20 * root + x)` is `2 * root` with `x` appended as its last digit.
+        let doubled_root = mul_small(&root, 2);
+        let (digit, excess) = (0..=9)
+            .rev()
+            .map(|x| {
+                let mut candidate = Vec::with_capacity(doubled_root.len() + 1);
+                candidate.push(x);
+                candidate.extend_from_slice(&doubled_root);
+                (x, mul_small(&candidate, x))
+            })
+            .find(|(_, excess)| cmp(excess, &remainder) != Ordering::Greater)
+            .expect("zero never exceeds the remainder");
+
+        sub_assign(&mut remainder, &excess);
+        root.insert(0, digit);
+        trim(&mut root);
+    }
+
+    if root.is_empty() {
+        return Ok(String::from("0"));
+    }
+    Ok(root
+        .iter()
+        .rev()
+        .map(|&digit| char::from(b'0' + digit))
+        .collect())
+}
+
+/// Removes leading zeros.
+fn trim(n: &mut Vec<u8>) {
+    while n.last() == Some(&0) {
+        n.pop();
+    }
+}
+
+fn cmp(a: &[u8], b: &[u8]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn mul_small(n: &[u8], factor: u8) -> Vec<u8> {
+    let mut product = Vec::with_capacity(n.len() + 1);
+    let mut carry = 0;
+    for &digit in n {
+        let digit_product = digit * factor + carry;
+        product.push(digit_product % 10);
+        carry = digit_product / 10;
+    }
+    if carry != 0 {
+        product.push(carry);
+    }
+    trim(&mut product);
+    product
+}
+
+/// Subtracts `b` from `a`, which must be at least `b`.
+fn sub_assign(a: &mut Vec<u8>, b: &[u8]) {
+    let mut borrow = 0;
+    for (i, digit) in a.iter_mut().enumerate() {
+        let subtrahend = b.get(i).copied().unwrap_or(0) + borrow;
+        borrow = u8::from(*digit < subtrahend);
+        *digit = *digit + 10 * borrow - subtrahend;
+    }
+    debug_assert_eq!(borrow, 0);
+    trim(a);
+}
@@ -0,0 +1,138 @@
+//! Integer square roots that use the fastest module for each integer width, for code that doesn't care which algorithm
+//! it gets.
+//!
+//! The mapping comes from the random-input groups of `cargo bench --bench isqrt`, one per type:
+//!
+//! | Types                      | Module           | `const` function        |
+//! |----------------------------|------------------|-------------------------|
+//! | `i8`, `u8`, `i16`, `u16`   | `karatsuba`      | `isqrt_u8`, `isqrt_u16` |
+//! | `i32`, `u32`, `i64`, `u64` | `floating_point` | none                    |
+//! | `i128`, `u128`             | `floating_point` | `isqrt_u128`            |
+//! | `isize`, `usize`           | `floating_point` | none                    |
+//!
+//! Up to 16 bits, `karatsuba` looks the square root up in a table, which beats converting to and from floating point.
+//! From 32 bits up, `floating_point` is fastest, with `floating_point_and_karatsuba` within noise of it, as it uses the
+//! same method at runtime. `floating_point` has no `const` 32- or 64-bit function, so those two widths aren't `const`.
+//!
+//! That table is for targets with `f64` square roots in hardware. The build script checks the target and falls back to
+//! `karatsuba` where `floating_point` would be slow or unavailable:
+//!
+//! | Target                                                       | 32 to 128 bits   | `isize`, `usize` |
+//! |--------------------------------------------------------------|------------------|------------------|
+//! | `algo-floating` off, or `no-float` or `wasm-no-float` on     | `karatsuba`      | `karatsuba`      |
+//! | No hardware `f64` square root                                | `karatsuba`      | `karatsuba`      |
+//! | Hardware `f64` square root, 16-bit pointers                  | `floating_point` | `karatsuba`      |
+//! | Hardware `f64` square root, 32- or 64-bit pointers           | `floating_point` | `floating_point` |
+//!
+//! A target has a hardware `f64` square root with SSE2 on x86 and x86-64, NEON on AArch64, VFPv2 on 32-bit ARM, the `D`
+//! extension on RISC-V and LoongArch, and always on WebAssembly. Other architectures are assumed not to, and x87 is left
+//! out, as its extended precision breaks the rounding that `floating_point` relies on. Enabling target features, as
+//! with `-C target-cpu=native`, is taken into account. The 8- and 16-bit types always use `karatsuba`, and the `const`
+//! functions stay `const` whichever module they forward to.
+//!
+//! `batch` doesn't need the build script's help: its `u32` slices use AVX2 when the CPU has it, checked at compile time
+//! when the target enables AVX2 and at runtime otherwise.
+//!
+//! ```
+//! use isqrt::DefaultIsqrt;
+//!
+//! assert_eq!(isqrt::isqrt(99_u64), 9);
+//! assert_eq!(DefaultIsqrt::checked_isqrt(-4_i16), None);
+//!
+//! const SIDE: u16 = isqrt::default::isqrt_u16(1000);
+//! assert_eq!(SIDE, 31);
+//! ```
+
+/// Integer square roots of every integer type, each taken with the fastest module for its width.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+pub trait DefaultIsqrt: Sized + crate::sealed::Sealed {
+    /// Returns the largest integer whose square is at most `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative, as the signed `isqrt` does.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt(self) -> Self;
+    /// Returns the square root, or `None` if `self` is negative. For unsigned types, this is always `Some`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_isqrt(self) -> Option<Self>;
+}
+
+macro_rules! default_isqrt {
+    ($module:ident: $($type:ty),+) => {
+        $(
+            impl DefaultIsqrt for $type {
+                #[inline]
+                fn isqrt(self) -> Self {
+                    crate::$module::IntIsqrt::isqrt(self)
+                }
+
+                #[inline]
+                fn checked_isqrt(self) -> Option<Self> {
+                    crate::$module::IntIsqrt::checked_isqrt(self)
+                }
+            }
+        )+
+    };
+}
+
+// `build.rs` only sets these flags when `floating_point` is compiled in.
+default_isqrt!(karatsuba: i8, u8, i16, u16);
+#[cfg(isqrt_default_floating)]
+default_isqrt!(floating_point: i32, u32, i64, u64, i128, u128);
+#[cfg(not(isqrt_default_floating))]
+default_isqrt!(karatsuba: i32, u32, i64, u64, i128, u128);
+#[cfg(isqrt_default_floating_usize)]
+default_isqrt!(floating_point: isize, usize);
+#[cfg(not(isqrt_default_floating_usize))]
+default_isqrt!(karatsuba: isize, usize);
+
+/// Returns the largest integer whose square is at most `n`.
+///
+/// # Panics
+///
+/// Panics if `n` is negative.
+#[inline]
+pub fn isqrt<T: DefaultIsqrt>(n: T) -> T {
+    DefaultIsqrt::isqrt(n)
+}
+
+/// Returns the square root of `n`, or `None` if `n` is negative.
+#[inline]
+pub fn checked_isqrt<T: DefaultIsqrt>(n: T) -> Option<T> {
+    DefaultIsqrt::checked_isqrt(n)
+}
+
+/// Returns the integer square root of `n`. Usable in `const` contexts.
+#[inline]
+pub const fn isqrt_u8(n: u8) -> u8 {
+    crate::karatsuba::isqrt_u8(n)
+}
+
+/// Returns the integer square root of `n`. Usable in `const` contexts.
+#[inline]
+pub const fn isqrt_u16(n: u16) -> u16 {
+    crate::karatsuba::isqrt_u16(n)
+}
+
+/// Returns the integer square root of `n`.
+#[inline]
+pub fn isqrt_u32(n: u32) -> u32 {
+    DefaultIsqrt::isqrt(n)
+}
+
+/// Returns the integer square root of `n`.
+#[inline]
+pub fn isqrt_u64(n: u64) -> u64 {
+    DefaultIsqrt::isqrt(n)
+}
+
+/// Returns the integer square root of `n`. Usable in `const` contexts.
+#[inline]
+pub const fn isqrt_u128(n: u128) -> u128 {
+    #[cfg(isqrt_default_floating)]
+    return crate::floating_point::isqrt_u128(n);
+    #[cfg(not(isqrt_default_floating))]
+    return crate::karatsuba::isqrt_u128(n);
+}
@@ -0,0 +1,36 @@
+//! Integer square roots of fixed-size unsigned integers given as big-endian byte arrays, such as the `[u8; 32]` and
+//! `[u8; 64]` of cryptographic code.
+
+/// Returns the integer square root of the unsigned integer whose big-endian bytes are `n`, also as big-endian bytes.
+///
+/// `N` must be even, so that the square root fits in the second half of the result, leaving the first half zero. An odd
+/// `N` fails to build, though `cargo check` doesn't report it.
+///
+/// This uses the Karatsuba square root of the `bytes` module.
+///
+/// ```
+/// use isqrt::fixed::isqrt_be;
+///
+/// let mut n = [0; 32];
+/// n[30..].copy_from_slice(&1000_u16.to_be_bytes());
+///
+/// let mut sqrt = [0; 32];
+/// sqrt[31] = 31;
+/// assert_eq!(isqrt_be(n), sqrt);
+/// ```
+pub fn isqrt_be<const N: usize>(n: [u8; N]) -> [u8; N] {
+    const { assert!(N.is_multiple_of(2), "the byte array length must be even") };
+
+    let mut n_le = n;
+    n_le.reverse();
+
+    let mut result = [0; N];
+    for (byte, sqrt_byte) in result
+        .iter_mut()
+        .rev()
+        .zip(crate::bytes::isqrt_bytes_le(&n_le))
+    {
+        *byte = sqrt_byte;
+    }
+    result
+}
@@ -0,0 +1,37 @@
+//! Integer square roots of the floors of floating-point numbers.
+
+use crate::floating_point::UnsignedIsqrt;
+
+/// Integer square roots of the floors of `f32` and `f64`.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+pub trait FloatIsqrt: crate::sealed::Sealed {
+    /// The unsigned integer type with as many bits as `Self`.
+    type Output;
+
+    /// Returns the integer square root of `self.floor()`.
+    ///
+    /// Negative numbers and NaN give zero. Numbers whose floor doesn't fit in `Self::Output`, including positive
+    /// infinity, saturate, giving the square root of `Self::Output::MAX`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_of_floor(self) -> Self::Output;
+}
+
+macro_rules! float_isqrt {
+    ($($float_type:ty => $unsigned_type:ty),+) => {
+        $(
+            impl FloatIsqrt for $float_type {
+                type Output = $unsigned_type;
+
+                #[inline]
+                fn isqrt_of_floor(self) -> Self::Output {
+                    // Float-to-integer `as` casts round toward zero, which floors nonnegative numbers, and saturate,
+                    // sending negative numbers and NaN to zero and larger numbers to `MAX`, as documented above.
+                    UnsignedIsqrt::isqrt(self as $unsigned_type)
+                }
+            }
+        )+
+    };
+}
+
+float_isqrt!(f32 => u32, f64 => u64);
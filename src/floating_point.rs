@@ -1,17 +1,110 @@
+#[cfg(feature = "nightly")]
 use core::intrinsics;
 
-pub trait SignedIsqrt: Sized {
+/// Integer square roots of the signed integer types.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+pub trait SignedIsqrt: Sized + crate::sealed::Sealed {
+    /// The square root of `Self::MAX`, which no square root exceeds.
+    const ISQRT_MAX: Self;
+
+    #[must_use = "this returns the result of the operation, without modifying the original"]
     fn checked_isqrt(self) -> Option<Self>;
+    #[must_use = "this returns the result of the operation, without modifying the original"]
     fn isqrt(self) -> Self;
+    /// Returns the square root, or a `NegativeInputError` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError>;
+    /// Returns the square root, or `default` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_or(self, default: Self) -> Self;
+    /// Returns the square root, or zero if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_or_zero(self) -> Self;
+    /// Returns the square root, or zero if `self` is negative, which clamps negative arguments to zero first.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    fn saturating_isqrt(self) -> Self {
+        SignedIsqrt::isqrt_or_zero(self)
+    }
+    /// Returns the smallest integer whose square is at least `self`, or `None` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_ceil_isqrt(self) -> Option<Self>;
+    /// Returns whether `self` is the square of an integer. Negative numbers never are.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn is_perfect_square(self) -> bool;
 }
-pub trait UnsignedIsqrt {
+/// Integer square roots of the unsigned integer types.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+pub trait UnsignedIsqrt: Sized + crate::sealed::Sealed {
+    /// The square root of `Self::MAX`, which no square root exceeds.
+    const ISQRT_MAX: Self;
+
+    #[must_use = "this returns the result of the operation, without modifying the original"]
     fn isqrt(self) -> Self;
+    /// Returns the largest integer whose square is at most `self`, the same as `isqrt`, named to match `ceil_isqrt`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    fn isqrt_floor(self) -> Self {
+        UnsignedIsqrt::isqrt(self)
+    }
+    /// Returns the smallest integer whose square is at least `self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn ceil_isqrt(self) -> Self;
+    /// Returns whether `self` is the square of an integer.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn is_perfect_square(self) -> bool;
+    /// Returns the largest perfect square that is at most `self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn prev_perfect_square(self) -> Self;
+    /// Returns the smallest perfect square greater than `self`.
+    ///
+    /// Panics if that square doesn't fit in `Self`, which happens when `self` is at least the largest perfect square
+    /// that does (for example, 225 for `u8`).
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn next_perfect_square(self) -> Self;
+    /// Returns the smallest perfect square greater than `self`, or `None` if it doesn't fit in `Self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_next_perfect_square(self) -> Option<Self>;
+    /// Returns the smallest perfect square greater than `self`, along with whether it overflowed `Self`. If it did,
+    /// which happens when `self` is at least the largest perfect square that fits, the value wraps around to zero.
+    ///
+    /// The `NonZero` types can't hold zero, so for them the value is one instead. That isn't the inner integer's
+    /// wrapped value, so check the flag rather than comparing the value with zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_next_perfect_square(self) -> (Self, bool);
+    /// Returns the square root if `self` is a perfect square, or `None` otherwise.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_exact(self) -> Option<Self>;
+    /// Returns the integer square root along with whether `self` is a perfect square.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_with_exactness(self) -> (Self, bool);
+    /// Returns the square root of `self` as an `f64` whose integer part is exactly `self.isqrt()`.
+    ///
+    /// For `u64` and `u128`, `(self as f64).sqrt()` can round up to the next integer just below a perfect square, or
+    /// round `self` down past one before taking the square root. This starts from `isqrt` instead and adds an estimate
+    /// of the fractional part, so `self.isqrt_f64().floor()` always equals `self.isqrt()` as long as that fits in an
+    /// `f64` exactly, which is every `u64` and every `u128` below `2^106`. Larger square roots are rounded down to the
+    /// nearest `f64`, so the integer part never exceeds `isqrt`.
+    ///
+    /// ```
+    /// use isqrt::floating_point::UnsignedIsqrt;
+    ///
+    /// let n = 94_906_267_u64.pow(2) - 1;
+    /// assert_eq!((n as f64).sqrt(), 94_906_267.0);
+    /// assert_eq!(n.isqrt_f64().floor(), 94_906_266.0);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_f64(self) -> f64;
 }
 
 impl SignedIsqrt for i8 {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
     fn checked_isqrt(self) -> Option<Self> {
         (self >= 0).then(|| {
-            let result = (self as f32).sqrt();
+            let result = crate::sqrt_f32(self as f32);
             // SAFETY: Guaranteed to not be a NaN or an infinity and to, except for the fractional part, be in `i8`
             // range.
             let result = unsafe { result.to_int_unchecked::<i8>() };
@@ -19,8 +112,8 @@ impl SignedIsqrt for i8 {
             // SAFETY: the result is nonnegative and less than or equal to `i8::MAX.isqrt()`.
             // Inform the optimizer about it.
             unsafe {
-                intrinsics::assume(0 <= result);
-                intrinsics::assume(result <= 11);
+                crate::assume(0 <= result);
+                crate::assume(result <= <Self as SignedIsqrt>::ISQRT_MAX);
             }
 
             result
@@ -29,31 +122,116 @@ impl SignedIsqrt for i8 {
 
     #[inline]
     fn isqrt(self) -> Self {
-        self.checked_isqrt()
-            .expect("argument of integer square root must be non-negative")
+        SignedIsqrt::checked_isqrt(self).unwrap_or_else(crate::negative_isqrt_argument)
+    }
+
+    #[inline]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+        SignedIsqrt::checked_isqrt(self).ok_or(crate::NegativeInputError)
+    }
+
+    #[inline]
+    fn isqrt_or(self, default: Self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(default)
+    }
+
+    #[inline]
+    fn isqrt_or_zero(self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(0)
+    }
+
+    #[inline]
+    fn checked_ceil_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_isqrt(self)
+            .map(|sqrt| if sqrt * sqrt == self { sqrt } else { sqrt + 1 })
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        self >= 0 && UnsignedIsqrt::is_perfect_square(self as u8)
     }
 }
 
 impl UnsignedIsqrt for u8 {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
     fn isqrt(self) -> Self {
-        let result = (self as f32).sqrt();
+        let result = crate::sqrt_f32(self as f32);
         // SAFETY: Guaranteed to not be a NaN or an infinity and to, except for the fractional part, be in `u8` range.
         let result = unsafe { result.to_int_unchecked::<u8>() };
 
+        crate::debug_assert_isqrt(self as u128, result as u128);
+
         // SAFETY: the result fits in an integer with half as many bits.
         // Inform the optimizer about it.
         unsafe {
-            intrinsics::assume(result < 1 << ((Self::BITS as Self) >> 1));
+            crate::assume(result < 1 << ((Self::BITS as Self) >> 1));
         }
 
         result
     }
+
+    #[inline]
+    fn ceil_isqrt(self) -> Self {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        if sqrt * sqrt == self {
+            sqrt
+        } else {
+            sqrt + 1
+        }
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        sqrt * sqrt == self
+    }
+    #[inline]
+    fn prev_perfect_square(self) -> Self {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        sqrt * sqrt
+    }
+    #[inline]
+    fn next_perfect_square(self) -> Self {
+        UnsignedIsqrt::checked_next_perfect_square(self)
+            .expect("no larger perfect square fits in the type")
+    }
+    #[inline]
+    fn checked_next_perfect_square(self) -> Option<Self> {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.checked_mul(sqrt)
+    }
+    #[inline]
+    fn overflowing_next_perfect_square(self) -> (Self, bool) {
+        // As in `checked_next_perfect_square`, `sqrt + 1` can't overflow.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.overflowing_mul(sqrt)
+    }
+    #[inline]
+    fn isqrt_exact(self) -> Option<Self> {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        (sqrt * sqrt == self).then_some(sqrt)
+    }
+    #[inline]
+    fn isqrt_with_exactness(self) -> (Self, bool) {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        (sqrt, sqrt * sqrt == self)
+    }
+    #[inline]
+    fn isqrt_f64(self) -> f64 {
+        // Every `u32` is exact in an `f64`, and its correctly rounded square root never rounds up to the next integer.
+        crate::sqrt_f64(self as f64)
+    }
 }
 
 impl SignedIsqrt for i16 {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
     fn checked_isqrt(self) -> Option<Self> {
         (self >= 0).then(|| {
-            let result = (self as f32).sqrt();
+            let result = crate::sqrt_f32(self as f32);
             // SAFETY: Guaranteed to not be a NaN or an infinity and to, except for the fractional part, be in `i16`
             // range.
             let result = unsafe { result.to_int_unchecked::<i16>() };
@@ -61,8 +239,8 @@ impl SignedIsqrt for i16 {
             // SAFETY: the result is nonnegative and less than or equal to `i16::MAX.isqrt()`.
             // Inform the optimizer about it.
             unsafe {
-                intrinsics::assume(0 <= result);
-                intrinsics::assume(result <= 181);
+                crate::assume(0 <= result);
+                crate::assume(result <= <Self as SignedIsqrt>::ISQRT_MAX);
             }
 
             result
@@ -71,31 +249,120 @@ impl SignedIsqrt for i16 {
 
     #[inline]
     fn isqrt(self) -> Self {
-        self.checked_isqrt()
-            .expect("argument of integer square root must be non-negative")
+        SignedIsqrt::checked_isqrt(self).unwrap_or_else(crate::negative_isqrt_argument)
+    }
+
+    #[inline]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+        SignedIsqrt::checked_isqrt(self).ok_or(crate::NegativeInputError)
+    }
+
+    #[inline]
+    fn isqrt_or(self, default: Self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(default)
+    }
+
+    #[inline]
+    fn isqrt_or_zero(self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(0)
+    }
+
+    #[inline]
+    fn checked_ceil_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_isqrt(self)
+            .map(|sqrt| if sqrt * sqrt == self { sqrt } else { sqrt + 1 })
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        self >= 0 && UnsignedIsqrt::is_perfect_square(self as u16)
     }
 }
 
 impl UnsignedIsqrt for u16 {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
     fn isqrt(self) -> Self {
-        let result = (self as f32).sqrt();
+        // No correction is needed. `f32` has a 24-bit mantissa, so the conversion is exact. If `s` is the square root,
+        // then `self <= (s + 1)^2 - 1`, so the exact square root is below `s + 1` by more than `1 / (2 * (s + 1))`,
+        // which is at least 2^-9, as `s + 1 <= 2^8`. The correctly rounded `sqrt` is off by at most half an ulp, which
+        // is at most 2^-17 below 2^8, so it never rounds up to `s + 1`, and truncation gives `s`.
+        let result = crate::sqrt_f32(self as f32);
         // SAFETY: Guaranteed to not be a NaN or an infinity and to, except for the fractional part, be in `u16` range.
         let result = unsafe { result.to_int_unchecked::<u16>() };
 
+        crate::debug_assert_isqrt(self as u128, result as u128);
+
         // SAFETY: the result fits in an integer with half as many bits.
         // Inform the optimizer about it.
         unsafe {
-            intrinsics::assume(result < 1 << ((Self::BITS as Self) >> 1));
+            crate::assume(result < 1 << ((Self::BITS as Self) >> 1));
         }
 
         result
     }
+
+    #[inline]
+    fn ceil_isqrt(self) -> Self {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        if sqrt * sqrt == self {
+            sqrt
+        } else {
+            sqrt + 1
+        }
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        sqrt * sqrt == self
+    }
+    #[inline]
+    fn prev_perfect_square(self) -> Self {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        sqrt * sqrt
+    }
+    #[inline]
+    fn next_perfect_square(self) -> Self {
+        UnsignedIsqrt::checked_next_perfect_square(self)
+            .expect("no larger perfect square fits in the type")
+    }
+    #[inline]
+    fn checked_next_perfect_square(self) -> Option<Self> {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.checked_mul(sqrt)
+    }
+    #[inline]
+    fn overflowing_next_perfect_square(self) -> (Self, bool) {
+        // As in `checked_next_perfect_square`, `sqrt + 1` can't overflow.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.overflowing_mul(sqrt)
+    }
+    #[inline]
+    fn isqrt_exact(self) -> Option<Self> {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        (sqrt * sqrt == self).then_some(sqrt)
+    }
+    #[inline]
+    fn isqrt_with_exactness(self) -> (Self, bool) {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        (sqrt, sqrt * sqrt == self)
+    }
+    #[inline]
+    fn isqrt_f64(self) -> f64 {
+        // Every `u32` is exact in an `f64`, and its correctly rounded square root never rounds up to the next integer.
+        crate::sqrt_f64(self as f64)
+    }
 }
 
 impl SignedIsqrt for i32 {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
     fn checked_isqrt(self) -> Option<Self> {
         (self >= 0).then(|| {
-            let result = (self as f64).sqrt();
+            let result = crate::sqrt_f64(self as f64);
             // SAFETY: Guaranteed to not be a NaN or an infinity and to, except for the fractional part, be in `i32`
             // range.
             let result = unsafe { result.to_int_unchecked::<i32>() };
@@ -103,8 +370,8 @@ impl SignedIsqrt for i32 {
             // SAFETY: the result is nonnegative and less than or equal to `i32::MAX.isqrt()`.
             // Inform the optimizer about it.
             unsafe {
-                intrinsics::assume(0 <= result);
-                intrinsics::assume(result <= 46_340);
+                crate::assume(0 <= result);
+                crate::assume(result <= <Self as SignedIsqrt>::ISQRT_MAX);
             }
 
             result
@@ -113,27 +380,117 @@ impl SignedIsqrt for i32 {
 
     #[inline]
     fn isqrt(self) -> Self {
-        self.checked_isqrt()
-            .expect("argument of integer square root must be non-negative")
+        SignedIsqrt::checked_isqrt(self).unwrap_or_else(crate::negative_isqrt_argument)
+    }
+
+    #[inline]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+        SignedIsqrt::checked_isqrt(self).ok_or(crate::NegativeInputError)
+    }
+
+    #[inline]
+    fn isqrt_or(self, default: Self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(default)
+    }
+
+    #[inline]
+    fn isqrt_or_zero(self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(0)
+    }
+
+    #[inline]
+    fn checked_ceil_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_isqrt(self)
+            .map(|sqrt| if sqrt * sqrt == self { sqrt } else { sqrt + 1 })
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        self >= 0 && UnsignedIsqrt::is_perfect_square(self as u32)
     }
 }
 
 impl UnsignedIsqrt for u32 {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
     fn isqrt(self) -> Self {
-        let result = (self as f64).sqrt() as u32;
-        // Strangely, `f64::to_int_unchecked` is much slower here on Ryzen 5900X for `u32`.
+        // No correction is needed, by the argument for `u16` above: `f64` has a 53-bit mantissa, so the conversion is
+        // exact, and the exact square root is below `s + 1` by more than 2^-17, as `s + 1 <= 2^16`, while half an ulp
+        // is at most 2^-38 below 2^16.
+        let result = crate::sqrt_f64(self as f64);
+        // SAFETY: Guaranteed to not be a NaN or an infinity and to, except for the fractional part, be in `u32` range.
+        // Unlike `as`, this doesn't clamp the result into range first, leaving only `sqrtsd` and a truncation.
+        let result = unsafe { result.to_int_unchecked::<u32>() };
+
+        crate::debug_assert_isqrt(self as u128, result as u128);
 
         // SAFETY: the result fits in an integer with half as many bits.
         // Inform the optimizer about it.
         unsafe {
-            intrinsics::assume(result < 1 << ((Self::BITS as Self) >> 1));
+            crate::assume(result < 1 << ((Self::BITS as Self) >> 1));
         }
 
         result
     }
+
+    #[inline]
+    fn ceil_isqrt(self) -> Self {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        if sqrt * sqrt == self {
+            sqrt
+        } else {
+            sqrt + 1
+        }
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        sqrt * sqrt == self
+    }
+    #[inline]
+    fn prev_perfect_square(self) -> Self {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        sqrt * sqrt
+    }
+    #[inline]
+    fn next_perfect_square(self) -> Self {
+        UnsignedIsqrt::checked_next_perfect_square(self)
+            .expect("no larger perfect square fits in the type")
+    }
+    #[inline]
+    fn checked_next_perfect_square(self) -> Option<Self> {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.checked_mul(sqrt)
+    }
+    #[inline]
+    fn overflowing_next_perfect_square(self) -> (Self, bool) {
+        // As in `checked_next_perfect_square`, `sqrt + 1` can't overflow.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.overflowing_mul(sqrt)
+    }
+    #[inline]
+    fn isqrt_exact(self) -> Option<Self> {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        (sqrt * sqrt == self).then_some(sqrt)
+    }
+    #[inline]
+    fn isqrt_with_exactness(self) -> (Self, bool) {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        (sqrt, sqrt * sqrt == self)
+    }
+    #[inline]
+    fn isqrt_f64(self) -> f64 {
+        // Every `u32` is exact in an `f64`, and its correctly rounded square root never rounds up to the next integer.
+        crate::sqrt_f64(self as f64)
+    }
 }
 
 impl SignedIsqrt for i64 {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
     fn checked_isqrt(self) -> Option<Self> {
         // This proof of correctness is a corrected version of the flawed proof at
         // https://web.archive.org/web/20220118185505/https://www.codecodex.com/wiki/Calculate_an_integer_square_root#Java
@@ -167,7 +524,7 @@ impl SignedIsqrt for i64 {
 
         (self >= 0).then(|| {
             let result = {
-                let result = (self as u64 as f64).sqrt();
+                let result = crate::sqrt_f64(self as u64 as f64);
                 // SAFETY: Guaranteed to not be a NaN or an infinity and to, except for the fractional part, be in
                 // `u64` range.
                 let result = unsafe { result.to_int_unchecked::<u64>() };
@@ -184,8 +541,8 @@ impl SignedIsqrt for i64 {
             // SAFETY: the result is nonnegative and less than or equal to `i64::MAX.isqrt()`.
             // Inform the optimizer about it.
             unsafe {
-                intrinsics::assume(0 <= result);
-                intrinsics::assume(result <= 3_037_000_499);
+                crate::assume(0 <= result);
+                crate::assume(result <= <Self as SignedIsqrt>::ISQRT_MAX);
             }
 
             result
@@ -194,12 +551,39 @@ impl SignedIsqrt for i64 {
 
     #[inline]
     fn isqrt(self) -> Self {
-        self.checked_isqrt()
-            .expect("argument of integer square root must be non-negative")
+        SignedIsqrt::checked_isqrt(self).unwrap_or_else(crate::negative_isqrt_argument)
+    }
+
+    #[inline]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+        SignedIsqrt::checked_isqrt(self).ok_or(crate::NegativeInputError)
+    }
+
+    #[inline]
+    fn isqrt_or(self, default: Self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(default)
+    }
+
+    #[inline]
+    fn isqrt_or_zero(self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(0)
+    }
+
+    #[inline]
+    fn checked_ceil_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_isqrt(self)
+            .map(|sqrt| if sqrt * sqrt == self { sqrt } else { sqrt + 1 })
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        self >= 0 && UnsignedIsqrt::is_perfect_square(self as u64)
     }
 }
 
 impl UnsignedIsqrt for u64 {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
     fn isqrt(self) -> Self {
         // This proof of correctness is a corrected version of the flawed proof at
         // https://web.archive.org/web/20220118185505/https://www.codecodex.com/wiki/Calculate_an_integer_square_root#Java
@@ -233,9 +617,17 @@ impl UnsignedIsqrt for u64 {
 
         // Is there some way to avoid the plus one case based on the distance divergence above?
 
-        // Avoid overflows when getting the result squared or the result plus one squared.
-        let result = if self < ((1 << 32) - 2) * ((1 << 32) - 2) {
-            let result = (self as f64).sqrt();
+        // Below 2^52, the conversion to `f64` is exact, and the correctly rounded square root of an exact integer
+        // never rounds up to the next integer, so no correction is needed.
+        //
+        // Otherwise, avoid overflows when getting the result squared or the result plus one squared.
+        let result = if self < 1 << 52 {
+            let result = crate::sqrt_f64(self as f64);
+            // SAFETY: Guaranteed to not be a NaN or an infinity and to, except for the fractional part, be in `u64`
+            // range.
+            unsafe { result.to_int_unchecked::<u64>() }
+        } else if self < ((1 << 32) - 2) * ((1 << 32) - 2) {
+            let result = crate::sqrt_f64(self as f64);
             // SAFETY: Guaranteed to not be a NaN or an infinity and to, except for the fractional part, be in `u64`
             // range.
             let result = unsafe { result.to_int_unchecked::<u64>() };
@@ -253,29 +645,116 @@ impl UnsignedIsqrt for u64 {
             (1 << 32) - 1
         };
 
+        crate::debug_assert_isqrt(self as u128, result as u128);
+
         // SAFETY: the result fits in an integer with half as many bits.
         // Inform the optimizer about it.
         unsafe {
-            intrinsics::assume(result < 1 << ((Self::BITS as Self) >> 1));
+            crate::assume(result < 1 << ((Self::BITS as Self) >> 1));
         }
 
         result
     }
+
+    #[inline]
+    fn ceil_isqrt(self) -> Self {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        if sqrt * sqrt == self {
+            sqrt
+        } else {
+            sqrt + 1
+        }
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        sqrt * sqrt == self
+    }
+    #[inline]
+    fn prev_perfect_square(self) -> Self {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        sqrt * sqrt
+    }
+    #[inline]
+    fn next_perfect_square(self) -> Self {
+        UnsignedIsqrt::checked_next_perfect_square(self)
+            .expect("no larger perfect square fits in the type")
+    }
+    #[inline]
+    fn checked_next_perfect_square(self) -> Option<Self> {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.checked_mul(sqrt)
+    }
+    #[inline]
+    fn overflowing_next_perfect_square(self) -> (Self, bool) {
+        // As in `checked_next_perfect_square`, `sqrt + 1` can't overflow.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.overflowing_mul(sqrt)
+    }
+    #[inline]
+    fn isqrt_exact(self) -> Option<Self> {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        (sqrt * sqrt == self).then_some(sqrt)
+    }
+    #[inline]
+    fn isqrt_with_exactness(self) -> (Self, bool) {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        (sqrt, sqrt * sqrt == self)
+    }
+    #[inline]
+    fn isqrt_f64(self) -> f64 {
+        isqrt_f64_from_isqrt(self as u128, UnsignedIsqrt::isqrt(self) as u128)
+    }
+}
+
+/// Returns the integer square root of `n`, as `u64`'s `UnsignedIsqrt::isqrt` does, but corrects the floating-point
+/// result with arithmetic on comparisons instead of branches.
+///
+/// Whether this beats the usual method depends on the target and on how predictable the inputs are, so benchmark both.
+/// On x86_64, the usual method benchmarks faster, even on inputs whose corrections are unpredictable.
+#[inline]
+pub fn isqrt_u64_branchless(n: u64) -> u64 {
+    // As proven in `u64`'s `isqrt`, the floored floating-point square root is the answer plus -1, 0, or 1.
+    //
+    // The floating-point square root only reaches 2^32 when `n` rounds up to 2^64, where the answer is 2^32 - 1, so
+    // clamping to that keeps the result in range without changing the answer. Then `result * result` can't overflow.
+    // After correcting downward, `n - result * result` can't underflow, and `(result + 1)^2 <= n` exactly when
+    // `n - result * result > 2 * result`, which avoids overflowing `(result + 1)^2` near `u64::MAX`.
+    //
+    // SAFETY: Guaranteed to not be a NaN or an infinity and to, except for the fractional part, be in `u64` range.
+    let result = unsafe { crate::sqrt_f64(n as f64).to_int_unchecked::<u64>() }.min((1 << 32) - 1);
+    let result = result - (n < result * result) as u64;
+    let result = result + (n - result * result > result << 1) as u64;
+
+    crate::debug_assert_isqrt(n as u128, result as u128);
+
+    // SAFETY: the result fits in an integer with half as many bits.
+    // Inform the optimizer about it.
+    unsafe {
+        crate::assume(result < 1 << (u64::BITS >> 1));
+    }
+
+    result
 }
 
 impl SignedIsqrt for i128 {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
     #[inline]
     fn checked_isqrt(self) -> Option<Self> {
         if self < 0 {
             None
         } else {
-            let result = (self as u128).isqrt() as Self;
+            let result = isqrt_u128(self as u128) as Self;
 
             // SAFETY: the result is nonnegative and less than or equal to `i128::MAX.isqrt()`.
             // Inform the optimizer about it.
             unsafe {
-                intrinsics::assume(0 <= result);
-                intrinsics::assume(result <= 13_043_817_825_332_782_212);
+                crate::assume(0 <= result);
+                crate::assume(result <= <Self as SignedIsqrt>::ISQRT_MAX);
             }
 
             Some(result)
@@ -284,54 +763,354 @@ impl SignedIsqrt for i128 {
 
     #[inline]
     fn isqrt(self) -> Self {
-        self.checked_isqrt()
-            .expect("argument of integer square root must be non-negative")
+        SignedIsqrt::checked_isqrt(self).unwrap_or_else(crate::negative_isqrt_argument)
+    }
+
+    #[inline]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+        SignedIsqrt::checked_isqrt(self).ok_or(crate::NegativeInputError)
+    }
+
+    #[inline]
+    fn isqrt_or(self, default: Self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(default)
+    }
+
+    #[inline]
+    fn isqrt_or_zero(self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(0)
+    }
+
+    #[inline]
+    fn checked_ceil_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_isqrt(self)
+            .map(|sqrt| if sqrt * sqrt == self { sqrt } else { sqrt + 1 })
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        self >= 0 && UnsignedIsqrt::is_perfect_square(self as u128)
     }
 }
 
-impl UnsignedIsqrt for u128 {
-    fn isqrt(mut self) -> Self {
-        // Performs a Karatsuba square root.
-        // https://web.archive.org/web/20230511212802/https://inria.hal.science/inria-00072854v1/file/RR-3805.pdf
+/// Returns the integer square root of `n`. Usable in `const` contexts.
+///
+/// At compile time, this uses the `karatsuba` module's integer method. At runtime, it uses this module's method, except
+/// on stable builds, which can't tell the two apart and so always use the integer method.
+#[inline]
+pub const fn isqrt_u128(n: u128) -> u128 {
+    #[cfg(not(feature = "nightly"))]
+    return crate::karatsuba::isqrt_u128(n);
 
-        type HalfBitsT = u64;
+    // SAFETY: both functions return the integer square root, so identical inputs give identical results.
+    #[cfg(feature = "nightly")]
+    unsafe {
+        intrinsics::const_eval_select((n,), crate::karatsuba::isqrt_u128, floating_isqrt_128)
+    }
+}
 
-        const HALF_BITS: u32 = HalfBitsT::BITS;
-        const QUARTER_BITS: u32 = HalfBitsT::BITS >> 1;
+/// Returns the integer square root of `n`, or `None` if `n` is negative. Usable in `const` contexts.
+#[inline]
+pub const fn checked_isqrt_i128(n: i128) -> Option<i128> {
+    if n < 0 {
+        None
+    } else {
+        Some(isqrt_u128(n as u128) as i128)
+    }
+}
 
-        let leading_zeros = self.leading_zeros();
-        let result = if leading_zeros >= HALF_BITS {
-            (self as HalfBitsT).isqrt() as Self
-        } else {
-            // Either the most-significant bit or its neighbor must be a one, so we shift left to make that happen.
-            let precondition_shift = leading_zeros & (HALF_BITS - 2);
-            self <<= precondition_shift;
+fn floating_isqrt_128(mut n: u128) -> u128 {
+    // Performs a Karatsuba square root.
+    // https://web.archive.org/web/20230511212802/https://inria.hal.science/inria-00072854v1/file/RR-3805.pdf
 
-            let hi = (self >> HALF_BITS) as HalfBitsT;
-            let lo = self & (HalfBitsT::MAX as Self);
+    type HalfBitsT = u64;
 
-            let s_prime = hi.isqrt();
-            let r_prime = hi - s_prime * s_prime;
+    const HALF_BITS: u32 = HalfBitsT::BITS;
+    const QUARTER_BITS: u32 = HalfBitsT::BITS >> 1;
 
-            let numerator = ((r_prime as Self) << QUARTER_BITS) | (lo >> QUARTER_BITS);
-            let denominator = (s_prime as Self) << 1;
+    let leading_zeros = n.leading_zeros();
+    if leading_zeros >= HALF_BITS {
+        UnsignedIsqrt::isqrt(n as HalfBitsT) as u128
+    } else {
+        // Either the most-significant bit or its neighbor must be a one, so we shift left to make that happen.
+        let precondition_shift = leading_zeros & (HALF_BITS - 2);
+        n <<= precondition_shift;
 
-            let q = numerator / denominator;
-            let u = numerator % denominator;
+        let hi = (n >> HALF_BITS) as HalfBitsT;
+        let lo = n & (HalfBitsT::MAX as u128);
 
-            let mut s = (s_prime << QUARTER_BITS) as Self + q;
-            if (u << QUARTER_BITS) | (lo & ((1 << QUARTER_BITS) - 1)) < q * q {
-                s -= 1;
-            }
-            s >> (precondition_shift >> 1)
-        };
+        let s_prime = UnsignedIsqrt::isqrt(hi);
+        let r_prime = hi - s_prime * s_prime;
+
+        let numerator = ((r_prime as u128) << QUARTER_BITS) | (lo >> QUARTER_BITS);
+        let denominator = (s_prime as u128) << 1;
+
+        let q = numerator / denominator;
+        let u = numerator % denominator;
+
+        let mut s = (s_prime << QUARTER_BITS) as u128 + q;
+        if (u << QUARTER_BITS) | (lo & ((1 << QUARTER_BITS) - 1)) < q * q {
+            s -= 1;
+        }
+        s >> (precondition_shift >> 1)
+    }
+}
+
+/// Returns the integer square root of `n`, as `u128`'s `UnsignedIsqrt::isqrt` does, but from one integer Newton step
+/// seeded with the `f64` square root instead of a Karatsuba step.
+///
+/// Whether this beats the usual method depends on how fast the target divides a `u128` by a `u128`, so benchmark both.
+/// On x86_64, the two benchmark within noise of each other on inputs of at least 2^64.
+#[inline]
+pub fn isqrt_u128_newton(n: u128) -> u128 {
+    if n <= u64::MAX as u128 {
+        return UnsignedIsqrt::isqrt(n as u64) as u128;
+    }
+
+    // Let `r = sqrt(n)` be the exact square root and `s = floor(r)` the result. As `2^64 <= n < 2^128`, `2^32 <= r < 2^64`.
+    //
+    // The seed: `n as f64` is within a relative `2^-53` of `n`, which puts its square root within a relative `2^-54` of
+    // `r`, and the correctly rounded `sqrt` adds at most another `2^-53`. So the seed is within a relative `2^-52` of
+    // `r`, which is less than `2^12` in absolute terms, as `r < 2^64`. Truncating it adds less than one more, so
+    // `|x - r| < 2^12 + 1`, which also keeps `x` positive.
+    //
+    // The Newton step: for integers, `floor((x + floor(n / x)) / 2) = floor((x + n / x) / 2)`, and
+    // `(x + n / x) / 2 - r = (x - r)^2 / (2 * x)`. That's nonnegative, and less than `(2^12 + 1)^2 / (2 * (2^32 - 2^13))`,
+    // which is below `2^-8`. So the step gives the floor of a number in `[r, r + 2^-8)`, which is `s` or `s + 1`.
+    //
+    // The correction: `s + 1` can be `2^64` when `n` is near `u128::MAX`, whose square overflows, but `s` can't exceed
+    // `u64::MAX`, so clamping to that first keeps the result `s` or `s + 1`. Then squaring can't overflow, and one
+    // comparison tells the two apart.
+    // SAFETY: the seed is finite and, as above, between `2^32 - 2^12 - 1` and `2^64`, so it fits in `u128`.
+    let x = unsafe { crate::sqrt_f64(n as f64).to_int_unchecked::<u128>() };
+    let x = ((x + n / x) >> 1).min(u64::MAX as u128);
+    let result = x - (n < x * x) as u128;
+
+    crate::debug_assert_isqrt(n, result);
+
+    // SAFETY: the result fits in an integer with half as many bits.
+    // Inform the optimizer about it.
+    unsafe {
+        crate::assume(result < 1 << (u128::BITS >> 1));
+    }
+
+    result
+}
+
+impl UnsignedIsqrt for u128 {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
+    #[inline]
+    fn isqrt(self) -> Self {
+        let result = isqrt_u128(self);
+
+        crate::debug_assert_isqrt(self, result);
 
         // SAFETY: the result fits in an integer with half as many bits.
         // Inform the optimizer about it.
         unsafe {
-            intrinsics::assume(result < 1 << ((Self::BITS as Self) >> 1));
+            crate::assume(result < 1 << ((Self::BITS as Self) >> 1));
         }
 
         result
     }
+
+    #[inline]
+    fn ceil_isqrt(self) -> Self {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        if sqrt * sqrt == self {
+            sqrt
+        } else {
+            sqrt + 1
+        }
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        sqrt * sqrt == self
+    }
+    #[inline]
+    fn prev_perfect_square(self) -> Self {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        sqrt * sqrt
+    }
+    #[inline]
+    fn next_perfect_square(self) -> Self {
+        UnsignedIsqrt::checked_next_perfect_square(self)
+            .expect("no larger perfect square fits in the type")
+    }
+    #[inline]
+    fn checked_next_perfect_square(self) -> Option<Self> {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.checked_mul(sqrt)
+    }
+    #[inline]
+    fn overflowing_next_perfect_square(self) -> (Self, bool) {
+        // As in `checked_next_perfect_square`, `sqrt + 1` can't overflow.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.overflowing_mul(sqrt)
+    }
+    #[inline]
+    fn isqrt_exact(self) -> Option<Self> {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        (sqrt * sqrt == self).then_some(sqrt)
+    }
+    #[inline]
+    fn isqrt_with_exactness(self) -> (Self, bool) {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        (sqrt, sqrt * sqrt == self)
+    }
+    #[inline]
+    fn isqrt_f64(self) -> f64 {
+        isqrt_f64_from_isqrt(self, UnsignedIsqrt::isqrt(self))
+    }
+}
+
+// `isize` and `usize` forward to the fixed-width type of the same size, which keeps its `assume` hints.
+impl SignedIsqrt for isize {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
+    #[inline]
+    fn checked_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_isqrt(self as crate::IsizeDelegate).map(|sqrt| sqrt as Self)
+    }
+
+    #[inline]
+    fn isqrt(self) -> Self {
+        SignedIsqrt::isqrt(self as crate::IsizeDelegate) as Self
+    }
+
+    #[inline]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+        SignedIsqrt::checked_isqrt(self).ok_or(crate::NegativeInputError)
+    }
+
+    #[inline]
+    fn isqrt_or(self, default: Self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(default)
+    }
+
+    #[inline]
+    fn isqrt_or_zero(self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(0)
+    }
+
+    #[inline]
+    fn checked_ceil_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_ceil_isqrt(self as crate::IsizeDelegate).map(|sqrt| sqrt as Self)
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        SignedIsqrt::is_perfect_square(self as crate::IsizeDelegate)
+    }
+}
+
+impl UnsignedIsqrt for usize {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
+    #[inline]
+    fn isqrt(self) -> Self {
+        UnsignedIsqrt::isqrt(self as crate::UsizeDelegate) as Self
+    }
+
+    #[inline]
+    fn ceil_isqrt(self) -> Self {
+        UnsignedIsqrt::ceil_isqrt(self as crate::UsizeDelegate) as Self
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        UnsignedIsqrt::is_perfect_square(self as crate::UsizeDelegate)
+    }
+    #[inline]
+    fn prev_perfect_square(self) -> Self {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        sqrt * sqrt
+    }
+    #[inline]
+    fn next_perfect_square(self) -> Self {
+        UnsignedIsqrt::checked_next_perfect_square(self)
+            .expect("no larger perfect square fits in the type")
+    }
+    #[inline]
+    fn checked_next_perfect_square(self) -> Option<Self> {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.checked_mul(sqrt)
+    }
+    #[inline]
+    fn overflowing_next_perfect_square(self) -> (Self, bool) {
+        // As in `checked_next_perfect_square`, `sqrt + 1` can't overflow.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.overflowing_mul(sqrt)
+    }
+    #[inline]
+    fn isqrt_exact(self) -> Option<Self> {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        (sqrt * sqrt == self).then_some(sqrt)
+    }
+    #[inline]
+    fn isqrt_with_exactness(self) -> (Self, bool) {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        (sqrt, sqrt * sqrt == self)
+    }
+    #[inline]
+    fn isqrt_f64(self) -> f64 {
+        UnsignedIsqrt::isqrt_f64(self as crate::UsizeDelegate)
+    }
 }
+
+nonzero_unsigned_isqrt!(
+    unsigned {
+        #[inline]
+        fn isqrt_f64(self) -> f64 {
+            UnsignedIsqrt::isqrt_f64(self.get())
+        }
+    }
+);
+
+wrapper_isqrt!(Wrapping, Saturating;
+    signed {}
+    unsigned {
+        #[inline]
+        fn isqrt_f64(self) -> f64 {
+            UnsignedIsqrt::isqrt_f64(self.0)
+        }
+    }
+);
+
+/// Adds an estimate of the fractional part of the square root of `n` to `sqrt`, its integer square root, without letting
+/// rounding change the integer part.
+fn isqrt_f64_from_isqrt(n: u128, sqrt: u128) -> f64 {
+    let whole = sqrt as f64;
+    if whole as u128 > sqrt {
+        // Only square roots above `2^53` can round, and there the fractional part is lost anyway.
+        return f64::from_bits(whole.to_bits() - 1);
+    } else if whole as u128 != sqrt {
+        return whole;
+    }
+
+    let remainder = n - sqrt * sqrt;
+    if remainder == 0 {
+        return whole;
+    }
+
+    // `sqrt(n) - sqrt = remainder / (sqrt(n) + sqrt)`, which, unlike the difference, doesn't cancel out the significant
+    // bits of the estimate.
+    let fraction = remainder as f64 / (crate::sqrt_f64(n as f64) + whole);
+    let root = whole + fraction;
+
+    // Rounding the sum can carry it up to the next integer.
+    let next = whole + 1.0;
+    if root >= next {
+        f64::from_bits(next.to_bits() - 1)
+    } else {
+        root
+    }
+}
+
+int_isqrt!();
@@ -1,41 +1,137 @@
 #![allow(unused_unsafe)]
 
+use crate::tables::{ISQRT_8_BIT, ISQRT_AND_REMAINDER_8_BIT};
+#[cfg(all(feature = "nightly", not(feature = "wasm-no-float")))]
 use core::intrinsics;
 
-pub trait SignedIsqrt: Sized {
+/// Integer square roots of the signed integer types.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+pub trait SignedIsqrt: Sized + crate::sealed::Sealed {
+    /// The square root of `Self::MAX`, which no square root exceeds.
+    const ISQRT_MAX: Self;
+
+    #[must_use = "this returns the result of the operation, without modifying the original"]
     fn checked_isqrt(self) -> Option<Self>;
+    #[must_use = "this returns the result of the operation, without modifying the original"]
     fn isqrt(self) -> Self;
+    /// Returns the square root, or a `NegativeInputError` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError>;
+    /// Returns the square root, or `default` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_or(self, default: Self) -> Self;
+    /// Returns the square root, or zero if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_or_zero(self) -> Self;
+    /// Returns the square root, or zero if `self` is negative, which clamps negative arguments to zero first.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    fn saturating_isqrt(self) -> Self {
+        SignedIsqrt::isqrt_or_zero(self)
+    }
+    /// Returns the smallest integer whose square is at least `self`, or `None` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_ceil_isqrt(self) -> Option<Self>;
+    /// Returns whether `self` is the square of an integer. Negative numbers never are.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn is_perfect_square(self) -> bool;
 }
-pub trait UnsignedIsqrt {
+/// Integer square roots of the unsigned integer types.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+pub trait UnsignedIsqrt: Sized + crate::sealed::Sealed {
+    /// The square root of `Self::MAX`, which no square root exceeds.
+    const ISQRT_MAX: Self;
+    /// The type of results that can be zero even when `self` isn't, such as remainders: `Self`, except for the `NonZero`
+    /// types, where it's the integer type they wrap.
+    type Int;
+
+    #[must_use = "this returns the result of the operation, without modifying the original"]
     fn isqrt(self) -> Self;
+    /// Returns the largest integer whose square is at most `self`, the same as `isqrt`, named to match `ceil_isqrt`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    fn isqrt_floor(self) -> Self {
+        UnsignedIsqrt::isqrt(self)
+    }
+    /// Returns the smallest integer whose square is at least `self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn ceil_isqrt(self) -> Self;
+    /// Returns whether `self` is the square of an integer.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn is_perfect_square(self) -> bool;
+    /// Returns the largest perfect square that is at most `self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn prev_perfect_square(self) -> Self;
+    /// Returns the smallest perfect square greater than `self`.
+    ///
+    /// Panics if that square doesn't fit in `Self`, which happens when `self` is at least the largest perfect square
+    /// that does (for example, 225 for `u8`).
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn next_perfect_square(self) -> Self;
+    /// Returns the smallest perfect square greater than `self`, or `None` if it doesn't fit in `Self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_next_perfect_square(self) -> Option<Self>;
+    /// Returns the smallest perfect square greater than `self`, along with whether it overflowed `Self`. If it did,
+    /// which happens when `self` is at least the largest perfect square that fits, the value wraps around to zero.
+    ///
+    /// The `NonZero` types can't hold zero, so for them the value is one instead. That isn't the inner integer's
+    /// wrapped value, so check the flag rather than comparing the value with zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_next_perfect_square(self) -> (Self, bool);
+    /// Returns the square root if `self` is a perfect square, or `None` otherwise.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_exact(self) -> Option<Self>;
+    /// Returns the integer square root along with whether `self` is a perfect square.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_with_exactness(self) -> (Self, bool);
+    /// Returns the integer square root along with the remainder, `self - self.isqrt().pow(2)`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_rem(self) -> (Self, Self::Int);
 }
 
 macro_rules! sqrt_impls {
-    ($signed_type:ty, $unsigned_type:ty, $const_isqrt:ident, $fast_isqrt:ident, $combined_isqrt:ident) => {
+    ($signed_type:ty, $unsigned_type:ty, $const_isqrt:ident, $fast_isqrt:ident, $combined_isqrt:ident, $public_isqrt:ident) => {
         #[inline(always)]
         const fn $combined_isqrt(n: $unsigned_type) -> $unsigned_type {
+            // Targets without floating-point support use the integer method at runtime too, as do stable builds,
+            // which can't choose a method based on whether they're running at compile time.
+            #[cfg(any(feature = "wasm-no-float", not(feature = "nightly")))]
+            return $const_isqrt(n);
+
             // SAFETY: identical inputs to both functions give identical results.
-            unsafe { intrinsics::const_eval_select((n,), $const_isqrt, $fast_isqrt) }
+            #[cfg(all(feature = "nightly", not(feature = "wasm-no-float")))]
+            unsafe {
+                intrinsics::const_eval_select((n,), $const_isqrt, $fast_isqrt)
+            }
+        }
+
+        /// Returns the integer square root of `n`. Usable in `const` contexts, where it uses the Karatsuba method; at
+        /// runtime, it uses the same method as the trait.
+        #[inline]
+        pub const fn $public_isqrt(n: $unsigned_type) -> $unsigned_type {
+            $combined_isqrt(n)
         }
 
         impl SignedIsqrt for $signed_type {
+            const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
             #[inline]
             fn checked_isqrt(self) -> Option<Self> {
+                #[cfg(feature = "runtime-self-check")]
+                self_check();
+
                 if self < 0 {
                     None
                 } else {
                     let result: Self = $combined_isqrt(self as $unsigned_type) as Self;
 
-                    // Make sure to use this `const` rather than just calculating it in `assume` below. Doing so
-                    // ensures that the calculation is done at compile-time rather than during every single `isqrt`
-                    // call.
-                    const MAX_RESULT: $signed_type =
-                        $const_isqrt(<$signed_type>::MAX as $unsigned_type) as $signed_type;
-                    // SAFETY: the result is nonnegative and less than or equal to `i8::MAX.isqrt()`.
-                    // Inform the optimizer about it.
+                    // SAFETY: the result is nonnegative and less than or equal to `ISQRT_MAX`, which is computed at
+                    // compile time. Inform the optimizer about it.
                     unsafe {
-                        intrinsics::assume(0 <= result);
-                        intrinsics::assume(result <= MAX_RESULT);
+                        crate::assume(0 <= result);
+                        crate::assume(result <= <Self as SignedIsqrt>::ISQRT_MAX);
                     }
 
                     Some(result)
@@ -44,27 +140,118 @@ macro_rules! sqrt_impls {
 
             #[inline]
             fn isqrt(self) -> Self {
-                self.checked_isqrt()
-                    .expect("argument of integer square root must be non-negative")
+                SignedIsqrt::checked_isqrt(self).unwrap_or_else(crate::negative_isqrt_argument)
+            }
+
+            #[inline]
+            fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+                SignedIsqrt::checked_isqrt(self).ok_or(crate::NegativeInputError)
+            }
+
+            #[inline]
+            fn isqrt_or(self, default: Self) -> Self {
+                SignedIsqrt::checked_isqrt(self).unwrap_or(default)
+            }
+
+            #[inline]
+            fn isqrt_or_zero(self) -> Self {
+                SignedIsqrt::checked_isqrt(self).unwrap_or(0)
+            }
+
+            #[inline]
+            fn checked_ceil_isqrt(self) -> Option<Self> {
+                SignedIsqrt::checked_isqrt(self).map(|sqrt| {
+                    if sqrt * sqrt == self {
+                        sqrt
+                    } else {
+                        sqrt + 1
+                    }
+                })
+            }
+
+            #[inline]
+            fn is_perfect_square(self) -> bool {
+                self >= 0 && UnsignedIsqrt::is_perfect_square(self as $unsigned_type)
             }
         }
 
         impl UnsignedIsqrt for $unsigned_type {
+            const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+            type Int = Self;
+
             #[inline(always)]
             fn isqrt(self) -> Self {
+                #[cfg(feature = "runtime-self-check")]
+                self_check();
+
                 let result = $combined_isqrt(self);
 
-                // Make sure to use this `const` rather than just calculating it in `assume` below. Doing so ensures
-                // that the calculation is done at compile-time rather than during every single `isqrt` call.
-                const MAX_RESULT: $unsigned_type = $const_isqrt(<$unsigned_type>::MAX);
-                // SAFETY: The square root cannot exceed the square root of the maximum input.
-                // Inform the optimizer.
+                crate::debug_assert_isqrt(self as u128, result as u128);
+
+                // SAFETY: The square root cannot exceed the square root of the maximum input, `ISQRT_MAX`, which is
+                // computed at compile time. Inform the optimizer.
                 unsafe {
-                    intrinsics::assume(result <= MAX_RESULT);
+                    crate::assume(result <= <Self as UnsignedIsqrt>::ISQRT_MAX);
                 }
 
                 result
             }
+
+            #[inline]
+            fn isqrt_rem(self) -> (Self, Self) {
+                // The floating-point method doesn't produce a remainder, so square its result.
+                let s = UnsignedIsqrt::isqrt(self);
+                (s, self - s * s)
+            }
+
+            #[inline]
+            fn ceil_isqrt(self) -> Self {
+                // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+                let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+                if remainder == 0 {
+                    sqrt
+                } else {
+                    sqrt + 1
+                }
+            }
+
+            #[inline]
+            fn is_perfect_square(self) -> bool {
+                // The remainder is zero exactly for perfect squares.
+                UnsignedIsqrt::isqrt_rem(self).1 == 0
+            }
+            #[inline]
+            fn prev_perfect_square(self) -> Self {
+                let sqrt = UnsignedIsqrt::isqrt(self);
+                sqrt * sqrt
+            }
+            #[inline]
+            fn next_perfect_square(self) -> Self {
+                UnsignedIsqrt::checked_next_perfect_square(self)
+                    .expect("no larger perfect square fits in the type")
+            }
+            #[inline]
+            fn checked_next_perfect_square(self) -> Option<Self> {
+                // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+                let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+                sqrt.checked_mul(sqrt)
+            }
+            #[inline]
+            fn overflowing_next_perfect_square(self) -> (Self, bool) {
+                // As in `checked_next_perfect_square`, `sqrt + 1` can't overflow.
+                let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+                sqrt.overflowing_mul(sqrt)
+            }
+            #[inline]
+            fn isqrt_exact(self) -> Option<Self> {
+                let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+                (remainder == 0).then_some(sqrt)
+            }
+            #[inline]
+            fn isqrt_with_exactness(self) -> (Self, bool) {
+                let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+                (sqrt, remainder == 0)
+            }
         }
     };
 }
@@ -74,82 +261,61 @@ sqrt_impls!(
     u8,
     karatsuba_isqrt_8,
     karatsuba_isqrt_8,
-    combined_isqrt_8
+    combined_isqrt_8,
+    isqrt_u8
 );
 sqrt_impls!(
     i16,
     u16,
     karatsuba_isqrt_16,
     floating_isqrt_16,
-    combined_isqrt_16
+    combined_isqrt_16,
+    isqrt_u16
 );
 sqrt_impls!(
     i32,
     u32,
     karatsuba_isqrt_32,
     floating_isqrt_32,
-    combined_isqrt_32
+    combined_isqrt_32,
+    isqrt_u32
 );
 sqrt_impls!(
     i64,
     u64,
     karatsuba_isqrt_64,
     floating_isqrt_64,
-    combined_isqrt_64
+    combined_isqrt_64,
+    isqrt_u64
 );
 sqrt_impls!(
     i128,
     u128,
     karatsuba_isqrt_128,
     floating_isqrt_128,
-    combined_isqrt_128
+    combined_isqrt_128,
+    isqrt_u128
 );
 
-/*** KARATSUBA METHOD ***/
-
-const ISQRT_8_BIT: [u8; 256] = {
-    let mut result = [0; 256];
-
-    let mut sqrt = 0;
-    let mut i = 0;
-    'outer: loop {
-        let mut remaining = 2 * sqrt + 1;
-        while remaining > 0 {
-            result[i as usize] = sqrt;
-            i += 1;
-            if i >= result.len() {
-                break 'outer;
-            }
-
-            remaining -= 1;
-        }
-        sqrt += 1;
-    }
-
-    result
-};
-
-// The first three bits of each entry are the last three bits of the square root. The next five bits are the remainder.
-const ISQRT_AND_REMAINDER_8_BIT: [u8; 256] = {
-    let mut result = [0; 256];
-
-    let mut sqrt = 0;
-    let mut i = 0;
-    'outer: loop {
-        let mut remaining = 2 * sqrt + 1;
-        while remaining > 0 {
-            result[i as usize] = (sqrt << 5) | (2 * sqrt + 1 - remaining);
-            i += 1;
-            if i >= result.len() {
-                break 'outer;
-            }
-            remaining -= 1;
-        }
-        sqrt += 1;
-    }
+/// Panics if the methods used at runtime don't pass `self_check::sweep`, which only runs on the first call.
+#[cfg(feature = "runtime-self-check")]
+fn self_check() {
+    static PASSED: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+
+    let passed = *PASSED.get_or_init(|| {
+        crate::self_check::sweep(8, |n| combined_isqrt_8(n as u8) as u128)
+            && crate::self_check::sweep(16, |n| combined_isqrt_16(n as u16) as u128)
+            && crate::self_check::sweep(32, |n| combined_isqrt_32(n as u32) as u128)
+            && crate::self_check::sweep(64, |n| combined_isqrt_64(n as u64) as u128)
+            && crate::self_check::sweep(128, combined_isqrt_128)
+    });
+    assert!(
+        passed,
+        "integer square root failed its runtime self-check on this hardware"
+    );
+}
 
-    result
-};
+/*** KARATSUBA METHOD ***/
 
 #[inline(always)]
 const fn karatsuba_isqrt_8(n: u8) -> u8 {
@@ -237,9 +403,16 @@ macro_rules! karatsuba_isqrt {
                     r = r.wrapping_add((s << 1) - 1);
                     s -= 1;
                 }
+
+                // Undo the precondition shift. With `k = precondition_shift >> 1` and `t` the lowest `k` bits of `s`,
+                // the unshifted square root is `s >> k`, and the unshifted remainder is
+                // `(n - (s - t)^2) >> precondition_shift`. As `n = s^2 + r`, that's
+                // `(r + t * (2 * s - t)) >> precondition_shift`.
+                let result_shift = precondition_shift >> 1;
+                let t = s & ((1 << result_shift) - 1);
                 (
-                    s >> (precondition_shift >> 1),
-                    r >> (precondition_shift >> 1),
+                    s >> result_shift,
+                    (r + t * ((s << 1) - t)) >> precondition_shift,
                 )
             };
 
@@ -286,17 +459,17 @@ karatsuba_isqrt!(
 #[allow(dead_code)]
 #[inline]
 fn floating_isqrt_8(n: u8) -> u8 {
-    (n as f32).sqrt() as u8
+    crate::sqrt_f32(n as f32) as u8
 }
 
 #[inline]
 fn floating_isqrt_16(n: u16) -> u16 {
-    (n as f32).sqrt() as u16
+    crate::sqrt_f32(n as f32) as u16
 }
 
 #[inline]
 fn floating_isqrt_32(n: u32) -> u32 {
-    (n as f64).sqrt() as u32
+    crate::sqrt_f64(n as f64) as u32
 }
 
 fn floating_isqrt_64(n: u64) -> u64 {
@@ -332,9 +505,13 @@ fn floating_isqrt_64(n: u64) -> u64 {
 
     // Avoid overflows when getting the result squared or the result plus one squared.
     if n < ((1 << 32) - 2) * ((1 << 32) - 2) {
-        let result = (n as f64).sqrt();
+        let result = crate::sqrt_f64(n as f64);
+        // Even if the process has set a non-default rounding mode, such as toward positive infinity, the conversion
+        // and the square root can each round up by at most one unit in the last place, which keeps the result far
+        // below 2^32, let alone 2^64. The correction below then handles the result being one too high.
+        debug_assert!(result < (1_u64 << 32) as f64);
         // SAFETY: Guaranteed to not be a NaN or an infinity and to, except for the fractional part, be in `u64`
-        // range.
+        // range, as checked above.
         let result = unsafe { result.to_int_unchecked::<u64>() };
         let result_squared = result * result;
         if n < result_squared {
@@ -371,7 +548,7 @@ fn floating_isqrt_128(mut n: u128) -> u128 {
         let hi = (n >> HALF_BITS) as HalfBitsT;
         let lo = n & (HalfBitsT::MAX as u128);
 
-        let s_prime = hi.isqrt();
+        let s_prime = floating_isqrt_64(hi);
         let r_prime = hi - s_prime * s_prime;
 
         let numerator = ((r_prime as u128) << QUARTER_BITS) | (lo >> QUARTER_BITS);
@@ -387,3 +564,129 @@ fn floating_isqrt_128(mut n: u128) -> u128 {
         s >> (precondition_shift >> 1)
     }
 }
+
+// `isize` and `usize` forward to the fixed-width type of the same size, which keeps its `assume` hints.
+impl SignedIsqrt for isize {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
+    #[inline]
+    fn checked_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_isqrt(self as crate::IsizeDelegate).map(|sqrt| sqrt as Self)
+    }
+
+    #[inline]
+    fn isqrt(self) -> Self {
+        SignedIsqrt::isqrt(self as crate::IsizeDelegate) as Self
+    }
+
+    #[inline]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+        SignedIsqrt::checked_isqrt(self).ok_or(crate::NegativeInputError)
+    }
+
+    #[inline]
+    fn isqrt_or(self, default: Self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(default)
+    }
+
+    #[inline]
+    fn isqrt_or_zero(self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(0)
+    }
+
+    #[inline]
+    fn checked_ceil_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_ceil_isqrt(self as crate::IsizeDelegate).map(|sqrt| sqrt as Self)
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        SignedIsqrt::is_perfect_square(self as crate::IsizeDelegate)
+    }
+}
+
+impl UnsignedIsqrt for usize {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+    type Int = Self;
+
+    #[inline]
+    fn isqrt(self) -> Self {
+        UnsignedIsqrt::isqrt(self as crate::UsizeDelegate) as Self
+    }
+
+    #[inline]
+    fn ceil_isqrt(self) -> Self {
+        UnsignedIsqrt::ceil_isqrt(self as crate::UsizeDelegate) as Self
+    }
+
+    #[inline]
+    fn isqrt_rem(self) -> (Self, Self) {
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self as crate::UsizeDelegate);
+        (sqrt as Self, remainder as Self)
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        UnsignedIsqrt::is_perfect_square(self as crate::UsizeDelegate)
+    }
+    #[inline]
+    fn prev_perfect_square(self) -> Self {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        sqrt * sqrt
+    }
+    #[inline]
+    fn next_perfect_square(self) -> Self {
+        UnsignedIsqrt::checked_next_perfect_square(self)
+            .expect("no larger perfect square fits in the type")
+    }
+    #[inline]
+    fn checked_next_perfect_square(self) -> Option<Self> {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.checked_mul(sqrt)
+    }
+    #[inline]
+    fn overflowing_next_perfect_square(self) -> (Self, bool) {
+        // As in `checked_next_perfect_square`, `sqrt + 1` can't overflow.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.overflowing_mul(sqrt)
+    }
+    #[inline]
+    fn isqrt_exact(self) -> Option<Self> {
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+        (remainder == 0).then_some(sqrt)
+    }
+    #[inline]
+    fn isqrt_with_exactness(self) -> (Self, bool) {
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+        (sqrt, remainder == 0)
+    }
+}
+
+nonzero_unsigned_isqrt!(
+    unsigned {
+        type Int = UnsignedInner;
+
+        #[inline]
+        fn isqrt_rem(self) -> (Self, Self::Int) {
+            let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self.get());
+            // SAFETY: the argument is at least one, so its square root is at least one.
+            (unsafe { Self::new_unchecked(sqrt) }, remainder)
+        }
+    }
+);
+
+wrapper_isqrt!(Wrapping, Saturating;
+    signed {}
+    unsigned {
+        type Int = Self;
+
+        #[inline]
+        fn isqrt_rem(self) -> (Self, Self) {
+            let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self.0);
+            (Self(sqrt), Self(remainder))
+        }
+    }
+);
+
+int_isqrt!();
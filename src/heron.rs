@@ -0,0 +1,358 @@
+use crate::tables::ISQRT_8_BIT;
+
+/// Integer square roots of the signed integer types.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+pub trait SignedIsqrt: Sized + crate::sealed::Sealed {
+    /// The square root of `Self::MAX`, which no square root exceeds.
+    const ISQRT_MAX: Self;
+
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_isqrt(self) -> Option<Self>;
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt(self) -> Self;
+    /// Returns the square root, or a `NegativeInputError` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError>;
+    /// Returns the square root, or `default` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_or(self, default: Self) -> Self;
+    /// Returns the square root, or zero if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_or_zero(self) -> Self;
+    /// Returns the square root, or zero if `self` is negative, which clamps negative arguments to zero first.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    fn saturating_isqrt(self) -> Self {
+        SignedIsqrt::isqrt_or_zero(self)
+    }
+    /// Returns the smallest integer whose square is at least `self`, or `None` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_ceil_isqrt(self) -> Option<Self>;
+    /// Returns whether `self` is the square of an integer. Negative numbers never are.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn is_perfect_square(self) -> bool;
+}
+/// Integer square roots of the unsigned integer types.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+pub trait UnsignedIsqrt: Sized + crate::sealed::Sealed {
+    /// The square root of `Self::MAX`, which no square root exceeds.
+    const ISQRT_MAX: Self;
+
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt(self) -> Self;
+    /// Returns the largest integer whose square is at most `self`, the same as `isqrt`, named to match `ceil_isqrt`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    fn isqrt_floor(self) -> Self {
+        UnsignedIsqrt::isqrt(self)
+    }
+    /// Returns the smallest integer whose square is at least `self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn ceil_isqrt(self) -> Self;
+    /// Returns whether `self` is the square of an integer.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn is_perfect_square(self) -> bool;
+    /// Returns the largest perfect square that is at most `self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn prev_perfect_square(self) -> Self;
+    /// Returns the smallest perfect square greater than `self`.
+    ///
+    /// Panics if that square doesn't fit in `Self`, which happens when `self` is at least the largest perfect square
+    /// that does (for example, 225 for `u8`).
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn next_perfect_square(self) -> Self;
+    /// Returns the smallest perfect square greater than `self`, or `None` if it doesn't fit in `Self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_next_perfect_square(self) -> Option<Self>;
+    /// Returns the smallest perfect square greater than `self`, along with whether it overflowed `Self`. If it did,
+    /// which happens when `self` is at least the largest perfect square that fits, the value wraps around to zero.
+    ///
+    /// The `NonZero` types can't hold zero, so for them the value is one instead. That isn't the inner integer's
+    /// wrapped value, so check the flag rather than comparing the value with zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_next_perfect_square(self) -> (Self, bool);
+    /// Returns the square root if `self` is a perfect square, or `None` otherwise.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_exact(self) -> Option<Self>;
+    /// Returns the integer square root along with whether `self` is a perfect square.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_with_exactness(self) -> (Self, bool);
+}
+
+const fn heron_isqrt_8(n: u8) -> u8 {
+    ISQRT_8_BIT[n as usize]
+}
+
+macro_rules! heron_isqrt {
+    ($FullBitsT:ty, $heron_isqrt:ident, $iterations:literal) => {
+        const fn $heron_isqrt(n: $FullBitsT) -> $FullBitsT {
+            // Seeds Heron's method with the square root of the input's most-significant byte, then refines it.
+            // https://en.wikipedia.org/wiki/Methods_of_computing_square_roots#Heron's_method
+
+            if n < 256 {
+                return ISQRT_8_BIT[n as usize] as $FullBitsT;
+            }
+
+            // Shift by an even amount so that the square root of the shifted input scales back up by a power of two.
+            // The shifted input is at least 64, so the table's square root of it rounded up is at or above the real
+            // square root and within a factor of 1 + 1/8 of it.
+            let shift = (<$FullBitsT>::BITS - 8 - n.leading_zeros() + 1) & !1;
+            let top = (n >> shift) as usize;
+            let mut x = (ISQRT_8_BIT[top] as $FullBitsT + 1) << (shift >> 1);
+
+            // Each iteration at least doubles the number of correct bits (the relative error goes from `e` to below
+            // `e^2 / 2`), so starting from 3 bits, the iteration counts are enough to get within one of the floored
+            // square root. Iterations starting at or above the floored square root never go below it.
+            let mut i = 0;
+            while i < $iterations {
+                x = (x + n / x) >> 1;
+                i += 1;
+            }
+
+            // `x` is now either the floored square root or one more than it.
+            if x > n / x {
+                x -= 1;
+            }
+
+            x
+        }
+    };
+}
+
+heron_isqrt!(u16, heron_isqrt_16, 2);
+heron_isqrt!(u32, heron_isqrt_32, 3);
+heron_isqrt!(u64, heron_isqrt_64, 4);
+heron_isqrt!(u128, heron_isqrt_128, 5);
+
+macro_rules! isqrt_impl {
+    ($signed_type:ty, $unsigned_type:ty, $heron_isqrt:ident) => {
+        impl SignedIsqrt for $signed_type {
+            const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
+            #[inline(always)]
+            fn checked_isqrt(self) -> Option<Self> {
+                (self >= 0).then(|| {
+                    let result = $heron_isqrt(self as _) as Self;
+
+                    // SAFETY: the result is nonnegative and less than or equal to `MAX.isqrt()`.
+                    // Inform the optimizer about it.
+                    unsafe {
+                        crate::assume(0 <= result);
+                        crate::assume(result <= <Self as SignedIsqrt>::ISQRT_MAX);
+                    }
+
+                    result
+                })
+            }
+
+            #[inline]
+            fn isqrt(self) -> Self {
+                SignedIsqrt::checked_isqrt(self).unwrap_or_else(crate::negative_isqrt_argument)
+            }
+
+            #[inline]
+            fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+                SignedIsqrt::checked_isqrt(self).ok_or(crate::NegativeInputError)
+            }
+
+            #[inline]
+            fn isqrt_or(self, default: Self) -> Self {
+                SignedIsqrt::checked_isqrt(self).unwrap_or(default)
+            }
+
+            #[inline]
+            fn isqrt_or_zero(self) -> Self {
+                SignedIsqrt::checked_isqrt(self).unwrap_or(0)
+            }
+
+            #[inline]
+            fn checked_ceil_isqrt(self) -> Option<Self> {
+                SignedIsqrt::checked_isqrt(self).map(|sqrt| {
+                    if sqrt * sqrt == self {
+                        sqrt
+                    } else {
+                        sqrt + 1
+                    }
+                })
+            }
+
+            #[inline]
+            fn is_perfect_square(self) -> bool {
+                self >= 0 && UnsignedIsqrt::is_perfect_square(self as $unsigned_type)
+            }
+        }
+
+        impl UnsignedIsqrt for $unsigned_type {
+            const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
+            #[inline(always)]
+            fn isqrt(self) -> Self {
+                let result = $heron_isqrt(self);
+
+                crate::debug_assert_isqrt(self as u128, result as u128);
+
+                // SAFETY: the result fits in an integer with half as many bits.
+                // Inform the optimizer about it.
+                unsafe {
+                    crate::assume(result < 1 << ((<$unsigned_type>::BITS as Self) >> 1));
+                }
+
+                result
+            }
+
+            #[inline]
+            fn ceil_isqrt(self) -> Self {
+                // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+                let sqrt = UnsignedIsqrt::isqrt(self);
+                if sqrt * sqrt == self {
+                    sqrt
+                } else {
+                    sqrt + 1
+                }
+            }
+
+            #[inline]
+            fn is_perfect_square(self) -> bool {
+                let sqrt = UnsignedIsqrt::isqrt(self);
+                sqrt * sqrt == self
+            }
+            #[inline]
+            fn prev_perfect_square(self) -> Self {
+                let sqrt = UnsignedIsqrt::isqrt(self);
+                sqrt * sqrt
+            }
+            #[inline]
+            fn next_perfect_square(self) -> Self {
+                UnsignedIsqrt::checked_next_perfect_square(self)
+                    .expect("no larger perfect square fits in the type")
+            }
+            #[inline]
+            fn checked_next_perfect_square(self) -> Option<Self> {
+                // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+                let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+                sqrt.checked_mul(sqrt)
+            }
+            #[inline]
+            fn overflowing_next_perfect_square(self) -> (Self, bool) {
+                // As in `checked_next_perfect_square`, `sqrt + 1` can't overflow.
+                let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+                sqrt.overflowing_mul(sqrt)
+            }
+            #[inline]
+            fn isqrt_exact(self) -> Option<Self> {
+                let sqrt = UnsignedIsqrt::isqrt(self);
+                (sqrt * sqrt == self).then_some(sqrt)
+            }
+            #[inline]
+            fn isqrt_with_exactness(self) -> (Self, bool) {
+                let sqrt = UnsignedIsqrt::isqrt(self);
+                (sqrt, sqrt * sqrt == self)
+            }
+        }
+    };
+}
+
+isqrt_impl!(i8, u8, heron_isqrt_8);
+isqrt_impl!(i16, u16, heron_isqrt_16);
+isqrt_impl!(i32, u32, heron_isqrt_32);
+isqrt_impl!(i64, u64, heron_isqrt_64);
+isqrt_impl!(i128, u128, heron_isqrt_128);
+
+// `isize` and `usize` forward to the fixed-width type of the same size, which keeps its `assume` hints.
+impl SignedIsqrt for isize {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
+    #[inline]
+    fn checked_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_isqrt(self as crate::IsizeDelegate).map(|sqrt| sqrt as Self)
+    }
+
+    #[inline]
+    fn isqrt(self) -> Self {
+        SignedIsqrt::isqrt(self as crate::IsizeDelegate) as Self
+    }
+
+    #[inline]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+        SignedIsqrt::checked_isqrt(self).ok_or(crate::NegativeInputError)
+    }
+
+    #[inline]
+    fn isqrt_or(self, default: Self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(default)
+    }
+
+    #[inline]
+    fn isqrt_or_zero(self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(0)
+    }
+
+    #[inline]
+    fn checked_ceil_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_ceil_isqrt(self as crate::IsizeDelegate).map(|sqrt| sqrt as Self)
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        SignedIsqrt::is_perfect_square(self as crate::IsizeDelegate)
+    }
+}
+
+impl UnsignedIsqrt for usize {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
+    #[inline]
+    fn isqrt(self) -> Self {
+        UnsignedIsqrt::isqrt(self as crate::UsizeDelegate) as Self
+    }
+
+    #[inline]
+    fn ceil_isqrt(self) -> Self {
+        UnsignedIsqrt::ceil_isqrt(self as crate::UsizeDelegate) as Self
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        UnsignedIsqrt::is_perfect_square(self as crate::UsizeDelegate)
+    }
+    #[inline]
+    fn prev_perfect_square(self) -> Self {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        sqrt * sqrt
+    }
+    #[inline]
+    fn next_perfect_square(self) -> Self {
+        UnsignedIsqrt::checked_next_perfect_square(self)
+            .expect("no larger perfect square fits in the type")
+    }
+    #[inline]
+    fn checked_next_perfect_square(self) -> Option<Self> {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.checked_mul(sqrt)
+    }
+    #[inline]
+    fn overflowing_next_perfect_square(self) -> (Self, bool) {
+        // As in `checked_next_perfect_square`, `sqrt + 1` can't overflow.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.overflowing_mul(sqrt)
+    }
+    #[inline]
+    fn isqrt_exact(self) -> Option<Self> {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        (sqrt * sqrt == self).then_some(sqrt)
+    }
+    #[inline]
+    fn isqrt_with_exactness(self) -> (Self, bool) {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        (sqrt, sqrt * sqrt == self)
+    }
+}
+
+nonzero_unsigned_isqrt!();
+
+wrapper_isqrt!(Wrapping, Saturating);
+
+int_isqrt!();
@@ -0,0 +1,78 @@
+//! An iterator adaptor that lazily takes the integer square root of each item, using the default module, `karatsuba`.
+
+use core::iter::FusedIterator;
+
+use crate::karatsuba::UnsignedIsqrt;
+
+/// Adds `isqrt` to iterators of unsigned integers.
+pub trait IsqrtIterator: Iterator + Sized {
+    /// Returns an iterator of the integer square roots of this iterator's items.
+    fn isqrt(self) -> Isqrt<Self>;
+}
+
+impl<I> IsqrtIterator for I
+where
+    I: Iterator,
+    I::Item: UnsignedIsqrt,
+{
+    #[inline]
+    fn isqrt(self) -> Isqrt<Self> {
+        Isqrt { iter: self }
+    }
+}
+
+/// An iterator of the integer square roots of another iterator's items.
+///
+/// This is returned by [`IsqrtIterator::isqrt`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Isqrt<I> {
+    iter: I,
+}
+
+impl<I> Iterator for Isqrt<I>
+where
+    I: Iterator,
+    I::Item: UnsignedIsqrt,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(UnsignedIsqrt::isqrt)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> DoubleEndedIterator for Isqrt<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: UnsignedIsqrt,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(UnsignedIsqrt::isqrt)
+    }
+}
+
+impl<I> ExactSizeIterator for Isqrt<I>
+where
+    I: ExactSizeIterator,
+    I::Item: UnsignedIsqrt,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I> FusedIterator for Isqrt<I>
+where
+    I: FusedIterator,
+    I::Item: UnsignedIsqrt,
+{
+}
@@ -1,11 +1,109 @@
-use core::intrinsics;
+use core::num::ParseIntError;
 
-pub trait SignedIsqrt: Sized {
+/// Integer square roots of the signed integer types.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+pub trait SignedIsqrt: Sized + crate::sealed::Sealed {
+    /// The unsigned type with half as many bits, which can hold every square root (or `u8` for `i8`).
+    type Root;
+
+    /// The square root of `Self::MAX`, which no square root exceeds.
+    const ISQRT_MAX: Self;
+
+    #[must_use = "this returns the result of the operation, without modifying the original"]
     fn checked_isqrt(self) -> Option<Self>;
+    #[must_use = "this returns the result of the operation, without modifying the original"]
     fn isqrt(self) -> Self;
+    /// Returns the square root, or a `NegativeInputError` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError>;
+    /// Returns the square root, or `default` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_or(self, default: Self) -> Self;
+    /// Returns the square root, or zero if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_or_zero(self) -> Self;
+    /// Returns the square root, or zero if `self` is negative, which clamps negative arguments to zero first.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    fn saturating_isqrt(self) -> Self {
+        SignedIsqrt::isqrt_or_zero(self)
+    }
+    /// Returns the smallest integer whose square is at least `self`, or `None` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_ceil_isqrt(self) -> Option<Self>;
+    /// Returns whether `self` is the square of an integer. Negative numbers never are.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn is_perfect_square(self) -> bool;
+    /// Returns the square root as a `Self::Root`, or `None` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_unsigned(self) -> Option<Self::Root>;
 }
-pub trait UnsignedIsqrt {
+/// Integer square roots of the unsigned integer types.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+pub trait UnsignedIsqrt: Sized + crate::sealed::Sealed {
+    /// The square root of `Self::MAX`, which no square root exceeds.
+    const ISQRT_MAX: Self;
+    /// The type of results that can be zero even when `self` isn't, such as remainders: `Self`, except for the `NonZero`
+    /// types, where it's the integer type they wrap.
+    type Int;
+
+    #[must_use = "this returns the result of the operation, without modifying the original"]
     fn isqrt(self) -> Self;
+    /// Returns the largest integer whose square is at most `self`, the same as `isqrt`, named to match `ceil_isqrt`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    fn isqrt_floor(self) -> Self {
+        UnsignedIsqrt::isqrt(self)
+    }
+    /// Returns the smallest integer whose square is at least `self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn ceil_isqrt(self) -> Self;
+    /// Returns whether `self` is the square of an integer.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn is_perfect_square(self) -> bool;
+    /// Returns the largest perfect square that is at most `self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn prev_perfect_square(self) -> Self;
+    /// Returns the smallest perfect square greater than `self`.
+    ///
+    /// Panics if that square doesn't fit in `Self`, which happens when `self` is at least the largest perfect square
+    /// that does (for example, 225 for `u8`).
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn next_perfect_square(self) -> Self;
+    /// Returns the smallest perfect square greater than `self`, or `None` if it doesn't fit in `Self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_next_perfect_square(self) -> Option<Self>;
+    /// Returns the smallest perfect square greater than `self`, along with whether it overflowed `Self`. If it did,
+    /// which happens when `self` is at least the largest perfect square that fits, the value wraps around to zero.
+    ///
+    /// The `NonZero` types can't hold zero, so for them the value is one instead. That isn't the inner integer's
+    /// wrapped value, so check the flag rather than comparing the value with zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_next_perfect_square(self) -> (Self, bool);
+    /// Returns the square root if `self` is a perfect square, or `None` otherwise.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_exact(self) -> Option<Self>;
+    /// Returns the integer square root along with whether `self` is a perfect square.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_with_exactness(self) -> (Self, bool);
+    /// Returns the integer nearest to the square root of `self`, rounding halfway cases up.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn round_isqrt(self) -> Self;
+    /// Returns the integer square root along with whether the remainder exceeds it, which is exactly when the square
+    /// root is at least halfway to the next integer.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_round_info(self) -> (Self, bool);
+    /// Returns the integer square root along with the remainder, `self - self.isqrt().pow(2)`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_rem(self) -> (Self, Self::Int);
+    /// Returns `floor(sqrt(self) * 2^(output_bits - Self::BITS / 2))`, the square root scaled to have
+    /// `output_bits` bits when the input is treated as a fraction of `2^Self::BITS`.
+    ///
+    /// Panics if `output_bits` is greater than `Self::BITS`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_scaled(self, output_bits: u32) -> Self::Int;
 }
 
 const ISQRT_AND_REMAINDER_8_BIT: [(u8, u8); 256] = {
@@ -16,7 +114,7 @@ const ISQRT_AND_REMAINDER_8_BIT: [(u8, u8); 256] = {
     'outer: loop {
         let mut remaining = 2 * sqrt + 1;
         while remaining > 0 {
-            result[i as usize] = (sqrt, 2 * sqrt + 1 - remaining);
+            result[i] = (sqrt, 2 * sqrt + 1 - remaining);
             i += 1;
             if i >= result.len() {
                 break 'outer;
@@ -75,7 +173,6 @@ macro_rules! karatsuba_isqrt {
             result
         }
 
-        #[allow(dead_code)]
         const fn $karatsuba_isqrt_with_remainder(mut n: $FullBitsT) -> ($FullBitsT, $FullBitsT) {
             // Performs a Karatsuba square root.
             // https://web.archive.org/web/20230511212802/https://inria.hal.science/inria-00072854v1/file/RR-3805.pdf
@@ -110,9 +207,16 @@ macro_rules! karatsuba_isqrt {
                     r = r.wrapping_add((s << 1) - 1);
                     s -= 1;
                 }
+
+                // Undo the precondition shift. With `k = precondition_shift >> 1` and `t` the lowest `k` bits of `s`,
+                // the unshifted square root is `s >> k`, and the unshifted remainder is
+                // `(n - (s - t)^2) >> precondition_shift`. As `n = s^2 + r`, that's
+                // `(r + t * (2 * s - t)) >> precondition_shift`.
+                let result_shift = precondition_shift >> 1;
+                let t = s & ((1 << result_shift) - 1);
                 (
-                    s >> (precondition_shift >> 1),
-                    r >> (precondition_shift >> 1),
+                    s >> result_shift,
+                    (r + t * ((s << 1) - t)) >> precondition_shift,
                 )
             };
 
@@ -121,6 +225,9 @@ macro_rules! karatsuba_isqrt {
     };
 }
 
+// With the `karatsuba-8-bit-table` feature, 16-bit square roots take a Karatsuba step over the 8-bit table. Otherwise,
+// they're looked up in a 16-bit table, which takes 256 KiB but saves a step in every wider square root.
+#[cfg(feature = "karatsuba-8-bit-table")]
 karatsuba_isqrt!(
     u16,
     karatsuba_isqrt_16,
@@ -129,6 +236,33 @@ karatsuba_isqrt!(
     karatsuba_isqrt_8,
     karatsuba_isqrt_with_remainder_8
 );
+
+#[cfg(not(feature = "karatsuba-8-bit-table"))]
+static ISQRT_AND_REMAINDER_16_BIT: [(u16, u16); 65536] = {
+    let mut result = [(0, 0); 65536];
+
+    let mut sqrt: u32 = 0;
+    let mut i: u32 = 0;
+    while i < result.len() as u32 {
+        if i == (sqrt + 1) * (sqrt + 1) {
+            sqrt += 1;
+        }
+        result[i as usize] = (sqrt as u16, (i - sqrt * sqrt) as u16);
+        i += 1;
+    }
+
+    result
+};
+
+#[cfg(not(feature = "karatsuba-8-bit-table"))]
+const fn karatsuba_isqrt_16(n: u16) -> u16 {
+    ISQRT_AND_REMAINDER_16_BIT[n as usize].0
+}
+
+#[cfg(not(feature = "karatsuba-8-bit-table"))]
+const fn karatsuba_isqrt_with_remainder_16(n: u16) -> (u16, u16) {
+    ISQRT_AND_REMAINDER_16_BIT[n as usize]
+}
 karatsuba_isqrt!(
     u32,
     karatsuba_isqrt_32,
@@ -154,9 +288,78 @@ karatsuba_isqrt!(
     karatsuba_isqrt_with_remainder_64
 );
 
+/// Returns the integer square root of `n`. Usable in `const` contexts.
+pub const fn isqrt_u8(n: u8) -> u8 {
+    karatsuba_isqrt_8(n)
+}
+
+/// Returns the integer square root of `n`. Usable in `const` contexts.
+pub const fn isqrt_u16(n: u16) -> u16 {
+    karatsuba_isqrt_16(n)
+}
+
+/// Returns the integer square root of `n`. Usable in `const` contexts.
+pub const fn isqrt_u32(n: u32) -> u32 {
+    karatsuba_isqrt_32(n)
+}
+
+/// Returns the integer square root of `n`. Usable in `const` contexts.
+///
+/// ```
+/// use isqrt::karatsuba::isqrt_u64;
+///
+/// const N: usize = isqrt_u64(1_000_000) as usize;
+/// let is_prime = [true; N];
+/// assert_eq!(is_prime.len(), 1000);
+/// ```
+pub const fn isqrt_u64(n: u64) -> u64 {
+    karatsuba_isqrt_64(n)
+}
+
+/// Returns the integer square root of `n`. Usable in `const` contexts.
+pub const fn isqrt_u128(n: u128) -> u128 {
+    karatsuba_isqrt_128(n)
+}
+
+/// Returns a table of the integer square roots of `0` through `N - 1`, computed at compile time when used in a `const`.
+///
+/// Every square root fits in a `u16` as long as `N` is at most `2^32`.
+pub const fn build_root_table<const N: usize>() -> [u16; N] {
+    let mut table = [0; N];
+
+    let mut i = 0;
+    while i < N {
+        table[i] = karatsuba_isqrt_64(i as u64) as u16;
+        i += 1;
+    }
+
+    table
+}
+
+/// Parses a decimal `u64` from `s` and returns its integer square root.
+pub fn parse_and_isqrt(s: &str) -> Result<u64, ParseIntError> {
+    s.parse().map(karatsuba_isqrt_64)
+}
+
+// Make sure that the table matches the 8-bit lookup table.
+#[cfg(test)]
+const _: () = {
+    let table = build_root_table::<256>();
+
+    let mut i = 0;
+    while i < table.len() {
+        assert!(table[i] == ISQRT_AND_REMAINDER_8_BIT[i].0 as u16);
+        i += 1;
+    }
+};
+
 macro_rules! isqrt_impl {
-    ($signed_type:ty, $unsigned_type:ty, $karatsuba_isqrt:ident) => {
+    ($signed_type:ty, $unsigned_type:ty, $root_type:ty, $karatsuba_isqrt:ident, $karatsuba_isqrt_with_remainder:ident) => {
         impl SignedIsqrt for $signed_type {
+            const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
+            type Root = $root_type;
+
             #[inline(always)]
             fn checked_isqrt(self) -> Option<Self> {
                 (self >= 0).then(|| {
@@ -164,10 +367,9 @@ macro_rules! isqrt_impl {
 
                     // SAFETY: the result is nonnegative and less than or equal to `i16::MAX.isqrt()`.
                     // Inform the optimizer about it.
-                    const ISQRT_MAX: $signed_type = $karatsuba_isqrt(<$signed_type>::MAX as _) as _;
                     unsafe {
-                        intrinsics::assume(0 <= result);
-                        intrinsics::assume(result <= ISQRT_MAX);
+                        crate::assume(0 <= result);
+                        crate::assume(result <= <Self as SignedIsqrt>::ISQRT_MAX);
                     }
 
                     result
@@ -176,30 +378,409 @@ macro_rules! isqrt_impl {
 
             #[inline]
             fn isqrt(self) -> Self {
-                self.checked_isqrt()
-                    .expect("argument of integer square root must be non-negative")
+                SignedIsqrt::checked_isqrt(self).unwrap_or_else(crate::negative_isqrt_argument)
+            }
+
+            #[inline]
+            fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+                SignedIsqrt::checked_isqrt(self).ok_or(crate::NegativeInputError)
+            }
+
+            #[inline]
+            fn isqrt_or(self, default: Self) -> Self {
+                SignedIsqrt::checked_isqrt(self).unwrap_or(default)
+            }
+
+            #[inline]
+            fn isqrt_or_zero(self) -> Self {
+                SignedIsqrt::checked_isqrt(self).unwrap_or(0)
+            }
+
+            #[inline]
+            fn isqrt_unsigned(self) -> Option<Self::Root> {
+                SignedIsqrt::checked_isqrt(self).map(|sqrt| sqrt as Self::Root)
+            }
+
+            #[inline]
+            fn checked_ceil_isqrt(self) -> Option<Self> {
+                SignedIsqrt::checked_isqrt(self).map(|sqrt| {
+                    if sqrt * sqrt == self {
+                        sqrt
+                    } else {
+                        sqrt + 1
+                    }
+                })
+            }
+
+            #[inline]
+            fn is_perfect_square(self) -> bool {
+                self >= 0 && UnsignedIsqrt::is_perfect_square(self as $unsigned_type)
             }
         }
 
         impl UnsignedIsqrt for $unsigned_type {
+            const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+            type Int = Self;
+
             #[inline(always)]
             fn isqrt(self) -> Self {
                 let result = $karatsuba_isqrt(self);
 
+                crate::debug_assert_isqrt(self as u128, result as u128);
+
                 // SAFETY: the result fits in an integer with half as many bits.
                 // Inform the optimizer about it.
                 unsafe {
-                    intrinsics::assume(result < 1 << ((<$unsigned_type>::BITS as Self) >> 1));
+                    crate::assume(result < 1 << ((<$unsigned_type>::BITS as Self) >> 1));
                 }
 
                 result
             }
+
+            #[inline]
+            fn isqrt_rem(self) -> (Self, Self) {
+                $karatsuba_isqrt_with_remainder(self)
+            }
+
+            #[inline]
+            fn isqrt_scaled(self, output_bits: u32) -> Self {
+                const HALF_BITS: u32 = <$unsigned_type>::BITS >> 1;
+
+                assert!(
+                    output_bits <= Self::BITS,
+                    "output bits of scaled integer square root must not exceed the bit width"
+                );
+
+                if output_bits <= HALF_BITS {
+                    // Flooring before dividing by a power of two doesn't change the floored quotient.
+                    return $karatsuba_isqrt(self) >> (HALF_BITS - output_bits);
+                }
+
+                let extra_bits = output_bits - HALF_BITS;
+                if extra_bits < HALF_BITS && self.leading_zeros() >= extra_bits << 1 {
+                    // Multiplying the input by `4^extra_bits` multiplies the square root by `2^extra_bits`.
+                    return $karatsuba_isqrt(self << (extra_bits << 1));
+                }
+
+                // Otherwise, extend the root one bit at a time. Appending two zero bits to the input appends one bit to
+                // the square root, which is a one when `4 * r >= 4 * s + 1` (that is, when `r > s`) and a zero
+                // otherwise.
+                //
+                // The remainder never exceeds `2 * s`, so it can only overflow on the last step, after which it's
+                // unused.
+                let (mut s, mut r) = $karatsuba_isqrt_with_remainder(self);
+                for _ in 0..extra_bits {
+                    if r > s {
+                        r = ((r - s) << 2).wrapping_sub(1);
+                        s = (s << 1) | 1;
+                    } else {
+                        r <<= 2;
+                        s <<= 1;
+                    }
+                }
+
+                s
+            }
+
+            #[inline]
+            fn ceil_isqrt(self) -> Self {
+                // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+                let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+                if remainder == 0 {
+                    sqrt
+                } else {
+                    sqrt + 1
+                }
+            }
+
+            #[inline]
+            fn is_perfect_square(self) -> bool {
+                // The remainder is zero exactly for perfect squares.
+                UnsignedIsqrt::isqrt_rem(self).1 == 0
+            }
+
+            #[inline]
+            fn prev_perfect_square(self) -> Self {
+                let sqrt = UnsignedIsqrt::isqrt(self);
+                sqrt * sqrt
+            }
+
+            #[inline]
+            fn next_perfect_square(self) -> Self {
+                UnsignedIsqrt::checked_next_perfect_square(self)
+                    .expect("no larger perfect square fits in the type")
+            }
+
+            #[inline]
+            fn checked_next_perfect_square(self) -> Option<Self> {
+                // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+                let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+                sqrt.checked_mul(sqrt)
+            }
+
+            #[inline]
+            fn overflowing_next_perfect_square(self) -> (Self, bool) {
+                // As in `checked_next_perfect_square`, `sqrt + 1` can't overflow.
+                let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+                sqrt.overflowing_mul(sqrt)
+            }
+
+            #[inline]
+            fn isqrt_exact(self) -> Option<Self> {
+                let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+                (remainder == 0).then_some(sqrt)
+            }
+
+            #[inline]
+            fn isqrt_with_exactness(self) -> (Self, bool) {
+                let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+                (sqrt, remainder == 0)
+            }
+
+            #[inline]
+            fn round_isqrt(self) -> Self {
+                // As with `ceil_isqrt`, `sqrt + 1` can't overflow.
+                let (sqrt, round_up) = UnsignedIsqrt::isqrt_round_info(self);
+                if round_up {
+                    sqrt + 1
+                } else {
+                    sqrt
+                }
+            }
+
+            #[inline]
+            fn isqrt_round_info(self) -> (Self, bool) {
+                // The square root is at least `sqrt + 1/2` exactly when `self >= sqrt^2 + sqrt + 1/4`, that is, when
+                // the remainder exceeds `sqrt`.
+                let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+                (sqrt, remainder > sqrt)
+            }
         }
     };
 }
 
-isqrt_impl!(i8, u8, karatsuba_isqrt_8);
-isqrt_impl!(i16, u16, karatsuba_isqrt_16);
-isqrt_impl!(i32, u32, karatsuba_isqrt_32);
-isqrt_impl!(i64, u64, karatsuba_isqrt_64);
-isqrt_impl!(i128, u128, karatsuba_isqrt_128);
+isqrt_impl!(
+    i8,
+    u8,
+    u8,
+    karatsuba_isqrt_8,
+    karatsuba_isqrt_with_remainder_8
+);
+isqrt_impl!(
+    i16,
+    u16,
+    u8,
+    karatsuba_isqrt_16,
+    karatsuba_isqrt_with_remainder_16
+);
+isqrt_impl!(
+    i32,
+    u32,
+    u16,
+    karatsuba_isqrt_32,
+    karatsuba_isqrt_with_remainder_32
+);
+isqrt_impl!(
+    i64,
+    u64,
+    u32,
+    karatsuba_isqrt_64,
+    karatsuba_isqrt_with_remainder_64
+);
+isqrt_impl!(
+    i128,
+    u128,
+    u64,
+    karatsuba_isqrt_128,
+    karatsuba_isqrt_with_remainder_128
+);
+
+// `isize` and `usize` forward to the fixed-width type of the same size, which keeps its `assume` hints.
+impl SignedIsqrt for isize {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
+    type Root = <crate::IsizeDelegate as SignedIsqrt>::Root;
+
+    #[inline]
+    fn checked_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_isqrt(self as crate::IsizeDelegate).map(|sqrt| sqrt as Self)
+    }
+
+    #[inline]
+    fn isqrt(self) -> Self {
+        SignedIsqrt::isqrt(self as crate::IsizeDelegate) as Self
+    }
+
+    #[inline]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+        SignedIsqrt::checked_isqrt(self).ok_or(crate::NegativeInputError)
+    }
+
+    #[inline]
+    fn isqrt_or(self, default: Self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(default)
+    }
+
+    #[inline]
+    fn isqrt_or_zero(self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(0)
+    }
+
+    #[inline]
+    fn checked_ceil_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_ceil_isqrt(self as crate::IsizeDelegate).map(|sqrt| sqrt as Self)
+    }
+
+    #[inline]
+    fn isqrt_unsigned(self) -> Option<Self::Root> {
+        SignedIsqrt::isqrt_unsigned(self as crate::IsizeDelegate)
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        SignedIsqrt::is_perfect_square(self as crate::IsizeDelegate)
+    }
+}
+
+impl UnsignedIsqrt for usize {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+    type Int = Self;
+
+    #[inline]
+    fn isqrt(self) -> Self {
+        UnsignedIsqrt::isqrt(self as crate::UsizeDelegate) as Self
+    }
+
+    #[inline]
+    fn ceil_isqrt(self) -> Self {
+        UnsignedIsqrt::ceil_isqrt(self as crate::UsizeDelegate) as Self
+    }
+
+    #[inline]
+    fn round_isqrt(self) -> Self {
+        UnsignedIsqrt::round_isqrt(self as crate::UsizeDelegate) as Self
+    }
+
+    #[inline]
+    fn isqrt_round_info(self) -> (Self, bool) {
+        let (sqrt, round_up) = UnsignedIsqrt::isqrt_round_info(self as crate::UsizeDelegate);
+        (sqrt as Self, round_up)
+    }
+
+    #[inline]
+    fn isqrt_rem(self) -> (Self, Self) {
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self as crate::UsizeDelegate);
+        (sqrt as Self, remainder as Self)
+    }
+
+    #[inline]
+    fn isqrt_scaled(self, output_bits: u32) -> Self {
+        UnsignedIsqrt::isqrt_scaled(self as crate::UsizeDelegate, output_bits) as Self
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        UnsignedIsqrt::is_perfect_square(self as crate::UsizeDelegate)
+    }
+    #[inline]
+    fn prev_perfect_square(self) -> Self {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        sqrt * sqrt
+    }
+    #[inline]
+    fn next_perfect_square(self) -> Self {
+        UnsignedIsqrt::checked_next_perfect_square(self)
+            .expect("no larger perfect square fits in the type")
+    }
+    #[inline]
+    fn checked_next_perfect_square(self) -> Option<Self> {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.checked_mul(sqrt)
+    }
+    #[inline]
+    fn overflowing_next_perfect_square(self) -> (Self, bool) {
+        // As in `checked_next_perfect_square`, `sqrt + 1` can't overflow.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.overflowing_mul(sqrt)
+    }
+    #[inline]
+    fn isqrt_exact(self) -> Option<Self> {
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+        (remainder == 0).then_some(sqrt)
+    }
+    #[inline]
+    fn isqrt_with_exactness(self) -> (Self, bool) {
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+        (sqrt, remainder == 0)
+    }
+}
+
+nonzero_unsigned_isqrt!(
+    unsigned {
+        type Int = UnsignedInner;
+
+        #[inline]
+        fn round_isqrt(self) -> Self {
+            // SAFETY: the argument is at least one, so its rounded square root is at least one.
+            unsafe { Self::new_unchecked(UnsignedIsqrt::round_isqrt(self.get())) }
+        }
+
+        #[inline]
+        fn isqrt_round_info(self) -> (Self, bool) {
+            let (sqrt, at_least_halfway) = UnsignedIsqrt::isqrt_round_info(self.get());
+            // SAFETY: the argument is at least one, so its square root is at least one.
+            (unsafe { Self::new_unchecked(sqrt) }, at_least_halfway)
+        }
+
+        #[inline]
+        fn isqrt_rem(self) -> (Self, Self::Int) {
+            let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self.get());
+            // SAFETY: the argument is at least one, so its square root is at least one.
+            (unsafe { Self::new_unchecked(sqrt) }, remainder)
+        }
+
+        #[inline]
+        fn isqrt_scaled(self, output_bits: u32) -> Self::Int {
+            UnsignedIsqrt::isqrt_scaled(self.get(), output_bits)
+        }
+    }
+);
+
+wrapper_isqrt!(Wrapping, Saturating;
+    signed {
+        type Root = <SignedInner as SignedIsqrt>::Root;
+
+        #[inline]
+        fn isqrt_unsigned(self) -> Option<Self::Root> {
+            SignedIsqrt::isqrt_unsigned(self.0)
+        }
+    }
+    unsigned {
+        type Int = Self;
+
+        #[inline]
+        fn round_isqrt(self) -> Self {
+            Self(UnsignedIsqrt::round_isqrt(self.0))
+        }
+
+        #[inline]
+        fn isqrt_round_info(self) -> (Self, bool) {
+            let (sqrt, at_least_halfway) = UnsignedIsqrt::isqrt_round_info(self.0);
+            (Self(sqrt), at_least_halfway)
+        }
+
+        #[inline]
+        fn isqrt_rem(self) -> (Self, Self) {
+            let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self.0);
+            (Self(sqrt), Self(remainder))
+        }
+
+        #[inline]
+        fn isqrt_scaled(self, output_bits: u32) -> Self {
+            Self(UnsignedIsqrt::isqrt_scaled(self.0, output_bits))
+        }
+    }
+);
+
+int_isqrt!();
@@ -1,10 +1,88 @@
-use core::intrinsics;
-pub trait SignedIsqrt: Sized {
+/// Integer square roots of the signed integer types.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+pub trait SignedIsqrt: Sized + crate::sealed::Sealed {
+    /// The square root of `Self::MAX`, which no square root exceeds.
+    const ISQRT_MAX: Self;
+
+    #[must_use = "this returns the result of the operation, without modifying the original"]
     fn checked_isqrt(self) -> Option<Self>;
+    #[must_use = "this returns the result of the operation, without modifying the original"]
     fn isqrt(self) -> Self;
+    /// Returns the square root, or a `NegativeInputError` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError>;
+    /// Returns the square root, or `default` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_or(self, default: Self) -> Self;
+    /// Returns the square root, or zero if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_or_zero(self) -> Self;
+    /// Returns the square root, or zero if `self` is negative, which clamps negative arguments to zero first.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    fn saturating_isqrt(self) -> Self {
+        SignedIsqrt::isqrt_or_zero(self)
+    }
+    /// Returns the smallest integer whose square is at least `self`, or `None` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_ceil_isqrt(self) -> Option<Self>;
+    /// Returns whether `self` is the square of an integer. Negative numbers never are.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn is_perfect_square(self) -> bool;
 }
-pub trait UnsignedIsqrt {
+/// Integer square roots of the unsigned integer types.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+pub trait UnsignedIsqrt: Sized + crate::sealed::Sealed {
+    /// The square root of `Self::MAX`, which no square root exceeds.
+    const ISQRT_MAX: Self;
+    /// The type of results that can be zero even when `self` isn't, such as remainders: `Self`, except for the `NonZero`
+    /// types, where it's the integer type they wrap.
+    type Int;
+
+    #[must_use = "this returns the result of the operation, without modifying the original"]
     fn isqrt(self) -> Self;
+    /// Returns the largest integer whose square is at most `self`, the same as `isqrt`, named to match `ceil_isqrt`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    fn isqrt_floor(self) -> Self {
+        UnsignedIsqrt::isqrt(self)
+    }
+    /// Returns the smallest integer whose square is at least `self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn ceil_isqrt(self) -> Self;
+    /// Returns whether `self` is the square of an integer.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn is_perfect_square(self) -> bool;
+    /// Returns the largest perfect square that is at most `self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn prev_perfect_square(self) -> Self;
+    /// Returns the smallest perfect square greater than `self`.
+    ///
+    /// Panics if that square doesn't fit in `Self`, which happens when `self` is at least the largest perfect square
+    /// that does (for example, 225 for `u8`).
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn next_perfect_square(self) -> Self;
+    /// Returns the smallest perfect square greater than `self`, or `None` if it doesn't fit in `Self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_next_perfect_square(self) -> Option<Self>;
+    /// Returns the smallest perfect square greater than `self`, along with whether it overflowed `Self`. If it did,
+    /// which happens when `self` is at least the largest perfect square that fits, the value wraps around to zero.
+    ///
+    /// The `NonZero` types can't hold zero, so for them the value is one instead. That isn't the inner integer's
+    /// wrapped value, so check the flag rather than comparing the value with zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_next_perfect_square(self) -> (Self, bool);
+    /// Returns the square root if `self` is a perfect square, or `None` otherwise.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_exact(self) -> Option<Self>;
+    /// Returns the integer square root along with whether `self` is a perfect square.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_with_exactness(self) -> (Self, bool);
+    /// Returns the integer square root along with the remainder, `self - self.isqrt().pow(2)`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_rem(self) -> (Self, Self::Int);
 }
 const ISQRT_AND_REMAINDER_8_BIT: [(u8, u8); 256] = {
     let mut result = [(0, 0); 256];
@@ -24,7 +102,7 @@ const ISQRT_AND_REMAINDER_8_BIT: [(u8, u8); 256] = {
 };
 
 macro_rules! first_stage {
-    ($original_bits:literal, $n:ident) => {{
+    ($original_bits:expr, $n:ident) => {{
         const N_SHIFT: u32 = $original_bits - 8;
         let n = $n >> N_SHIFT;
 
@@ -33,7 +111,7 @@ macro_rules! first_stage {
 }
 
 macro_rules! middle_stage {
-    ($original_bits:literal, $ty:ty, $n:ident, $s:ident, $r:ident) => {{
+    ($original_bits:expr, $ty:ty, $n:ident, $s:ident, $r:ident) => {{
         const N_SHIFT: u32 = $original_bits - <$ty>::BITS;
         let n = ($n >> N_SHIFT) as $ty;
 
@@ -70,14 +148,74 @@ macro_rules! last_stage {
         let denominator = ($s as $ty) << 1;
         let q = numerator / denominator;
         let mut s = ($s << QUARTER_BITS) as $ty + q;
-        let (s_squared, overflow) = s.overflowing_mul(s);
-        if overflow || s_squared > $n {
-            s -= 1;
+        // `s` is at most one more than the square root, so its square can only overflow when it's past the largest
+        // square root, `LOWER_HALF_1_BITS`. Below that, which is almost always, a plain multiplication suffices.
+        if s <= LOWER_HALF_1_BITS {
+            if s * s > $n {
+                s -= 1;
+            }
+        } else {
+            let (s_squared, overflow) = s.overflowing_mul(s);
+            if overflow || s_squared > $n {
+                s -= 1;
+            }
         }
         s
     }};
 }
 
+/// Returns the integer square root of `n` and its remainder, `n - s^2`, which is at most `2 * s`.
+///
+/// This is the first stage of the Karatsuba square root, which starts from the top eight bits of the input. Any byte is
+/// allowed, but the later stages need `n` to have one of its top two bits set, which shifting the input left by an even
+/// number of bits beforehand ensures.
+///
+/// Each stage takes twice as many of the input's top bits as the one before:
+///
+/// ```
+/// use isqrt::karatsuba_2::{karatsuba_first_stage, karatsuba_last_stage_u32, karatsuba_middle_stage_u16};
+///
+/// // One of the top two bits is set, so no shift is needed first.
+/// let n: u32 = 3_000_000_000;
+/// let (s, r) = karatsuba_first_stage((n >> 24) as u8);
+/// let (s, r) = karatsuba_middle_stage_u16((n >> 16) as u16, s, r);
+/// assert_eq!(karatsuba_last_stage_u32(n, s, r), 54_772);
+/// ```
+pub const fn karatsuba_first_stage(n: u8) -> (u8, u8) {
+    first_stage!(8, n)
+}
+
+macro_rules! public_stages {
+    ($($ty:ty, $HalfT:ty, $middle_stage:ident, $last_stage:ident);+) => {
+        $(
+            /// Returns the integer square root of `n` and its remainder, `n - s^2`, given those of the top half of `n`.
+            ///
+            /// This is a middle stage of the Karatsuba square root, which doubles the bits of the root. `n` is the top
+            /// bits of the input, as many as fit, and must have one of its top two bits set. `s` and `r` must be the
+            /// square root and remainder of the top half of `n`, as returned by the previous stage, so `r <= 2 * s`.
+            /// The result meets the same conditions for the next stage.
+            pub const fn $middle_stage(n: $ty, s: $HalfT, r: $HalfT) -> ($ty, $ty) {
+                middle_stage!({ <$ty>::BITS }, $ty, n, s, r)
+            }
+
+            /// Returns the integer square root of `n`, given the square root and remainder of its top half.
+            ///
+            /// This is the last stage of the Karatsuba square root. It's cheaper than a middle stage, as it skips the
+            /// remainder, and takes the same arguments.
+            pub const fn $last_stage(n: $ty, s: $HalfT, r: $HalfT) -> $ty {
+                last_stage!($ty, n, s, r)
+            }
+        )+
+    };
+}
+
+public_stages!(
+    u16, u8, karatsuba_middle_stage_u16, karatsuba_last_stage_u16;
+    u32, u16, karatsuba_middle_stage_u32, karatsuba_last_stage_u32;
+    u64, u32, karatsuba_middle_stage_u64, karatsuba_last_stage_u64;
+    u128, u64, karatsuba_middle_stage_u128, karatsuba_last_stage_u128
+);
+
 const fn karatsuba_isqrt_8(n: u8) -> u8 {
     ISQRT_AND_REMAINDER_8_BIT[n as usize].0
 }
@@ -85,7 +223,7 @@ const fn karatsuba_isqrt_16(mut n: u16) -> u16 {
     if n == 0 {
         return 0;
     }
-    const EVEN_BITMASK: u32 = u32::MAX & !1;
+    const EVEN_BITMASK: u32 = !1;
     let precondition_shift = n.leading_zeros() & EVEN_BITMASK;
     n <<= precondition_shift;
 
@@ -99,7 +237,7 @@ const fn karatsuba_isqrt_32(mut n: u32) -> u32 {
     if n == 0 {
         return 0;
     }
-    const EVEN_BITMASK: u32 = u32::MAX & !1;
+    const EVEN_BITMASK: u32 = !1;
     let precondition_shift = n.leading_zeros() & EVEN_BITMASK;
     n <<= precondition_shift;
 
@@ -114,7 +252,7 @@ const fn karatsuba_isqrt_64(mut n: u64) -> u64 {
     if n == 0 {
         return 0;
     }
-    const EVEN_BITMASK: u32 = u32::MAX & !1;
+    const EVEN_BITMASK: u32 = !1;
     let precondition_shift = n.leading_zeros() & EVEN_BITMASK;
     n <<= precondition_shift;
 
@@ -130,7 +268,7 @@ const fn karatsuba_isqrt_128(mut n: u128) -> u128 {
     if n == 0 {
         return 0;
     }
-    const EVEN_BITMASK: u32 = u32::MAX & !1;
+    const EVEN_BITMASK: u32 = !1;
     let precondition_shift = n.leading_zeros() & EVEN_BITMASK;
     n <<= precondition_shift;
 
@@ -144,157 +282,775 @@ const fn karatsuba_isqrt_128(mut n: u128) -> u128 {
     s >> result_shift
 }
 
+macro_rules! undo_precondition_shift {
+    ($precondition_shift:ident, $s:ident, $r:ident) => {{
+        // With `k = precondition_shift >> 1` and `t` the lowest `k` bits of `s`, the unshifted square root is `s >> k`,
+        // and the unshifted remainder is `(n - (s - t)^2) >> precondition_shift`. As `n = s^2 + r`, that's
+        // `(r + t * (2 * s - t)) >> precondition_shift`.
+        let result_shift = $precondition_shift >> 1;
+        let t = $s & ((1 << result_shift) - 1);
+        (
+            $s >> result_shift,
+            ($r + t * (($s << 1) - t)) >> $precondition_shift,
+        )
+    }};
+}
+
+const fn karatsuba_isqrt_with_remainder_8(n: u8) -> (u8, u8) {
+    ISQRT_AND_REMAINDER_8_BIT[n as usize]
+}
+// These replace `last_stage!` with a final `middle_stage!` over the whole input, which also gives the remainder.
+const fn karatsuba_isqrt_with_remainder_16(mut n: u16) -> (u16, u16) {
+    if n == 0 {
+        return (0, 0);
+    }
+    const EVEN_BITMASK: u32 = !1;
+    let precondition_shift = n.leading_zeros() & EVEN_BITMASK;
+    n <<= precondition_shift;
+
+    let (s, r) = first_stage!(16, n);
+    let (s, r) = middle_stage!(16, u16, n, s, r);
+
+    undo_precondition_shift!(precondition_shift, s, r)
+}
+const fn karatsuba_isqrt_with_remainder_32(mut n: u32) -> (u32, u32) {
+    if n == 0 {
+        return (0, 0);
+    }
+    const EVEN_BITMASK: u32 = !1;
+    let precondition_shift = n.leading_zeros() & EVEN_BITMASK;
+    n <<= precondition_shift;
+
+    let (s, r) = first_stage!(32, n);
+    let (s, r) = middle_stage!(32, u16, n, s, r);
+    let (s, r) = middle_stage!(32, u32, n, s, r);
+
+    undo_precondition_shift!(precondition_shift, s, r)
+}
+const fn karatsuba_isqrt_with_remainder_64(mut n: u64) -> (u64, u64) {
+    if n == 0 {
+        return (0, 0);
+    }
+    const EVEN_BITMASK: u32 = !1;
+    let precondition_shift = n.leading_zeros() & EVEN_BITMASK;
+    n <<= precondition_shift;
+
+    let (s, r) = first_stage!(64, n);
+    let (s, r) = middle_stage!(64, u16, n, s, r);
+    let (s, r) = middle_stage!(64, u32, n, s, r);
+    let (s, r) = middle_stage!(64, u64, n, s, r);
+
+    undo_precondition_shift!(precondition_shift, s, r)
+}
+const fn karatsuba_isqrt_with_remainder_128(mut n: u128) -> (u128, u128) {
+    if n == 0 {
+        return (0, 0);
+    }
+    const EVEN_BITMASK: u32 = !1;
+    let precondition_shift = n.leading_zeros() & EVEN_BITMASK;
+    n <<= precondition_shift;
+
+    let (s, r) = first_stage!(128, n);
+    let (s, r) = middle_stage!(128, u16, n, s, r);
+    let (s, r) = middle_stage!(128, u32, n, s, r);
+    let (s, r) = middle_stage!(128, u64, n, s, r);
+    let (s, r) = middle_stage!(128, u128, n, s, r);
+
+    undo_precondition_shift!(precondition_shift, s, r)
+}
+
 impl SignedIsqrt for i8 {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
     #[inline(always)]
     fn checked_isqrt(self) -> Option<Self> {
         (self >= 0).then(|| {
             let result = karatsuba_isqrt_8(self as _) as Self;
-            const ISQRT_MAX: i8 = karatsuba_isqrt_8(<i8>::MAX as _) as _;
             unsafe {
-                intrinsics::assume(0 <= result);
-                intrinsics::assume(result <= ISQRT_MAX);
+                crate::assume(0 <= result);
+                crate::assume(result <= <Self as SignedIsqrt>::ISQRT_MAX);
             }
             result
         })
     }
     #[inline]
     fn isqrt(self) -> Self {
-        self.checked_isqrt()
-            .expect("argument of integer square root must be non-negative")
+        SignedIsqrt::checked_isqrt(self).unwrap_or_else(crate::negative_isqrt_argument)
+    }
+
+    #[inline]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+        SignedIsqrt::checked_isqrt(self).ok_or(crate::NegativeInputError)
+    }
+
+    #[inline]
+    fn isqrt_or(self, default: Self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(default)
+    }
+
+    #[inline]
+    fn isqrt_or_zero(self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(0)
+    }
+
+    #[inline]
+    fn checked_ceil_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_isqrt(self)
+            .map(|sqrt| if sqrt * sqrt == self { sqrt } else { sqrt + 1 })
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        self >= 0 && UnsignedIsqrt::is_perfect_square(self as u8)
     }
 }
 
 impl UnsignedIsqrt for u8 {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+    type Int = Self;
+
     #[inline(always)]
     fn isqrt(self) -> Self {
         let result = karatsuba_isqrt_8(self);
+        crate::debug_assert_isqrt(self as u128, result as u128);
         unsafe {
-            intrinsics::assume(result < 1 << ((<u8>::BITS as Self) >> 1));
+            crate::assume(result < 1 << ((<u8>::BITS as Self) >> 1));
         }
         result
     }
+    #[inline]
+    fn isqrt_rem(self) -> (Self, Self) {
+        karatsuba_isqrt_with_remainder_8(self)
+    }
+
+    #[inline]
+    fn ceil_isqrt(self) -> Self {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+        if remainder == 0 {
+            sqrt
+        } else {
+            sqrt + 1
+        }
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        // The remainder is zero exactly for perfect squares.
+        UnsignedIsqrt::isqrt_rem(self).1 == 0
+    }
+    #[inline]
+    fn prev_perfect_square(self) -> Self {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        sqrt * sqrt
+    }
+    #[inline]
+    fn next_perfect_square(self) -> Self {
+        UnsignedIsqrt::checked_next_perfect_square(self)
+            .expect("no larger perfect square fits in the type")
+    }
+    #[inline]
+    fn checked_next_perfect_square(self) -> Option<Self> {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.checked_mul(sqrt)
+    }
+    #[inline]
+    fn overflowing_next_perfect_square(self) -> (Self, bool) {
+        // As in `checked_next_perfect_square`, `sqrt + 1` can't overflow.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.overflowing_mul(sqrt)
+    }
+    #[inline]
+    fn isqrt_exact(self) -> Option<Self> {
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+        (remainder == 0).then_some(sqrt)
+    }
+    #[inline]
+    fn isqrt_with_exactness(self) -> (Self, bool) {
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+        (sqrt, remainder == 0)
+    }
 }
 
 impl SignedIsqrt for i16 {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
     #[inline(always)]
     fn checked_isqrt(self) -> Option<Self> {
         (self >= 0).then(|| {
             let result = karatsuba_isqrt_16(self as _) as Self;
-            const ISQRT_MAX: i16 = karatsuba_isqrt_16(<i16>::MAX as _) as _;
             unsafe {
-                intrinsics::assume(0 <= result);
-                intrinsics::assume(result <= ISQRT_MAX);
+                crate::assume(0 <= result);
+                crate::assume(result <= <Self as SignedIsqrt>::ISQRT_MAX);
             }
             result
         })
     }
     #[inline]
     fn isqrt(self) -> Self {
-        self.checked_isqrt()
-            .expect("argument of integer square root must be non-negative")
+        SignedIsqrt::checked_isqrt(self).unwrap_or_else(crate::negative_isqrt_argument)
+    }
+
+    #[inline]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+        SignedIsqrt::checked_isqrt(self).ok_or(crate::NegativeInputError)
+    }
+
+    #[inline]
+    fn isqrt_or(self, default: Self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(default)
+    }
+
+    #[inline]
+    fn isqrt_or_zero(self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(0)
+    }
+
+    #[inline]
+    fn checked_ceil_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_isqrt(self)
+            .map(|sqrt| if sqrt * sqrt == self { sqrt } else { sqrt + 1 })
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        self >= 0 && UnsignedIsqrt::is_perfect_square(self as u16)
     }
 }
 
 impl UnsignedIsqrt for u16 {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+    type Int = Self;
+
     #[inline(always)]
     fn isqrt(self) -> Self {
         let result = karatsuba_isqrt_16(self);
+        crate::debug_assert_isqrt(self as u128, result as u128);
         unsafe {
-            intrinsics::assume(result < 1 << ((<u16>::BITS as Self) >> 1));
+            crate::assume(result < 1 << ((<u16>::BITS as Self) >> 1));
         }
         result
     }
+    #[inline]
+    fn isqrt_rem(self) -> (Self, Self) {
+        karatsuba_isqrt_with_remainder_16(self)
+    }
+
+    #[inline]
+    fn ceil_isqrt(self) -> Self {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+        if remainder == 0 {
+            sqrt
+        } else {
+            sqrt + 1
+        }
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        // The remainder is zero exactly for perfect squares.
+        UnsignedIsqrt::isqrt_rem(self).1 == 0
+    }
+    #[inline]
+    fn prev_perfect_square(self) -> Self {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        sqrt * sqrt
+    }
+    #[inline]
+    fn next_perfect_square(self) -> Self {
+        UnsignedIsqrt::checked_next_perfect_square(self)
+            .expect("no larger perfect square fits in the type")
+    }
+    #[inline]
+    fn checked_next_perfect_square(self) -> Option<Self> {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.checked_mul(sqrt)
+    }
+    #[inline]
+    fn overflowing_next_perfect_square(self) -> (Self, bool) {
+        // As in `checked_next_perfect_square`, `sqrt + 1` can't overflow.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.overflowing_mul(sqrt)
+    }
+    #[inline]
+    fn isqrt_exact(self) -> Option<Self> {
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+        (remainder == 0).then_some(sqrt)
+    }
+    #[inline]
+    fn isqrt_with_exactness(self) -> (Self, bool) {
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+        (sqrt, remainder == 0)
+    }
 }
 
 impl SignedIsqrt for i32 {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
     #[inline(always)]
     fn checked_isqrt(self) -> Option<Self> {
         (self >= 0).then(|| {
             let result = karatsuba_isqrt_32(self as _) as Self;
-            const ISQRT_MAX: i32 = karatsuba_isqrt_32(<i32>::MAX as _) as _;
             unsafe {
-                intrinsics::assume(0 <= result);
-                intrinsics::assume(result <= ISQRT_MAX);
+                crate::assume(0 <= result);
+                crate::assume(result <= <Self as SignedIsqrt>::ISQRT_MAX);
             }
             result
         })
     }
     #[inline]
     fn isqrt(self) -> Self {
-        self.checked_isqrt()
-            .expect("argument of integer square root must be non-negative")
+        SignedIsqrt::checked_isqrt(self).unwrap_or_else(crate::negative_isqrt_argument)
+    }
+
+    #[inline]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+        SignedIsqrt::checked_isqrt(self).ok_or(crate::NegativeInputError)
+    }
+
+    #[inline]
+    fn isqrt_or(self, default: Self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(default)
+    }
+
+    #[inline]
+    fn isqrt_or_zero(self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(0)
+    }
+
+    #[inline]
+    fn checked_ceil_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_isqrt(self)
+            .map(|sqrt| if sqrt * sqrt == self { sqrt } else { sqrt + 1 })
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        self >= 0 && UnsignedIsqrt::is_perfect_square(self as u32)
     }
 }
 
 impl UnsignedIsqrt for u32 {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+    type Int = Self;
+
     #[inline(always)]
     fn isqrt(self) -> Self {
         let result = karatsuba_isqrt_32(self);
+        crate::debug_assert_isqrt(self as u128, result as u128);
         unsafe {
-            intrinsics::assume(result < 1 << ((<u32>::BITS as Self) >> 1));
+            crate::assume(result < 1 << ((<u32>::BITS as Self) >> 1));
         }
         result
     }
+    #[inline]
+    fn isqrt_rem(self) -> (Self, Self) {
+        karatsuba_isqrt_with_remainder_32(self)
+    }
+
+    #[inline]
+    fn ceil_isqrt(self) -> Self {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+        if remainder == 0 {
+            sqrt
+        } else {
+            sqrt + 1
+        }
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        // The remainder is zero exactly for perfect squares.
+        UnsignedIsqrt::isqrt_rem(self).1 == 0
+    }
+    #[inline]
+    fn prev_perfect_square(self) -> Self {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        sqrt * sqrt
+    }
+    #[inline]
+    fn next_perfect_square(self) -> Self {
+        UnsignedIsqrt::checked_next_perfect_square(self)
+            .expect("no larger perfect square fits in the type")
+    }
+    #[inline]
+    fn checked_next_perfect_square(self) -> Option<Self> {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.checked_mul(sqrt)
+    }
+    #[inline]
+    fn overflowing_next_perfect_square(self) -> (Self, bool) {
+        // As in `checked_next_perfect_square`, `sqrt + 1` can't overflow.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.overflowing_mul(sqrt)
+    }
+    #[inline]
+    fn isqrt_exact(self) -> Option<Self> {
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+        (remainder == 0).then_some(sqrt)
+    }
+    #[inline]
+    fn isqrt_with_exactness(self) -> (Self, bool) {
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+        (sqrt, remainder == 0)
+    }
 }
 
 impl SignedIsqrt for i64 {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
     #[inline(always)]
     fn checked_isqrt(self) -> Option<Self> {
         (self >= 0).then(|| {
             let result = karatsuba_isqrt_64(self as _) as Self;
-            const ISQRT_MAX: i64 = karatsuba_isqrt_64(<i64>::MAX as _) as _;
             unsafe {
-                intrinsics::assume(0 <= result);
-                intrinsics::assume(result <= ISQRT_MAX);
+                crate::assume(0 <= result);
+                crate::assume(result <= <Self as SignedIsqrt>::ISQRT_MAX);
             }
             result
         })
     }
     #[inline]
     fn isqrt(self) -> Self {
-        self.checked_isqrt()
-            .expect("argument of integer square root must be non-negative")
+        SignedIsqrt::checked_isqrt(self).unwrap_or_else(crate::negative_isqrt_argument)
+    }
+
+    #[inline]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+        SignedIsqrt::checked_isqrt(self).ok_or(crate::NegativeInputError)
+    }
+
+    #[inline]
+    fn isqrt_or(self, default: Self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(default)
+    }
+
+    #[inline]
+    fn isqrt_or_zero(self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(0)
+    }
+
+    #[inline]
+    fn checked_ceil_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_isqrt(self)
+            .map(|sqrt| if sqrt * sqrt == self { sqrt } else { sqrt + 1 })
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        self >= 0 && UnsignedIsqrt::is_perfect_square(self as u64)
     }
 }
 
 impl UnsignedIsqrt for u64 {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+    type Int = Self;
+
     #[inline(always)]
     fn isqrt(self) -> Self {
         let result = karatsuba_isqrt_64(self);
+        crate::debug_assert_isqrt(self as u128, result as u128);
         unsafe {
-            intrinsics::assume(result < 1 << ((<u64>::BITS as Self) >> 1));
+            crate::assume(result < 1 << ((<u64>::BITS as Self) >> 1));
         }
         result
     }
+    #[inline]
+    fn isqrt_rem(self) -> (Self, Self) {
+        karatsuba_isqrt_with_remainder_64(self)
+    }
+
+    #[inline]
+    fn ceil_isqrt(self) -> Self {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+        if remainder == 0 {
+            sqrt
+        } else {
+            sqrt + 1
+        }
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        // The remainder is zero exactly for perfect squares.
+        UnsignedIsqrt::isqrt_rem(self).1 == 0
+    }
+    #[inline]
+    fn prev_perfect_square(self) -> Self {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        sqrt * sqrt
+    }
+    #[inline]
+    fn next_perfect_square(self) -> Self {
+        UnsignedIsqrt::checked_next_perfect_square(self)
+            .expect("no larger perfect square fits in the type")
+    }
+    #[inline]
+    fn checked_next_perfect_square(self) -> Option<Self> {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.checked_mul(sqrt)
+    }
+    #[inline]
+    fn overflowing_next_perfect_square(self) -> (Self, bool) {
+        // As in `checked_next_perfect_square`, `sqrt + 1` can't overflow.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.overflowing_mul(sqrt)
+    }
+    #[inline]
+    fn isqrt_exact(self) -> Option<Self> {
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+        (remainder == 0).then_some(sqrt)
+    }
+    #[inline]
+    fn isqrt_with_exactness(self) -> (Self, bool) {
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+        (sqrt, remainder == 0)
+    }
 }
 
 impl SignedIsqrt for i128 {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
     #[inline(always)]
     fn checked_isqrt(self) -> Option<Self> {
         (self >= 0).then(|| {
             let result = karatsuba_isqrt_128(self as _) as Self;
-            const ISQRT_MAX: i128 = karatsuba_isqrt_128(<i128>::MAX as _) as _;
             unsafe {
-                intrinsics::assume(0 <= result);
-                intrinsics::assume(result <= ISQRT_MAX);
+                crate::assume(0 <= result);
+                crate::assume(result <= <Self as SignedIsqrt>::ISQRT_MAX);
             }
             result
         })
     }
     #[inline]
     fn isqrt(self) -> Self {
-        self.checked_isqrt()
-            .expect("argument of integer square root must be non-negative")
+        SignedIsqrt::checked_isqrt(self).unwrap_or_else(crate::negative_isqrt_argument)
+    }
+
+    #[inline]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+        SignedIsqrt::checked_isqrt(self).ok_or(crate::NegativeInputError)
+    }
+
+    #[inline]
+    fn isqrt_or(self, default: Self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(default)
+    }
+
+    #[inline]
+    fn isqrt_or_zero(self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(0)
+    }
+
+    #[inline]
+    fn checked_ceil_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_isqrt(self)
+            .map(|sqrt| if sqrt * sqrt == self { sqrt } else { sqrt + 1 })
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        self >= 0 && UnsignedIsqrt::is_perfect_square(self as u128)
     }
 }
 
 impl UnsignedIsqrt for u128 {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+    type Int = Self;
+
     #[inline(always)]
     fn isqrt(self) -> Self {
         let result = karatsuba_isqrt_128(self);
+        crate::debug_assert_isqrt(self, result);
         unsafe {
-            intrinsics::assume(result < 1 << ((<u128>::BITS as Self) >> 1));
+            crate::assume(result < 1 << ((<u128>::BITS as Self) >> 1));
         }
         result
     }
+    #[inline]
+    fn isqrt_rem(self) -> (Self, Self) {
+        karatsuba_isqrt_with_remainder_128(self)
+    }
+
+    #[inline]
+    fn ceil_isqrt(self) -> Self {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+        if remainder == 0 {
+            sqrt
+        } else {
+            sqrt + 1
+        }
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        // The remainder is zero exactly for perfect squares.
+        UnsignedIsqrt::isqrt_rem(self).1 == 0
+    }
+    #[inline]
+    fn prev_perfect_square(self) -> Self {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        sqrt * sqrt
+    }
+    #[inline]
+    fn next_perfect_square(self) -> Self {
+        UnsignedIsqrt::checked_next_perfect_square(self)
+            .expect("no larger perfect square fits in the type")
+    }
+    #[inline]
+    fn checked_next_perfect_square(self) -> Option<Self> {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.checked_mul(sqrt)
+    }
+    #[inline]
+    fn overflowing_next_perfect_square(self) -> (Self, bool) {
+        // As in `checked_next_perfect_square`, `sqrt + 1` can't overflow.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.overflowing_mul(sqrt)
+    }
+    #[inline]
+    fn isqrt_exact(self) -> Option<Self> {
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+        (remainder == 0).then_some(sqrt)
+    }
+    #[inline]
+    fn isqrt_with_exactness(self) -> (Self, bool) {
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+        (sqrt, remainder == 0)
+    }
 }
+
+// `isize` and `usize` forward to the fixed-width type of the same size, which keeps its `assume` hints.
+impl SignedIsqrt for isize {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
+    #[inline]
+    fn checked_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_isqrt(self as crate::IsizeDelegate).map(|sqrt| sqrt as Self)
+    }
+
+    #[inline]
+    fn isqrt(self) -> Self {
+        SignedIsqrt::isqrt(self as crate::IsizeDelegate) as Self
+    }
+
+    #[inline]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+        SignedIsqrt::checked_isqrt(self).ok_or(crate::NegativeInputError)
+    }
+
+    #[inline]
+    fn isqrt_or(self, default: Self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(default)
+    }
+
+    #[inline]
+    fn isqrt_or_zero(self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(0)
+    }
+
+    #[inline]
+    fn checked_ceil_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_ceil_isqrt(self as crate::IsizeDelegate).map(|sqrt| sqrt as Self)
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        SignedIsqrt::is_perfect_square(self as crate::IsizeDelegate)
+    }
+}
+
+impl UnsignedIsqrt for usize {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+    type Int = Self;
+
+    #[inline]
+    fn isqrt(self) -> Self {
+        UnsignedIsqrt::isqrt(self as crate::UsizeDelegate) as Self
+    }
+
+    #[inline]
+    fn ceil_isqrt(self) -> Self {
+        UnsignedIsqrt::ceil_isqrt(self as crate::UsizeDelegate) as Self
+    }
+
+    #[inline]
+    fn isqrt_rem(self) -> (Self, Self) {
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self as crate::UsizeDelegate);
+        (sqrt as Self, remainder as Self)
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        UnsignedIsqrt::is_perfect_square(self as crate::UsizeDelegate)
+    }
+    #[inline]
+    fn prev_perfect_square(self) -> Self {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        sqrt * sqrt
+    }
+    #[inline]
+    fn next_perfect_square(self) -> Self {
+        UnsignedIsqrt::checked_next_perfect_square(self)
+            .expect("no larger perfect square fits in the type")
+    }
+    #[inline]
+    fn checked_next_perfect_square(self) -> Option<Self> {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.checked_mul(sqrt)
+    }
+    #[inline]
+    fn overflowing_next_perfect_square(self) -> (Self, bool) {
+        // As in `checked_next_perfect_square`, `sqrt + 1` can't overflow.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.overflowing_mul(sqrt)
+    }
+    #[inline]
+    fn isqrt_exact(self) -> Option<Self> {
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+        (remainder == 0).then_some(sqrt)
+    }
+    #[inline]
+    fn isqrt_with_exactness(self) -> (Self, bool) {
+        let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self);
+        (sqrt, remainder == 0)
+    }
+}
+
+nonzero_unsigned_isqrt!(
+    unsigned {
+        type Int = UnsignedInner;
+
+        #[inline]
+        fn isqrt_rem(self) -> (Self, Self::Int) {
+            let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self.get());
+            // SAFETY: the argument is at least one, so its square root is at least one.
+            (unsafe { Self::new_unchecked(sqrt) }, remainder)
+        }
+    }
+);
+
+wrapper_isqrt!(Wrapping, Saturating;
+    signed {}
+    unsigned {
+        type Int = Self;
+
+        #[inline]
+        fn isqrt_rem(self) -> (Self, Self) {
+            let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(self.0);
+            (Self(sqrt), Self(remainder))
+        }
+    }
+);
+
+int_isqrt!();
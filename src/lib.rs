@@ -1,12 +1,612 @@
-#![feature(const_eval_select, core_intrinsics)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![cfg_attr(feature = "nightly", feature(core_intrinsics))]
+// Only the floating-point modules use these, so they're left off whenever those modules are, as unused features are
+// rejected.
+#![cfg_attr(
+    all(
+        feature = "nightly",
+        not(feature = "no-float"),
+        any(
+            feature = "algo-floating",
+            all(feature = "algo-floating-karatsuba", not(feature = "wasm-no-float"))
+        )
+    ),
+    feature(const_eval_select)
+)]
+#![cfg_attr(
+    all(
+        feature = "portable_simd",
+        feature = "algo-floating",
+        feature = "std",
+        not(feature = "no-float")
+    ),
+    feature(portable_simd)
+)]
 #![allow(dead_code, internal_features, unstable_name_collisions, unused_unsafe)]
+// The traits are only implemented for `Copy` integers, so `is_*` methods take `self` by value.
+#![allow(clippy::wrong_self_convention)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+/// Implements a module's `UnsignedIsqrt` for the `NonZero` unsigned types by taking the square root of the inner value.
+///
+/// The methods every module has are implemented here. Modules with more methods pass their implementations in the
+/// `unsigned` block, where `UnsignedInner` names the wrapped type. Results that can be zero, such as remainders, can't be
+/// `NonZero`, so those methods return the trait's `Int`, which those modules set to `UnsignedInner`.
+macro_rules! nonzero_unsigned_isqrt {
+    () => {
+        nonzero_unsigned_isqrt!(unsigned {});
+    };
+    (unsigned $unsigned_extra:tt) => {
+        nonzero_unsigned_isqrt!(@impl u8; $unsigned_extra);
+        nonzero_unsigned_isqrt!(@impl u16; $unsigned_extra);
+        nonzero_unsigned_isqrt!(@impl u32; $unsigned_extra);
+        nonzero_unsigned_isqrt!(@impl u64; $unsigned_extra);
+        nonzero_unsigned_isqrt!(@impl u128; $unsigned_extra);
+        nonzero_unsigned_isqrt!(@impl usize; $unsigned_extra);
+    };
+    (@impl $UnsignedT:ty; { $($unsigned_extra:tt)* }) => {
+        const _: () = {
+            type UnsignedInner = $UnsignedT;
+
+            impl UnsignedIsqrt for core::num::NonZero<$UnsignedT> {
+                const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
+                #[inline]
+                fn isqrt(self) -> Self {
+                    // SAFETY: the argument is at least one, so its square root is at least one.
+                    unsafe { Self::new_unchecked(UnsignedIsqrt::isqrt(self.get())) }
+                }
+
+                #[inline]
+                fn ceil_isqrt(self) -> Self {
+                    // SAFETY: the argument is at least one, so its ceiling square root is at least one.
+                    unsafe { Self::new_unchecked(UnsignedIsqrt::ceil_isqrt(self.get())) }
+                }
+
+                #[inline]
+                fn is_perfect_square(self) -> bool {
+                    UnsignedIsqrt::is_perfect_square(self.get())
+                }
+
+                #[inline]
+                fn prev_perfect_square(self) -> Self {
+                    // SAFETY: the argument is at least one, so the largest perfect square at most it is at least one.
+                    unsafe { Self::new_unchecked(UnsignedIsqrt::prev_perfect_square(self.get())) }
+                }
+
+                #[inline]
+                fn next_perfect_square(self) -> Self {
+                    // SAFETY: a perfect square greater than the argument is at least one.
+                    unsafe { Self::new_unchecked(UnsignedIsqrt::next_perfect_square(self.get())) }
+                }
+
+                #[inline]
+                fn checked_next_perfect_square(self) -> Option<Self> {
+                    // SAFETY: a perfect square greater than the argument is at least one.
+                    UnsignedIsqrt::checked_next_perfect_square(self.get())
+                        .map(|square| unsafe { Self::new_unchecked(square) })
+                }
+
+                #[inline]
+                fn overflowing_next_perfect_square(self) -> (Self, bool) {
+                    // The inner value wraps around to zero, which doesn't fit, so this wraps around to one instead.
+                    match UnsignedIsqrt::checked_next_perfect_square(self) {
+                        Some(square) => (square, false),
+                        None => (Self::MIN, true),
+                    }
+                }
+
+                #[inline]
+                fn isqrt_exact(self) -> Option<Self> {
+                    // SAFETY: the argument is at least one, so its square root is at least one.
+                    UnsignedIsqrt::isqrt_exact(self.get()).map(|sqrt| unsafe { Self::new_unchecked(sqrt) })
+                }
+
+                #[inline]
+                fn isqrt_with_exactness(self) -> (Self, bool) {
+                    let (sqrt, exact) = UnsignedIsqrt::isqrt_with_exactness(self.get());
+                    // SAFETY: the argument is at least one, so its square root is at least one.
+                    (unsafe { Self::new_unchecked(sqrt) }, exact)
+                }
+
+                $($unsigned_extra)*
+            }
+        };
+    };
+}
+
+/// Implements a module's `SignedIsqrt` and `UnsignedIsqrt` for `core::num` wrappers, such as `Wrapping`, of each
+/// integer type by taking the square root of the inner value and rewrapping it.
+///
+/// The methods every module has are implemented here. Modules with more methods pass their implementations in the
+/// `signed` and `unsigned` blocks, where `SignedInner` names the wrapped signed type.
+macro_rules! wrapper_isqrt {
+    ($($Wrapper:ident),+) => {
+        wrapper_isqrt!($($Wrapper),+; signed {} unsigned {});
+    };
+    ($($Wrapper:ident),+; signed $signed_extra:tt unsigned $unsigned_extra:tt) => {
+        $(wrapper_isqrt!(@wrapper $Wrapper; $signed_extra $unsigned_extra);)+
+    };
+    (@wrapper $Wrapper:ident; $signed_extra:tt $unsigned_extra:tt) => {
+        wrapper_isqrt!(@impl $Wrapper; i8, u8; $signed_extra $unsigned_extra);
+        wrapper_isqrt!(@impl $Wrapper; i16, u16; $signed_extra $unsigned_extra);
+        wrapper_isqrt!(@impl $Wrapper; i32, u32; $signed_extra $unsigned_extra);
+        wrapper_isqrt!(@impl $Wrapper; i64, u64; $signed_extra $unsigned_extra);
+        wrapper_isqrt!(@impl $Wrapper; i128, u128; $signed_extra $unsigned_extra);
+        wrapper_isqrt!(@impl $Wrapper; isize, usize; $signed_extra $unsigned_extra);
+    };
+    (@impl $Wrapper:ident; $SignedT:ty, $UnsignedT:ty; { $($signed_extra:tt)* } { $($unsigned_extra:tt)* }) => {
+        const _: () = {
+            type SignedInner = $SignedT;
+
+            impl SignedIsqrt for core::num::$Wrapper<$SignedT> {
+                const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
+                #[inline]
+                fn checked_isqrt(self) -> Option<Self> {
+                    SignedIsqrt::checked_isqrt(self.0).map(Self)
+                }
+
+                #[inline]
+                fn isqrt(self) -> Self {
+                    Self(SignedIsqrt::isqrt(self.0))
+                }
+
+                #[inline]
+                fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+                    SignedIsqrt::try_isqrt(self.0).map(Self)
+                }
+
+                #[inline]
+                fn isqrt_or(self, default: Self) -> Self {
+                    Self(SignedIsqrt::isqrt_or(self.0, default.0))
+                }
+
+                #[inline]
+                fn isqrt_or_zero(self) -> Self {
+                    Self(SignedIsqrt::isqrt_or_zero(self.0))
+                }
+
+                #[inline]
+                fn checked_ceil_isqrt(self) -> Option<Self> {
+                    SignedIsqrt::checked_ceil_isqrt(self.0).map(Self)
+                }
+
+                #[inline]
+                fn is_perfect_square(self) -> bool {
+                    SignedIsqrt::is_perfect_square(self.0)
+                }
+
+                $($signed_extra)*
+            }
+
+            impl UnsignedIsqrt for core::num::$Wrapper<$UnsignedT> {
+                const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
+                #[inline]
+                fn isqrt(self) -> Self {
+                    Self(UnsignedIsqrt::isqrt(self.0))
+                }
+
+                #[inline]
+                fn ceil_isqrt(self) -> Self {
+                    Self(UnsignedIsqrt::ceil_isqrt(self.0))
+                }
+
+                #[inline]
+                fn is_perfect_square(self) -> bool {
+                    UnsignedIsqrt::is_perfect_square(self.0)
+                }
+
+                #[inline]
+                fn prev_perfect_square(self) -> Self {
+                    Self(UnsignedIsqrt::prev_perfect_square(self.0))
+                }
+
+                #[inline]
+                fn next_perfect_square(self) -> Self {
+                    Self(UnsignedIsqrt::next_perfect_square(self.0))
+                }
+
+                #[inline]
+                fn checked_next_perfect_square(self) -> Option<Self> {
+                    UnsignedIsqrt::checked_next_perfect_square(self.0).map(Self)
+                }
+
+                #[inline]
+                fn overflowing_next_perfect_square(self) -> (Self, bool) {
+                    let (square, overflowed) = UnsignedIsqrt::overflowing_next_perfect_square(self.0);
+                    (Self(square), overflowed)
+                }
+
+                #[inline]
+                fn isqrt_exact(self) -> Option<Self> {
+                    UnsignedIsqrt::isqrt_exact(self.0).map(Self)
+                }
+
+                #[inline]
+                fn isqrt_with_exactness(self) -> (Self, bool) {
+                    let (sqrt, exact) = UnsignedIsqrt::isqrt_with_exactness(self.0);
+                    (Self(sqrt), exact)
+                }
+
+                $($unsigned_extra)*
+            }
+        };
+    };
+}
+
+/// Defines a module's `IntIsqrt`, which covers every type its `SignedIsqrt` and `UnsignedIsqrt` do, so that generic code
+/// can take the square root of any integer with one bound. The methods forward to those two traits.
+macro_rules! int_isqrt {
+    () => {
+        /// Integer square roots of every integer type, signed or unsigned, for generic code.
+        ///
+        /// This trait is sealed, so it can't be implemented outside this crate.
+        pub trait IntIsqrt: Sized + crate::sealed::Sealed {
+            /// Returns the largest integer whose square is at most `self`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `self` is negative, as the signed `isqrt` does.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            fn isqrt(self) -> Self;
+            /// Returns the square root, or `None` if `self` is negative. For unsigned types, this is always `Some`.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            fn checked_isqrt(self) -> Option<Self>;
+            /// Returns the square root along with the remainder, `self - sqrt * sqrt`, or `None` if `self` is negative.
+            /// For unsigned types, this is always `Some`.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            fn checked_isqrt_rem(self) -> Option<(Self, Self)>;
+        }
+
+        int_isqrt!(@signed i8, i16, i32, i64, i128, isize);
+        int_isqrt!(@unsigned u8, u16, u32, u64, u128, usize);
+        int_isqrt!(@wrapped Wrapping);
+        int_isqrt!(@wrapped Saturating);
+    };
+    (@wrapped $Wrapper:ident) => {
+        int_isqrt!(
+            @signed
+            core::num::$Wrapper<i8>,
+            core::num::$Wrapper<i16>,
+            core::num::$Wrapper<i32>,
+            core::num::$Wrapper<i64>,
+            core::num::$Wrapper<i128>,
+            core::num::$Wrapper<isize>
+        );
+        int_isqrt!(
+            @unsigned
+            core::num::$Wrapper<u8>,
+            core::num::$Wrapper<u16>,
+            core::num::$Wrapper<u32>,
+            core::num::$Wrapper<u64>,
+            core::num::$Wrapper<u128>,
+            core::num::$Wrapper<usize>
+        );
+    };
+    (@signed $($type:ty),+) => {
+        $(
+            impl IntIsqrt for $type {
+                #[inline]
+                fn isqrt(self) -> Self {
+                    SignedIsqrt::isqrt(self)
+                }
+
+                #[inline]
+                fn checked_isqrt(self) -> Option<Self> {
+                    SignedIsqrt::checked_isqrt(self)
+                }
+
+                #[inline]
+                fn checked_isqrt_rem(self) -> Option<(Self, Self)> {
+                    SignedIsqrt::checked_isqrt(self).map(|sqrt| (sqrt, self - sqrt * sqrt))
+                }
+            }
+        )+
+    };
+    (@unsigned $($type:ty),+) => {
+        $(
+            impl IntIsqrt for $type {
+                #[inline]
+                fn isqrt(self) -> Self {
+                    UnsignedIsqrt::isqrt(self)
+                }
+
+                #[inline]
+                fn checked_isqrt(self) -> Option<Self> {
+                    Some(UnsignedIsqrt::isqrt(self))
+                }
+
+                #[inline]
+                fn checked_isqrt_rem(self) -> Option<(Self, Self)> {
+                    let sqrt = UnsignedIsqrt::isqrt(self);
+                    Some((sqrt, self - sqrt * sqrt))
+                }
+            }
+        )+
+    };
+}
+
+/// The square root of each type's maximum value, computed once at compile time, for the modules' `ISQRT_MAX` constants.
+trait IsqrtMax {
+    const ISQRT_MAX: Self;
+}
+
+/// Returns the integer square root of `n`, computed one bit at a time.
+///
+/// This is only for the `ISQRT_MAX` constants, so that they don't depend on any of the algorithm modules, which can
+/// each be left out with their Cargo features.
+const fn const_isqrt(n: u128) -> u128 {
+    let mut remainder = n;
+    let mut root = 0;
+    // The largest power of four that is at most `n`.
+    let mut bit = if n == 0 { 0 } else { 1 << (n.ilog2() & !1) };
+    while bit != 0 {
+        if remainder >= root + bit {
+            remainder -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    root
+}
+
+macro_rules! isqrt_max {
+    ($($SignedT:ty, $UnsignedT:ty, $NonZeroT:ty);+) => {
+        $(
+            impl IsqrtMax for $SignedT {
+                const ISQRT_MAX: Self = const_isqrt(<$SignedT>::MAX as u128) as Self;
+            }
+
+            impl IsqrtMax for $UnsignedT {
+                const ISQRT_MAX: Self = const_isqrt(<$UnsignedT>::MAX as u128) as Self;
+            }
+
+            impl IsqrtMax for $NonZeroT {
+                const ISQRT_MAX: Self = <$NonZeroT>::new(<$UnsignedT as IsqrtMax>::ISQRT_MAX).unwrap();
+            }
+        )+
+    };
+}
+
+isqrt_max!(
+    i8, u8, core::num::NonZeroU8;
+    i16, u16, core::num::NonZeroU16;
+    i32, u32, core::num::NonZeroU32;
+    i64, u64, core::num::NonZeroU64;
+    i128, u128, core::num::NonZeroU128
+);
+
+impl IsqrtMax for isize {
+    const ISQRT_MAX: Self = <IsizeDelegate as IsqrtMax>::ISQRT_MAX as Self;
+}
+
+impl IsqrtMax for usize {
+    const ISQRT_MAX: Self = <UsizeDelegate as IsqrtMax>::ISQRT_MAX as Self;
+}
+
+impl IsqrtMax for core::num::NonZeroUsize {
+    const ISQRT_MAX: Self = Self::new(<usize as IsqrtMax>::ISQRT_MAX).unwrap();
+}
+
+impl<T: IsqrtMax> IsqrtMax for core::num::Wrapping<T> {
+    const ISQRT_MAX: Self = Self(T::ISQRT_MAX);
+}
+
+impl<T: IsqrtMax> IsqrtMax for core::num::Saturating<T> {
+    const ISQRT_MAX: Self = Self(T::ISQRT_MAX);
+}
+
+/// Keeps the square root traits from being implemented outside this crate, as the implementations tell the optimizer
+/// what range the results are in, and any other implementation couldn't be trusted to uphold that.
+mod sealed {
+    pub trait Sealed {}
+
+    macro_rules! sealed {
+        ($($type:ty),+) => {
+            $(impl Sealed for $type {})+
+        };
+    }
+
+    sealed!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    sealed!(f32, f64);
+    sealed!(
+        core::num::NonZeroU8,
+        core::num::NonZeroU16,
+        core::num::NonZeroU32,
+        core::num::NonZeroU64,
+        core::num::NonZeroU128,
+        core::num::NonZeroUsize
+    );
+
+    impl<T: Sealed> Sealed for core::num::Wrapping<T> {}
+    impl<T: Sealed> Sealed for core::num::Saturating<T> {}
+}
+
+#[cfg(all(feature = "algo-floating", feature = "std", not(feature = "no-float")))]
+pub mod batch;
+#[cfg(feature = "algo-karatsuba")]
+pub mod bitpacked;
+#[cfg(all(feature = "algo-karatsuba", feature = "alloc"))]
+pub mod bytes;
+#[cfg(all(feature = "std", not(feature = "no-float")))]
+pub mod cbrt;
+#[cfg(feature = "alloc")]
+pub mod decimal;
+#[cfg(feature = "algo-karatsuba")]
+pub mod default;
+#[cfg(all(feature = "algo-karatsuba", feature = "alloc"))]
+pub mod fixed;
+#[cfg(all(feature = "algo-floating", not(feature = "no-float")))]
+pub mod float;
+#[cfg(all(feature = "algo-floating", not(feature = "no-float")))]
 pub mod floating_point;
+#[cfg(all(feature = "algo-floating-karatsuba", not(feature = "no-float")))]
 pub mod floating_point_and_karatsuba;
+pub mod heron;
+#[cfg(feature = "algo-karatsuba")]
+pub mod iter;
+#[cfg(feature = "algo-karatsuba")]
 pub mod karatsuba;
+#[cfg(feature = "algo-karatsuba-2")]
 pub mod karatsuba_2;
-//pub mod libgmp;
+#[cfg(feature = "libgmp")]
+pub mod libgmp;
+pub mod newton;
+#[cfg(feature = "algo-original")]
 pub mod original;
-//pub mod table;
+#[cfg(feature = "algo-karatsuba")]
+pub mod prelude;
+#[cfg(feature = "num-traits")]
+pub mod prim_int;
+pub mod root;
+pub mod select;
+#[cfg(all(feature = "runtime-self-check", not(feature = "no-float")))]
+mod self_check;
+#[cfg(feature = "algo-karatsuba")]
+pub mod square_distance;
+pub mod swar;
+pub mod table;
+pub mod tables;
 #[cfg(test)]
 mod tests;
+#[cfg(feature = "algo-karatsuba")]
+pub mod triangular;
+#[cfg(feature = "algo-karatsuba")]
+pub mod wide;
+
+#[cfg(feature = "algo-karatsuba")]
+pub use default::{checked_isqrt, isqrt, DefaultIsqrt};
+
+#[cfg(all(
+    any(feature = "algo-floating", feature = "algo-floating-karatsuba"),
+    not(any(feature = "std", feature = "libm")),
+    not(feature = "no-float")
+))]
+compile_error!(
+    "the floating-point modules need the `std` or `libm` feature for their square roots"
+);
+
+/// Returns the correctly rounded square root of `x`. Without `std`, which has no `f32::sqrt`, this uses `libm`.
+#[inline(always)]
+#[cfg(all(
+    any(feature = "algo-floating", feature = "algo-floating-karatsuba"),
+    not(feature = "no-float")
+))]
+fn sqrt_f32(x: f32) -> f32 {
+    #[cfg(feature = "std")]
+    return x.sqrt();
+    #[cfg(all(feature = "libm", not(feature = "std")))]
+    return libm::sqrtf(x);
+}
+
+/// Returns the correctly rounded square root of `x`. Without `std`, which has no `f64::sqrt`, this uses `libm`.
+#[inline(always)]
+#[cfg(all(
+    any(feature = "algo-floating", feature = "algo-floating-karatsuba"),
+    not(feature = "no-float")
+))]
+fn sqrt_f64(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    return x.sqrt();
+    #[cfg(all(feature = "libm", not(feature = "std")))]
+    return libm::sqrt(x);
+}
+
+/// Informs the optimizer that `condition` holds. Without the `nightly` feature, this does nothing, except in debug
+/// builds, where it panics if `condition` is false.
+///
+/// # Safety
+///
+/// `condition` must be true.
+#[inline(always)]
+const unsafe fn assume(condition: bool) {
+    debug_assert!(condition, "an optimizer hint doesn't hold");
+    #[cfg(feature = "nightly")]
+    core::intrinsics::assume(condition);
+    #[cfg(not(feature = "nightly"))]
+    let _ = condition;
+}
+
+/// Panics in debug builds if `sqrt` isn't the integer square root of `n`, that is, if `sqrt^2 <= n < (sqrt + 1)^2`
+/// doesn't hold.
+///
+/// The unsigned `isqrt` methods call this just before telling the optimizer about their result's bounds, so that a
+/// wrong result fails loudly in tests instead of making a false `assume` undefined behavior. Release builds skip it.
+#[inline(always)]
+#[track_caller]
+const fn debug_assert_isqrt(n: u128, sqrt: u128) {
+    debug_assert!(
+        match sqrt.checked_mul(sqrt) {
+            Some(square) => square <= n,
+            None => false,
+        } && match (sqrt + 1).checked_mul(sqrt + 1) {
+            Some(next_square) => n < next_square,
+            None => true,
+        },
+        "the integer square root is wrong"
+    );
+}
+
+/// Called by the signed `isqrt` methods when their argument is negative.
+///
+/// By default, this panics. With the `no-panic-signed` feature, this instead returns zero so that signed `isqrt` never
+/// panics, which suits targets that can't unwind or report panics. That mode is lossy: a negative argument can't be
+/// told apart from zero, so use `checked_isqrt` wherever that matters.
+///
+/// Every signed `checked_isqrt` checks the sign before casting its argument to an unsigned type, so `MIN`, which has no
+/// positive counterpart, ends up here like any other negative argument.
+#[inline]
+#[track_caller]
+fn negative_isqrt_argument<T: Default>() -> T {
+    if cfg!(feature = "no-panic-signed") {
+        T::default()
+    } else {
+        isqrt_negative_panic()
+    }
+}
+
+/// The panic of `negative_isqrt_argument`, kept out of line so that the panic and formatting machinery isn't inlined
+/// into every signed `isqrt`, which keeps the nonnegative path small.
+///
+/// This is a `const fn` so that the `const` signed functions can panic the same way.
+#[cold]
+#[inline(never)]
+#[track_caller]
+const fn isqrt_negative_panic() -> ! {
+    panic!("{}", NEGATIVE_INPUT_MESSAGE)
+}
+
+/// The panic message for a negative argument, which is also how `NegativeInputError` displays.
+const NEGATIVE_INPUT_MESSAGE: &str = "argument of integer square root must be non-negative";
+
+/// The error returned by the signed `try_isqrt` methods when their argument is negative.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NegativeInputError;
+
+impl core::fmt::Display for NegativeInputError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(NEGATIVE_INPUT_MESSAGE)
+    }
+}
+
+impl core::error::Error for NegativeInputError {}
+
+// The fixed-width types that `isize` and `usize` forward to in each module.
+#[cfg(target_pointer_width = "16")]
+type IsizeDelegate = i16;
+#[cfg(target_pointer_width = "16")]
+type UsizeDelegate = u16;
+#[cfg(target_pointer_width = "32")]
+type IsizeDelegate = i32;
+#[cfg(target_pointer_width = "32")]
+type UsizeDelegate = u32;
+#[cfg(target_pointer_width = "64")]
+type IsizeDelegate = i64;
+#[cfg(target_pointer_width = "64")]
+type UsizeDelegate = u64;
@@ -0,0 +1,266 @@
+//! Square roots computed by GMP's `mpz_sqrt`, for comparing the other modules' correctness and speed against.
+//!
+//! Every width goes through a 128-bit GMP integer, so this measures GMP along with its call and conversion overhead.
+
+use core::ffi::{c_int, c_void};
+use core::mem::MaybeUninit;
+
+/// Integer square roots of the signed integer types.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+pub trait SignedIsqrt: Sized + crate::sealed::Sealed {
+    /// The square root of `Self::MAX`, which no square root exceeds.
+    const ISQRT_MAX: Self;
+
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_isqrt(self) -> Option<Self>;
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt(self) -> Self;
+    /// Returns the square root, or a `NegativeInputError` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError>;
+    /// Returns the square root, or `default` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_or(self, default: Self) -> Self;
+    /// Returns the square root, or zero if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_or_zero(self) -> Self;
+    /// Returns the square root, or zero if `self` is negative, which clamps negative arguments to zero first.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    fn saturating_isqrt(self) -> Self {
+        SignedIsqrt::isqrt_or_zero(self)
+    }
+    /// Returns the smallest integer whose square is at least `self`, or `None` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_ceil_isqrt(self) -> Option<Self>;
+    /// Returns whether `self` is the square of an integer. Negative numbers never are.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn is_perfect_square(self) -> bool;
+}
+/// Integer square roots of the unsigned integer types.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+pub trait UnsignedIsqrt: Sized + crate::sealed::Sealed {
+    /// The square root of `Self::MAX`, which no square root exceeds.
+    const ISQRT_MAX: Self;
+
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt(self) -> Self;
+    /// Returns the largest integer whose square is at most `self`, the same as `isqrt`, named to match `ceil_isqrt`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    fn isqrt_floor(self) -> Self {
+        UnsignedIsqrt::isqrt(self)
+    }
+    /// Returns the smallest integer whose square is at least `self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn ceil_isqrt(self) -> Self;
+    /// Returns whether `self` is the square of an integer.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn is_perfect_square(self) -> bool;
+    /// Returns the largest perfect square that is at most `self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn prev_perfect_square(self) -> Self;
+    /// Returns the smallest perfect square greater than `self`.
+    ///
+    /// Panics if that square doesn't fit in `Self`, which happens when `self` is at least the largest perfect square
+    /// that does (for example, 225 for `u8`).
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn next_perfect_square(self) -> Self;
+    /// Returns the smallest perfect square greater than `self`, or `None` if it doesn't fit in `Self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_next_perfect_square(self) -> Option<Self>;
+    /// Returns the smallest perfect square greater than `self`, along with whether it overflowed `Self`. If it did,
+    /// which happens when `self` is at least the largest perfect square that fits, the value wraps around to zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_next_perfect_square(self) -> (Self, bool);
+    /// Returns the square root if `self` is a perfect square, or `None` otherwise.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_exact(self) -> Option<Self>;
+    /// Returns the integer square root along with whether `self` is a perfect square.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_with_exactness(self) -> (Self, bool);
+}
+
+/// GMP's `__mpz_struct`.
+#[repr(C)]
+struct Mpz {
+    alloc: c_int,
+    size: c_int,
+    limbs: *mut c_void,
+}
+
+#[link(name = "gmp")]
+extern "C" {
+    #[link_name = "__gmpz_init"]
+    fn mpz_init(x: *mut Mpz);
+    #[link_name = "__gmpz_clear"]
+    fn mpz_clear(x: *mut Mpz);
+    #[link_name = "__gmpz_import"]
+    fn mpz_import(
+        rop: *mut Mpz,
+        count: usize,
+        order: c_int,
+        size: usize,
+        endian: c_int,
+        nails: usize,
+        op: *const c_void,
+    );
+    #[link_name = "__gmpz_export"]
+    fn mpz_export(
+        rop: *mut c_void,
+        countp: *mut usize,
+        order: c_int,
+        size: usize,
+        endian: c_int,
+        nails: usize,
+        op: *const Mpz,
+    ) -> *mut c_void;
+    #[link_name = "__gmpz_sqrt"]
+    fn mpz_sqrt(rop: *mut Mpz, op: *const Mpz);
+}
+
+fn gmp_isqrt_128(n: u128) -> u128 {
+    let mut sqrt = 0_u128;
+
+    let mut z = MaybeUninit::<Mpz>::uninit();
+    // SAFETY: `z` is initialized before use and cleared afterward. The import reads one native-endian 16-byte word from
+    // `n`, and the export writes at most one 16-byte word to `sqrt`, as the square root is no bigger than `n`. A zero
+    // exports no words, leaving `sqrt` at zero.
+    unsafe {
+        mpz_init(z.as_mut_ptr());
+        mpz_import(z.as_mut_ptr(), 1, -1, 16, 0, 0, (&n as *const u128).cast());
+        mpz_sqrt(z.as_mut_ptr(), z.as_ptr());
+        mpz_export(
+            (&mut sqrt as *mut u128).cast(),
+            &mut 0,
+            -1,
+            16,
+            0,
+            0,
+            z.as_ptr(),
+        );
+        mpz_clear(z.as_mut_ptr());
+    }
+
+    sqrt
+}
+
+macro_rules! isqrt_impl {
+    ($signed_type:ty, $unsigned_type:ty) => {
+        impl SignedIsqrt for $signed_type {
+            const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
+            #[inline]
+            fn checked_isqrt(self) -> Option<Self> {
+                (self >= 0).then(|| gmp_isqrt_128(self as u128) as Self)
+            }
+
+            #[inline]
+            fn isqrt(self) -> Self {
+                SignedIsqrt::checked_isqrt(self).unwrap_or_else(crate::negative_isqrt_argument)
+            }
+
+            #[inline]
+            fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+                SignedIsqrt::checked_isqrt(self).ok_or(crate::NegativeInputError)
+            }
+
+            #[inline]
+            fn isqrt_or(self, default: Self) -> Self {
+                SignedIsqrt::checked_isqrt(self).unwrap_or(default)
+            }
+
+            #[inline]
+            fn isqrt_or_zero(self) -> Self {
+                SignedIsqrt::checked_isqrt(self).unwrap_or(0)
+            }
+
+            #[inline]
+            fn checked_ceil_isqrt(self) -> Option<Self> {
+                SignedIsqrt::checked_isqrt(self).map(|sqrt| {
+                    if sqrt * sqrt == self {
+                        sqrt
+                    } else {
+                        sqrt + 1
+                    }
+                })
+            }
+
+            #[inline]
+            fn is_perfect_square(self) -> bool {
+                self >= 0 && UnsignedIsqrt::is_perfect_square(self as $unsigned_type)
+            }
+        }
+
+        impl UnsignedIsqrt for $unsigned_type {
+            const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
+            #[inline]
+            fn isqrt(self) -> Self {
+                gmp_isqrt_128(self as u128) as Self
+            }
+
+            #[inline]
+            fn ceil_isqrt(self) -> Self {
+                // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+                let sqrt = UnsignedIsqrt::isqrt(self);
+                if sqrt * sqrt == self {
+                    sqrt
+                } else {
+                    sqrt + 1
+                }
+            }
+
+            #[inline]
+            fn is_perfect_square(self) -> bool {
+                let sqrt = UnsignedIsqrt::isqrt(self);
+                sqrt * sqrt == self
+            }
+            #[inline]
+            fn prev_perfect_square(self) -> Self {
+                let sqrt = UnsignedIsqrt::isqrt(self);
+                sqrt * sqrt
+            }
+            #[inline]
+            fn next_perfect_square(self) -> Self {
+                UnsignedIsqrt::checked_next_perfect_square(self)
+                    .expect("no larger perfect square fits in the type")
+            }
+            #[inline]
+            fn checked_next_perfect_square(self) -> Option<Self> {
+                // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+                let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+                sqrt.checked_mul(sqrt)
+            }
+            #[inline]
+            fn overflowing_next_perfect_square(self) -> (Self, bool) {
+                // As in `checked_next_perfect_square`, `sqrt + 1` can't overflow.
+                let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+                sqrt.overflowing_mul(sqrt)
+            }
+            #[inline]
+            fn isqrt_exact(self) -> Option<Self> {
+                let sqrt = UnsignedIsqrt::isqrt(self);
+                (sqrt * sqrt == self).then_some(sqrt)
+            }
+            #[inline]
+            fn isqrt_with_exactness(self) -> (Self, bool) {
+                let sqrt = UnsignedIsqrt::isqrt(self);
+                (sqrt, sqrt * sqrt == self)
+            }
+        }
+    };
+}
+
+isqrt_impl!(i8, u8);
+isqrt_impl!(i16, u16);
+isqrt_impl!(i32, u32);
+isqrt_impl!(i64, u64);
+isqrt_impl!(i128, u128);
+isqrt_impl!(isize, usize);
+
+wrapper_isqrt!(Wrapping, Saturating);
+
+int_isqrt!();
@@ -0,0 +1,341 @@
+/// Integer square roots of the signed integer types.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+pub trait SignedIsqrt: Sized + crate::sealed::Sealed {
+    /// The square root of `Self::MAX`, which no square root exceeds.
+    const ISQRT_MAX: Self;
+
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_isqrt(self) -> Option<Self>;
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt(self) -> Self;
+    /// Returns the square root, or a `NegativeInputError` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError>;
+    /// Returns the square root, or `default` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_or(self, default: Self) -> Self;
+    /// Returns the square root, or zero if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_or_zero(self) -> Self;
+    /// Returns the square root, or zero if `self` is negative, which clamps negative arguments to zero first.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    fn saturating_isqrt(self) -> Self {
+        SignedIsqrt::isqrt_or_zero(self)
+    }
+    /// Returns the smallest integer whose square is at least `self`, or `None` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_ceil_isqrt(self) -> Option<Self>;
+    /// Returns whether `self` is the square of an integer. Negative numbers never are.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn is_perfect_square(self) -> bool;
+}
+/// Integer square roots of the unsigned integer types.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+pub trait UnsignedIsqrt: Sized + crate::sealed::Sealed {
+    /// The square root of `Self::MAX`, which no square root exceeds.
+    const ISQRT_MAX: Self;
+
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt(self) -> Self;
+    /// Returns the largest integer whose square is at most `self`, the same as `isqrt`, named to match `ceil_isqrt`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    fn isqrt_floor(self) -> Self {
+        UnsignedIsqrt::isqrt(self)
+    }
+    /// Returns the smallest integer whose square is at least `self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn ceil_isqrt(self) -> Self;
+    /// Returns whether `self` is the square of an integer.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn is_perfect_square(self) -> bool;
+    /// Returns the largest perfect square that is at most `self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn prev_perfect_square(self) -> Self;
+    /// Returns the smallest perfect square greater than `self`.
+    ///
+    /// Panics if that square doesn't fit in `Self`, which happens when `self` is at least the largest perfect square
+    /// that does (for example, 225 for `u8`).
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn next_perfect_square(self) -> Self;
+    /// Returns the smallest perfect square greater than `self`, or `None` if it doesn't fit in `Self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_next_perfect_square(self) -> Option<Self>;
+    /// Returns the smallest perfect square greater than `self`, along with whether it overflowed `Self`. If it did,
+    /// which happens when `self` is at least the largest perfect square that fits, the value wraps around to zero.
+    ///
+    /// The `NonZero` types can't hold zero, so for them the value is one instead. That isn't the inner integer's
+    /// wrapped value, so check the flag rather than comparing the value with zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_next_perfect_square(self) -> (Self, bool);
+    /// Returns the square root if `self` is a perfect square, or `None` otherwise.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_exact(self) -> Option<Self>;
+    /// Returns the integer square root along with whether `self` is a perfect square.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_with_exactness(self) -> (Self, bool);
+}
+
+macro_rules! newton_isqrt {
+    ($FullBitsT:ty, $newton_isqrt:ident) => {
+        const fn $newton_isqrt(n: $FullBitsT) -> $FullBitsT {
+            // Performs Newton's method with integer arithmetic, seeded from the input's bit length.
+            // https://en.wikipedia.org/wiki/Integer_square_root#Algorithm_using_Newton's_method
+
+            if n < 2 {
+                return n;
+            }
+
+            // As `n < 2^(ilog2(n) + 1)`, the seed is above the square root. Iterations starting above the floored square
+            // root decrease until they reach it and never go below it, so stop as soon as the iterate stops decreasing.
+            let mut x: $FullBitsT = 1 << ((n.ilog2() >> 1) + 1);
+            loop {
+                let next = (x + n / x) >> 1;
+                if next >= x {
+                    return x;
+                }
+                x = next;
+            }
+        }
+    };
+}
+
+newton_isqrt!(u8, newton_isqrt_8);
+newton_isqrt!(u16, newton_isqrt_16);
+newton_isqrt!(u32, newton_isqrt_32);
+newton_isqrt!(u64, newton_isqrt_64);
+newton_isqrt!(u128, newton_isqrt_128);
+
+macro_rules! isqrt_impl {
+    ($signed_type:ty, $unsigned_type:ty, $newton_isqrt:ident) => {
+        impl SignedIsqrt for $signed_type {
+            const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
+            #[inline(always)]
+            fn checked_isqrt(self) -> Option<Self> {
+                (self >= 0).then(|| {
+                    let result = $newton_isqrt(self as _) as Self;
+
+                    // SAFETY: the result is nonnegative and less than or equal to `MAX.isqrt()`.
+                    // Inform the optimizer about it.
+                    unsafe {
+                        crate::assume(0 <= result);
+                        crate::assume(result <= <Self as SignedIsqrt>::ISQRT_MAX);
+                    }
+
+                    result
+                })
+            }
+
+            #[inline]
+            fn isqrt(self) -> Self {
+                SignedIsqrt::checked_isqrt(self).unwrap_or_else(crate::negative_isqrt_argument)
+            }
+
+            #[inline]
+            fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+                SignedIsqrt::checked_isqrt(self).ok_or(crate::NegativeInputError)
+            }
+
+            #[inline]
+            fn isqrt_or(self, default: Self) -> Self {
+                SignedIsqrt::checked_isqrt(self).unwrap_or(default)
+            }
+
+            #[inline]
+            fn isqrt_or_zero(self) -> Self {
+                SignedIsqrt::checked_isqrt(self).unwrap_or(0)
+            }
+
+            #[inline]
+            fn checked_ceil_isqrt(self) -> Option<Self> {
+                SignedIsqrt::checked_isqrt(self).map(|sqrt| {
+                    if sqrt * sqrt == self {
+                        sqrt
+                    } else {
+                        sqrt + 1
+                    }
+                })
+            }
+
+            #[inline]
+            fn is_perfect_square(self) -> bool {
+                self >= 0 && UnsignedIsqrt::is_perfect_square(self as $unsigned_type)
+            }
+        }
+
+        impl UnsignedIsqrt for $unsigned_type {
+            const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
+            #[inline(always)]
+            fn isqrt(self) -> Self {
+                let result = $newton_isqrt(self);
+
+                crate::debug_assert_isqrt(self as u128, result as u128);
+
+                // SAFETY: the result fits in an integer with half as many bits.
+                // Inform the optimizer about it.
+                unsafe {
+                    crate::assume(result < 1 << ((<$unsigned_type>::BITS as Self) >> 1));
+                }
+
+                result
+            }
+
+            #[inline]
+            fn ceil_isqrt(self) -> Self {
+                // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+                let sqrt = UnsignedIsqrt::isqrt(self);
+                if sqrt * sqrt == self {
+                    sqrt
+                } else {
+                    sqrt + 1
+                }
+            }
+
+            #[inline]
+            fn is_perfect_square(self) -> bool {
+                let sqrt = UnsignedIsqrt::isqrt(self);
+                sqrt * sqrt == self
+            }
+            #[inline]
+            fn prev_perfect_square(self) -> Self {
+                let sqrt = UnsignedIsqrt::isqrt(self);
+                sqrt * sqrt
+            }
+            #[inline]
+            fn next_perfect_square(self) -> Self {
+                UnsignedIsqrt::checked_next_perfect_square(self)
+                    .expect("no larger perfect square fits in the type")
+            }
+            #[inline]
+            fn checked_next_perfect_square(self) -> Option<Self> {
+                // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+                let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+                sqrt.checked_mul(sqrt)
+            }
+            #[inline]
+            fn overflowing_next_perfect_square(self) -> (Self, bool) {
+                // As in `checked_next_perfect_square`, `sqrt + 1` can't overflow.
+                let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+                sqrt.overflowing_mul(sqrt)
+            }
+            #[inline]
+            fn isqrt_exact(self) -> Option<Self> {
+                let sqrt = UnsignedIsqrt::isqrt(self);
+                (sqrt * sqrt == self).then_some(sqrt)
+            }
+            #[inline]
+            fn isqrt_with_exactness(self) -> (Self, bool) {
+                let sqrt = UnsignedIsqrt::isqrt(self);
+                (sqrt, sqrt * sqrt == self)
+            }
+        }
+    };
+}
+
+isqrt_impl!(i8, u8, newton_isqrt_8);
+isqrt_impl!(i16, u16, newton_isqrt_16);
+isqrt_impl!(i32, u32, newton_isqrt_32);
+isqrt_impl!(i64, u64, newton_isqrt_64);
+isqrt_impl!(i128, u128, newton_isqrt_128);
+
+// `isize` and `usize` forward to the fixed-width type of the same size, which keeps its `assume` hints.
+impl SignedIsqrt for isize {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
+    #[inline]
+    fn checked_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_isqrt(self as crate::IsizeDelegate).map(|sqrt| sqrt as Self)
+    }
+
+    #[inline]
+    fn isqrt(self) -> Self {
+        SignedIsqrt::isqrt(self as crate::IsizeDelegate) as Self
+    }
+
+    #[inline]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+        SignedIsqrt::checked_isqrt(self).ok_or(crate::NegativeInputError)
+    }
+
+    #[inline]
+    fn isqrt_or(self, default: Self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(default)
+    }
+
+    #[inline]
+    fn isqrt_or_zero(self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(0)
+    }
+
+    #[inline]
+    fn checked_ceil_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_ceil_isqrt(self as crate::IsizeDelegate).map(|sqrt| sqrt as Self)
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        SignedIsqrt::is_perfect_square(self as crate::IsizeDelegate)
+    }
+}
+
+impl UnsignedIsqrt for usize {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
+    #[inline]
+    fn isqrt(self) -> Self {
+        UnsignedIsqrt::isqrt(self as crate::UsizeDelegate) as Self
+    }
+
+    #[inline]
+    fn ceil_isqrt(self) -> Self {
+        UnsignedIsqrt::ceil_isqrt(self as crate::UsizeDelegate) as Self
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        UnsignedIsqrt::is_perfect_square(self as crate::UsizeDelegate)
+    }
+    #[inline]
+    fn prev_perfect_square(self) -> Self {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        sqrt * sqrt
+    }
+    #[inline]
+    fn next_perfect_square(self) -> Self {
+        UnsignedIsqrt::checked_next_perfect_square(self)
+            .expect("no larger perfect square fits in the type")
+    }
+    #[inline]
+    fn checked_next_perfect_square(self) -> Option<Self> {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.checked_mul(sqrt)
+    }
+    #[inline]
+    fn overflowing_next_perfect_square(self) -> (Self, bool) {
+        // As in `checked_next_perfect_square`, `sqrt + 1` can't overflow.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.overflowing_mul(sqrt)
+    }
+    #[inline]
+    fn isqrt_exact(self) -> Option<Self> {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        (sqrt * sqrt == self).then_some(sqrt)
+    }
+    #[inline]
+    fn isqrt_with_exactness(self) -> (Self, bool) {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        (sqrt, sqrt * sqrt == self)
+    }
+}
+
+nonzero_unsigned_isqrt!();
+
+wrapper_isqrt!(Wrapping, Saturating);
+
+int_isqrt!();
@@ -1,91 +1,447 @@
-use core::intrinsics;
+/// Integer square roots of the signed integer types.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+pub trait SignedIsqrt: Sized + crate::sealed::Sealed {
+    /// The square root of `Self::MAX`, which no square root exceeds.
+    const ISQRT_MAX: Self;
 
-pub trait SignedIsqrt: Sized {
+    #[must_use = "this returns the result of the operation, without modifying the original"]
     fn checked_isqrt(self) -> Option<Self>;
+    #[must_use = "this returns the result of the operation, without modifying the original"]
     fn isqrt(self) -> Self;
+    /// Returns the square root, or a `NegativeInputError` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError>;
+    /// Returns the square root, or `default` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_or(self, default: Self) -> Self;
+    /// Returns the square root, or zero if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_or_zero(self) -> Self;
+    /// Returns the square root, or zero if `self` is negative, which clamps negative arguments to zero first.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    fn saturating_isqrt(self) -> Self {
+        SignedIsqrt::isqrt_or_zero(self)
+    }
+    /// Returns the smallest integer whose square is at least `self`, or `None` if `self` is negative.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_ceil_isqrt(self) -> Option<Self>;
+    /// Returns whether `self` is the square of an integer. Negative numbers never are.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn is_perfect_square(self) -> bool;
 }
 
 macro_rules! signed_isqrt {
-    ($type:ty, $unsigned_type:ty) => {
+    ($type:ty, $unsigned_type:ty, $checked_isqrt:ident, $isqrt:ident, $unsigned_isqrt:ident) => {
+        /// Returns the integer square root of `n`, or `None` if `n` is negative. Usable in `const` contexts.
+        #[inline]
+        pub const fn $checked_isqrt(n: $type) -> Option<$type> {
+            if n < 0 {
+                None
+            } else {
+                Some($unsigned_isqrt(n as $unsigned_type) as $type)
+            }
+        }
+
+        /// Returns the integer square root of `n`. Usable in `const` contexts.
+        ///
+        /// Panics if `n` is negative, unless the `no-panic-signed` feature is enabled, in which case this returns zero.
+        #[inline]
+        #[track_caller]
+        pub const fn $isqrt(n: $type) -> $type {
+            // `negative_isqrt_argument` is generic over `Default`, which can't be called in a `const fn`, so this
+            // makes the same choice itself.
+            match $checked_isqrt(n) {
+                Some(sqrt) => sqrt,
+                None if cfg!(feature = "no-panic-signed") => 0,
+                None => crate::isqrt_negative_panic(),
+            }
+        }
+
         impl SignedIsqrt for $type {
+            const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
             #[inline]
             fn checked_isqrt(self) -> Option<Self> {
-                if self < 0 {
-                    None
-                } else {
-                    Some((self as $unsigned_type).isqrt() as $type)
-                }
+                $checked_isqrt(self)
             }
 
             #[inline]
             fn isqrt(self) -> Self {
-                // I would like to implement it as
-                // ```
-                // self.checked_isqrt().expect("argument of integer square root must be non-negative")
-                // ```
-                // but `expect` is not yet stable as a `const fn`.
-                match self.checked_isqrt() {
-                    Some(sqrt) => sqrt,
-                    None => panic!("argument of integer square root must be non-negative"),
-                }
+                $isqrt(self)
+            }
+
+            #[inline]
+            fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+                SignedIsqrt::checked_isqrt(self).ok_or(crate::NegativeInputError)
+            }
+
+            #[inline]
+            fn isqrt_or(self, default: Self) -> Self {
+                SignedIsqrt::checked_isqrt(self).unwrap_or(default)
+            }
+
+            #[inline]
+            fn isqrt_or_zero(self) -> Self {
+                SignedIsqrt::checked_isqrt(self).unwrap_or(0)
+            }
+
+            #[inline]
+            fn checked_ceil_isqrt(self) -> Option<Self> {
+                SignedIsqrt::checked_isqrt(self).map(|sqrt| {
+                    if sqrt * sqrt == self {
+                        sqrt
+                    } else {
+                        sqrt + 1
+                    }
+                })
+            }
+
+            #[inline]
+            fn is_perfect_square(self) -> bool {
+                self >= 0 && UnsignedIsqrt::is_perfect_square(self as $unsigned_type)
             }
         }
     };
 }
 
-signed_isqrt!(i8, u8);
-signed_isqrt!(i16, u16);
-signed_isqrt!(i32, u32);
-signed_isqrt!(i64, u64);
-signed_isqrt!(i128, u128);
+signed_isqrt!(i8, u8, checked_isqrt_i8, isqrt_i8, isqrt_u8);
+signed_isqrt!(i16, u16, checked_isqrt_i16, isqrt_i16, isqrt_u16);
+signed_isqrt!(i32, u32, checked_isqrt_i32, isqrt_i32, isqrt_u32);
+signed_isqrt!(i64, u64, checked_isqrt_i64, isqrt_i64, isqrt_u64);
+signed_isqrt!(i128, u128, checked_isqrt_i128, isqrt_i128, isqrt_u128);
+
+/// Integer square roots of the unsigned integer types.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+pub trait UnsignedIsqrt: Sized + crate::sealed::Sealed {
+    /// The square root of `Self::MAX`, which no square root exceeds.
+    const ISQRT_MAX: Self;
 
-pub trait UnsignedIsqrt {
+    #[must_use = "this returns the result of the operation, without modifying the original"]
     fn isqrt(self) -> Self;
+    /// Returns the largest integer whose square is at most `self`, the same as `isqrt`, named to match `ceil_isqrt`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    fn isqrt_floor(self) -> Self {
+        UnsignedIsqrt::isqrt(self)
+    }
+    /// Returns the smallest integer whose square is at least `self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn ceil_isqrt(self) -> Self;
+    /// Returns whether `self` is the square of an integer.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn is_perfect_square(self) -> bool;
+    /// Returns the largest perfect square that is at most `self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn prev_perfect_square(self) -> Self;
+    /// Returns the smallest perfect square greater than `self`.
+    ///
+    /// Panics if that square doesn't fit in `Self`, which happens when `self` is at least the largest perfect square
+    /// that does (for example, 225 for `u8`).
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn next_perfect_square(self) -> Self;
+    /// Returns the smallest perfect square greater than `self`, or `None` if it doesn't fit in `Self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_next_perfect_square(self) -> Option<Self>;
+    /// Returns the smallest perfect square greater than `self`, along with whether it overflowed `Self`. If it did,
+    /// which happens when `self` is at least the largest perfect square that fits, the value wraps around to zero.
+    ///
+    /// The `NonZero` types can't hold zero, so for them the value is one instead. That isn't the inner integer's
+    /// wrapped value, so check the flag rather than comparing the value with zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_next_perfect_square(self) -> (Self, bool);
+    /// Returns the square root if `self` is a perfect square, or `None` otherwise.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_exact(self) -> Option<Self>;
+    /// Returns the integer square root along with whether `self` is a perfect square.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_with_exactness(self) -> (Self, bool);
 }
 
-macro_rules! unsigned_isqrt {
-    ($unsigned_type:ty) => {
-        impl UnsignedIsqrt for $unsigned_type {
-            #[inline]
-            fn isqrt(self) -> Self {
-                if self < 2 {
-                    return self;
+/// Whether `leading_zeros`, and so `ilog2`, compiles to a single instruction on the target. Elsewhere, it's a loop, and
+/// the de Bruijn lookups below are faster.
+const HAS_FAST_LEADING_ZEROS: bool = cfg!(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "arm64ec",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "s390x",
+    target_arch = "loongarch64",
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "wasm32",
+    target_arch = "wasm64",
+    all(target_arch = "arm", target_feature = "v7"),
+    all(
+        any(target_arch = "riscv32", target_arch = "riscv64"),
+        target_feature = "zbb"
+    ),
+));
+
+macro_rules! de_bruijn_ilog2 {
+    ($FullBitsT:ty, $de_bruijn_ilog2:ident, $de_bruijn_sequence:literal) => {
+        /// Returns `n.ilog2()` without `leading_zeros`, using a de Bruijn sequence.
+        ///
+        /// Panics if `n` is zero.
+        pub(crate) const fn $de_bruijn_ilog2(n: $FullBitsT) -> u32 {
+            // https://graphics.stanford.edu/~seander/bithacks.html#IntegerLogDeBruijn
+            //
+            // Every `log2(BITS)`-bit window of the sequence is different, so shifting it left by a bit position
+            // leaves a distinct value in the top bits, which the table maps back to the bit position.
+            const DE_BRUIJN_SEQUENCE: $FullBitsT = $de_bruijn_sequence;
+            const SHIFT: u32 = <$FullBitsT>::BITS - <$FullBitsT>::BITS.ilog2();
+            const BIT_POSITIONS: [u8; <$FullBitsT>::BITS as usize] = {
+                let mut result = [0; <$FullBitsT>::BITS as usize];
+
+                let mut i = 0;
+                while i < result.len() {
+                    result[((DE_BRUIJN_SEQUENCE << i) >> SHIFT) as usize] = i as u8;
+                    i += 1;
                 }
 
-                // The algorithm is based on the one presented in
-                // <https://en.wikipedia.org/wiki/Methods_of_computing_square_roots#Binary_numeral_system_(base_2)>
-                // which cites as source the following C code:
-                // <https://web.archive.org/web/20120306040058/http://medialab.freaknet.org/martin/src/sqrt/sqrt.c>.
+                result
+            };
 
-                let mut op = self;
-                let mut res = 0;
-                let mut one = 1 << (self.ilog2() & !1);
+            assert!(n != 0, "argument of integer logarithm must be positive");
 
-                while one != 0 {
-                    if op >= res + one {
-                        op -= res + one;
-                        res = (res >> 1) + one;
-                    } else {
-                        res >>= 1;
-                    }
-                    one >>= 2;
+            // Set every bit below the highest set bit, then clear all but the highest set bit.
+            let mut n = n;
+            let mut shift = 1;
+            while shift < <$FullBitsT>::BITS {
+                n |= n >> shift;
+                shift <<= 1;
+            }
+            let highest_bit = n ^ (n >> 1);
+
+            BIT_POSITIONS[(highest_bit.wrapping_mul(DE_BRUIJN_SEQUENCE) >> SHIFT) as usize] as u32
+        }
+    };
+}
+
+de_bruijn_ilog2!(u32, de_bruijn_ilog2_32, 0x077C_B531);
+de_bruijn_ilog2!(u64, de_bruijn_ilog2_64, 0x0218_A392_CD3D_5DBF);
+
+#[inline]
+const fn ilog2_32(n: u32) -> u32 {
+    if HAS_FAST_LEADING_ZEROS {
+        n.ilog2()
+    } else {
+        de_bruijn_ilog2_32(n)
+    }
+}
+
+#[inline]
+const fn ilog2_64(n: u64) -> u32 {
+    if HAS_FAST_LEADING_ZEROS {
+        n.ilog2()
+    } else {
+        de_bruijn_ilog2_64(n)
+    }
+}
+
+macro_rules! unsigned_isqrt {
+    ($unsigned_type:ty, $ilog2:path, $isqrt:ident) => {
+        /// Returns the integer square root of `n`. Usable in `const` contexts.
+        #[inline]
+        pub const fn $isqrt(n: $unsigned_type) -> $unsigned_type {
+            if n < 2 {
+                return n;
+            }
+
+            // The algorithm is based on the one presented in
+            // <https://en.wikipedia.org/wiki/Methods_of_computing_square_roots#Binary_numeral_system_(base_2)>
+            // which cites as source the following C code:
+            // <https://web.archive.org/web/20120306040058/http://medialab.freaknet.org/martin/src/sqrt/sqrt.c>.
+
+            let mut op = n;
+            let mut res = 0;
+            let mut one = 1 << ($ilog2(n) & !1);
+
+            while one != 0 {
+                if op >= res + one {
+                    op -= res + one;
+                    res = (res >> 1) + one;
+                } else {
+                    res >>= 1;
                 }
+                one >>= 2;
+            }
+
+            crate::debug_assert_isqrt(n as u128, res as u128);
 
-                // SAFETY: the result is positive and fits in an integer with half as many bits.
-                // Inform the optimizer about it.
-                unsafe {
-                    intrinsics::assume(0 < res);
-                    intrinsics::assume(res < 1 << (Self::BITS / 2));
+            // SAFETY: the result is positive and fits in an integer with half as many bits.
+            // Inform the optimizer about it.
+            unsafe {
+                crate::assume(0 < res);
+                crate::assume(res < 1 << (<$unsigned_type>::BITS / 2));
+            }
+
+            res
+        }
+
+        impl UnsignedIsqrt for $unsigned_type {
+            const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
+            #[inline]
+            fn isqrt(self) -> Self {
+                $isqrt(self)
+            }
+
+            #[inline]
+            fn ceil_isqrt(self) -> Self {
+                // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+                let sqrt = UnsignedIsqrt::isqrt(self);
+                if sqrt * sqrt == self {
+                    sqrt
+                } else {
+                    sqrt + 1
                 }
+            }
 
-                res
+            #[inline]
+            fn is_perfect_square(self) -> bool {
+                let sqrt = UnsignedIsqrt::isqrt(self);
+                sqrt * sqrt == self
+            }
+            #[inline]
+            fn prev_perfect_square(self) -> Self {
+                let sqrt = UnsignedIsqrt::isqrt(self);
+                sqrt * sqrt
+            }
+            #[inline]
+            fn next_perfect_square(self) -> Self {
+                UnsignedIsqrt::checked_next_perfect_square(self)
+                    .expect("no larger perfect square fits in the type")
+            }
+            #[inline]
+            fn checked_next_perfect_square(self) -> Option<Self> {
+                // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+                let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+                sqrt.checked_mul(sqrt)
+            }
+            #[inline]
+            fn overflowing_next_perfect_square(self) -> (Self, bool) {
+                // As in `checked_next_perfect_square`, `sqrt + 1` can't overflow.
+                let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+                sqrt.overflowing_mul(sqrt)
+            }
+            #[inline]
+            fn isqrt_exact(self) -> Option<Self> {
+                let sqrt = UnsignedIsqrt::isqrt(self);
+                (sqrt * sqrt == self).then_some(sqrt)
+            }
+            #[inline]
+            fn isqrt_with_exactness(self) -> (Self, bool) {
+                let sqrt = UnsignedIsqrt::isqrt(self);
+                (sqrt, sqrt * sqrt == self)
             }
         }
     };
 }
 
-unsigned_isqrt!(u8);
-unsigned_isqrt!(u16);
-unsigned_isqrt!(u32);
-unsigned_isqrt!(u64);
-unsigned_isqrt!(u128);
+unsigned_isqrt!(u8, u8::ilog2, isqrt_u8);
+unsigned_isqrt!(u16, u16::ilog2, isqrt_u16);
+unsigned_isqrt!(u32, ilog2_32, isqrt_u32);
+unsigned_isqrt!(u64, ilog2_64, isqrt_u64);
+unsigned_isqrt!(u128, u128::ilog2, isqrt_u128);
+
+// `isize` and `usize` forward to the fixed-width type of the same size, which keeps its `assume` hints.
+impl SignedIsqrt for isize {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
+    #[inline]
+    fn checked_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_isqrt(self as crate::IsizeDelegate).map(|sqrt| sqrt as Self)
+    }
+
+    #[inline]
+    fn isqrt(self) -> Self {
+        SignedIsqrt::isqrt(self as crate::IsizeDelegate) as Self
+    }
+
+    #[inline]
+    fn try_isqrt(self) -> Result<Self, crate::NegativeInputError> {
+        SignedIsqrt::checked_isqrt(self).ok_or(crate::NegativeInputError)
+    }
+
+    #[inline]
+    fn isqrt_or(self, default: Self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(default)
+    }
+
+    #[inline]
+    fn isqrt_or_zero(self) -> Self {
+        SignedIsqrt::checked_isqrt(self).unwrap_or(0)
+    }
+
+    #[inline]
+    fn checked_ceil_isqrt(self) -> Option<Self> {
+        SignedIsqrt::checked_ceil_isqrt(self as crate::IsizeDelegate).map(|sqrt| sqrt as Self)
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        SignedIsqrt::is_perfect_square(self as crate::IsizeDelegate)
+    }
+}
+
+impl UnsignedIsqrt for usize {
+    const ISQRT_MAX: Self = <Self as crate::IsqrtMax>::ISQRT_MAX;
+
+    #[inline]
+    fn isqrt(self) -> Self {
+        UnsignedIsqrt::isqrt(self as crate::UsizeDelegate) as Self
+    }
+
+    #[inline]
+    fn ceil_isqrt(self) -> Self {
+        UnsignedIsqrt::ceil_isqrt(self as crate::UsizeDelegate) as Self
+    }
+
+    #[inline]
+    fn is_perfect_square(self) -> bool {
+        UnsignedIsqrt::is_perfect_square(self as crate::UsizeDelegate)
+    }
+    #[inline]
+    fn prev_perfect_square(self) -> Self {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        sqrt * sqrt
+    }
+    #[inline]
+    fn next_perfect_square(self) -> Self {
+        UnsignedIsqrt::checked_next_perfect_square(self)
+            .expect("no larger perfect square fits in the type")
+    }
+    #[inline]
+    fn checked_next_perfect_square(self) -> Option<Self> {
+        // `sqrt + 1` can't overflow, as the square root has at most half as many bits.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.checked_mul(sqrt)
+    }
+    #[inline]
+    fn overflowing_next_perfect_square(self) -> (Self, bool) {
+        // As in `checked_next_perfect_square`, `sqrt + 1` can't overflow.
+        let sqrt = UnsignedIsqrt::isqrt(self) + 1;
+        sqrt.overflowing_mul(sqrt)
+    }
+    #[inline]
+    fn isqrt_exact(self) -> Option<Self> {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        (sqrt * sqrt == self).then_some(sqrt)
+    }
+    #[inline]
+    fn isqrt_with_exactness(self) -> (Self, bool) {
+        let sqrt = UnsignedIsqrt::isqrt(self);
+        (sqrt, sqrt * sqrt == self)
+    }
+}
+
+nonzero_unsigned_isqrt!();
+
+wrapper_isqrt!(Wrapping, Saturating);
+
+int_isqrt!();
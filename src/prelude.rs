@@ -0,0 +1,18 @@
+//! Imports the square root traits of the default module, `karatsuba`, with `use isqrt::prelude::*;`.
+//!
+//! The traits are renamed so that they don't collide with those of a module imported alongside the prelude. To use
+//! another algorithm, import that module's traits instead, such as `use isqrt::heron::{SignedIsqrt, UnsignedIsqrt};`.
+//!
+//! The standard library's inherent `isqrt` and `checked_isqrt` methods take precedence over trait methods, so
+//! `n.isqrt()` calls the standard library. Call those two through the traits, as in `DefaultUnsignedIsqrt::isqrt(n)`,
+//! to get this crate's implementation. Methods the standard library doesn't have, such as `ceil_isqrt`, work with
+//! method-call syntax.
+//!
+//! The traits aren't implemented for references, as their methods return `Self`, and a reference to a newly computed
+//! root can't be returned. Method calls on references work anyway, as the integers are `Copy`, so `(&n).ceil_isqrt()`
+//! compiles. Where a method is named as a path, copy the items first, as in
+//! `slice.iter().copied().map(DefaultUnsignedIsqrt::isqrt)`.
+
+pub use crate::karatsuba::IntIsqrt as DefaultIntIsqrt;
+pub use crate::karatsuba::SignedIsqrt as DefaultSignedIsqrt;
+pub use crate::karatsuba::UnsignedIsqrt as DefaultUnsignedIsqrt;
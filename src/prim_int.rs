@@ -0,0 +1,32 @@
+//! Integer square roots for code that's generic over `num_traits::PrimInt`.
+
+use core::mem::size_of;
+use num_traits::PrimInt;
+
+use crate::karatsuba::{isqrt_u128, isqrt_u16, isqrt_u32, isqrt_u64, isqrt_u8};
+
+/// Returns the integer square root of `n`, using the `karatsuba` method for the unsigned type of the same width.
+///
+/// Like the signed `isqrt` methods, this panics if `n` is negative, unless the `no-panic-signed` feature is enabled, in
+/// which case it returns zero. It also panics if `n` doesn't fit in a `u128`, which can only happen with a `PrimInt`
+/// implemented outside of the standard integer types.
+#[inline]
+pub fn isqrt<T: PrimInt>(n: T) -> T {
+    if n < T::zero() {
+        crate::negative_isqrt_argument::<()>();
+        return T::zero();
+    }
+
+    // The size is known at compile time, so only one branch survives.
+    let sqrt = match size_of::<T>() {
+        1 => n.to_u8().map(|n| isqrt_u8(n) as u128),
+        2 => n.to_u16().map(|n| isqrt_u16(n) as u128),
+        4 => n.to_u32().map(|n| isqrt_u32(n) as u128),
+        8 => n.to_u64().map(|n| isqrt_u64(n) as u128),
+        _ => n.to_u128().map(isqrt_u128),
+    }
+    .expect("argument of integer square root must fit in a `u128`");
+
+    // The square root is at most the argument, so it fits.
+    T::from(sqrt).unwrap()
+}
@@ -0,0 +1,55 @@
+//! Integer `n`th roots, computed with integer Newton's method.
+
+/// Integer `n`th roots of the unsigned integer types.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+pub trait UnsignedRoot: Sized + crate::sealed::Sealed {
+    /// Returns the largest integer whose `n`th power is at most `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn nth_root(self, n: u32) -> Self;
+}
+
+macro_rules! unsigned_root {
+    ($($unsigned_type:ty),+) => {
+        $(
+            impl UnsignedRoot for $unsigned_type {
+                #[inline]
+                fn nth_root(self, n: u32) -> Self {
+                    assert!(n != 0, "the zeroth root is undefined");
+
+                    if n == 1 || self < 2 {
+                        return self;
+                    }
+                    // `2^n` doesn't fit, so every root of a nonzero value is one.
+                    if n >= Self::BITS {
+                        return 1;
+                    }
+
+                    // Unlike square and cube roots, a floating-point seed loses too much precision as `n` grows, so
+                    // seed with a power of two above the root instead. `self < 2^(ilog2 + 1) <= 2^(n * (ilog2 / n +
+                    // 1))`, so the seed exceeds the root, and Newton's method then decreases monotonically to it,
+                    // stopping once it would no longer decrease.
+                    //
+                    // Powers of the early, overestimated iterates can overflow. An overflowing power is above `self`,
+                    // so the quotient is zero.
+                    let n_minus_one = (n - 1) as Self;
+                    let mut result: Self = 1 << (self.ilog2() / n + 1);
+                    loop {
+                        let quotient = result.checked_pow(n - 1).map_or(0, |power| self / power);
+                        let next = (n_minus_one * result + quotient) / n as Self;
+                        if next >= result {
+                            return result;
+                        }
+                        result = next;
+                    }
+                }
+            }
+        )+
+    };
+}
+
+unsigned_root!(u8, u16, u32, u64, u128, usize);
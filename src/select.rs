@@ -0,0 +1,115 @@
+//! Integer square roots with the algorithm chosen by a `const` parameter, for switching between algorithms in
+//! benchmarks and comparisons without importing each module's traits.
+//!
+//! The parameter is matched at compile time, so the call is the same as calling the module's `IntIsqrt::isqrt`:
+//!
+//! | `ALGO` | Constant                       | Module                         | Needs the feature         |
+//! |--------|--------------------------------|--------------------------------|---------------------------|
+//! | 0      | `ORIGINAL`                     | `original`                     | `algo-original`           |
+//! | 1      | `FLOATING_POINT`               | `floating_point`               | `algo-floating`           |
+//! | 2      | `KARATSUBA`                    | `karatsuba`                    | `algo-karatsuba`          |
+//! | 3      | `KARATSUBA_2`                  | `karatsuba_2`                  | `algo-karatsuba-2`        |
+//! | 4      | `FLOATING_POINT_AND_KARATSUBA` | `floating_point_and_karatsuba` | `algo-floating-karatsuba` |
+//! | 5      | `HERON`                        | `heron`                        | none                      |
+//! | 6      | `NEWTON`                       | `newton`                       | none                      |
+//! | 7      | `TABLE`                        | `table`                        | none                      |
+//! | 8      | `LIBGMP`                       | `libgmp`                       | `libgmp`                  |
+//!
+//! The floating-point modules are also left out by `no-float`. Choosing an algorithm that isn't compiled in, or a value
+//! that isn't in the table, fails to build, though `cargo check` doesn't report it.
+//!
+//! ```
+//! use isqrt::select::{isqrt_with, IsqrtWith, HERON, NEWTON};
+//!
+//! assert_eq!(99_u32.isqrt_with::<HERON>(), 9);
+//! assert_eq!(isqrt_with::<NEWTON, _>(10_000_i64), 100);
+//! ```
+
+pub const ORIGINAL: u8 = 0;
+pub const FLOATING_POINT: u8 = 1;
+pub const KARATSUBA: u8 = 2;
+pub const KARATSUBA_2: u8 = 3;
+pub const FLOATING_POINT_AND_KARATSUBA: u8 = 4;
+pub const HERON: u8 = 5;
+pub const NEWTON: u8 = 6;
+pub const TABLE: u8 = 7;
+pub const LIBGMP: u8 = 8;
+
+/// Whether the module that `algo` stands for is compiled in.
+// With every module compiled in, each arm but the last is `true`, and clippy would rather see a `matches!`.
+#[allow(clippy::match_like_matches_macro)]
+const fn is_available(algo: u8) -> bool {
+    match algo {
+        ORIGINAL => cfg!(feature = "algo-original"),
+        FLOATING_POINT => cfg!(all(feature = "algo-floating", not(feature = "no-float"))),
+        KARATSUBA => cfg!(feature = "algo-karatsuba"),
+        KARATSUBA_2 => cfg!(feature = "algo-karatsuba-2"),
+        FLOATING_POINT_AND_KARATSUBA => cfg!(all(
+            feature = "algo-floating-karatsuba",
+            not(feature = "no-float")
+        )),
+        HERON | NEWTON | TABLE => true,
+        LIBGMP => cfg!(feature = "libgmp"),
+        _ => false,
+    }
+}
+
+/// Integer square roots of every integer type, signed or unsigned, with the algorithm chosen by `ALGO`.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+pub trait IsqrtWith: Sized + crate::sealed::Sealed {
+    /// Returns the largest integer whose square is at most `self`, taken with the algorithm that `ALGO` stands for.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative, as the signed `isqrt` does.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn isqrt_with<const ALGO: u8>(self) -> Self;
+}
+
+macro_rules! isqrt_with {
+    ($($type:ty),+) => {
+        $(
+            impl IsqrtWith for $type {
+                #[inline]
+                #[track_caller]
+                fn isqrt_with<const ALGO: u8>(self) -> Self {
+                    const { assert!(is_available(ALGO), "that algorithm isn't compiled in") };
+
+                    match ALGO {
+                        #[cfg(feature = "algo-original")]
+                        ORIGINAL => crate::original::IntIsqrt::isqrt(self),
+                        #[cfg(all(feature = "algo-floating", not(feature = "no-float")))]
+                        FLOATING_POINT => crate::floating_point::IntIsqrt::isqrt(self),
+                        #[cfg(feature = "algo-karatsuba")]
+                        KARATSUBA => crate::karatsuba::IntIsqrt::isqrt(self),
+                        #[cfg(feature = "algo-karatsuba-2")]
+                        KARATSUBA_2 => crate::karatsuba_2::IntIsqrt::isqrt(self),
+                        #[cfg(all(feature = "algo-floating-karatsuba", not(feature = "no-float")))]
+                        FLOATING_POINT_AND_KARATSUBA => crate::floating_point_and_karatsuba::IntIsqrt::isqrt(self),
+                        HERON => crate::heron::IntIsqrt::isqrt(self),
+                        NEWTON => crate::newton::IntIsqrt::isqrt(self),
+                        TABLE => crate::table::IntIsqrt::isqrt(self),
+                        #[cfg(feature = "libgmp")]
+                        LIBGMP => crate::libgmp::IntIsqrt::isqrt(self),
+                        // The assertion above rules out every other value.
+                        _ => unreachable!(),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+isqrt_with!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Returns the largest integer whose square is at most `n`, taken with the algorithm that `ALGO` stands for.
+///
+/// # Panics
+///
+/// Panics if `n` is negative.
+#[inline]
+#[track_caller]
+pub fn isqrt_with<const ALGO: u8, T: IsqrtWith>(n: T) -> T {
+    n.isqrt_with::<ALGO>()
+}
@@ -0,0 +1,23 @@
+/// Checks `isqrt` for `bits`-bit integers against a few hundred perfect squares and their neighbors, returning whether
+/// every result was correct.
+///
+/// The square roots checked are the first and last 64 possible square roots along with the powers of two and their
+/// neighbors. For each square root `s`, `s^2 - 1`, `s^2`, and `s^2 + 2 * s` are checked, which are the inputs around
+/// `s^2` where a miscomputed floating-point square root or a miscompiled correction step would show up first.
+pub(crate) fn sweep(bits: u32, isqrt: impl Fn(u128) -> u128) -> bool {
+    let max_root: u128 = (1 << (bits / 2)) - 1;
+
+    (0..=63.min(max_root))
+        .chain(max_root.saturating_sub(63)..=max_root)
+        .chain((0..bits / 2).flat_map(|exponent| {
+            let power = 1 << exponent;
+            [power - 1, power, power + 1]
+        }))
+        .filter(|&s| s <= max_root)
+        .all(|s| {
+            let square = s * s;
+            (square == 0 || isqrt(square - 1) == s - 1)
+                && isqrt(square) == s
+                && isqrt(square + 2 * s) == s
+        })
+}
@@ -0,0 +1,25 @@
+use crate::karatsuba::UnsignedIsqrt;
+
+/// Returns the distance from `n` to the nearest perfect square.
+pub fn square_distance(n: u64) -> u64 {
+    let s = UnsignedIsqrt::isqrt(n);
+    let below = n - s * s;
+    // Computed from the distance below rather than from `(s + 1)^2`, which can overflow.
+    let above = (s << 1) + 1 - below;
+    below.min(above)
+}
+
+/// Buckets each element of `xs` by the bit length of its `square_distance`.
+///
+/// Bucket 0 counts perfect squares, and bucket `i` for `i >= 1` counts elements whose distance is in
+/// `2^(i - 1)..2^i`. Distances are always below `2^32`, so only the first 33 buckets can be nonzero.
+///
+/// Inputs close to perfect squares are the ones that exercise the correction steps of the various methods, so this
+/// shows how an input distribution will behave in benchmarks.
+pub fn square_distance_histogram(xs: &[u64]) -> [u64; 64] {
+    let mut histogram = [0; 64];
+    for &x in xs {
+        histogram[(u64::BITS - square_distance(x).leading_zeros()) as usize] += 1;
+    }
+    histogram
+}
@@ -0,0 +1,63 @@
+// SWAR (SIMD within a register) square roots, which compute several square roots at once using ordinary integer
+// registers.
+
+use crate::tables::ISQRT_8_BIT;
+
+/// Lane-wise `a - b` for two 32-bit lanes, along with a mask that's all ones in each lane where `a >= b`.
+#[inline(always)]
+const fn sub_32x2(a: u64, b: u64) -> (u64, u64) {
+    const HIGH_BITS: u64 = 0x8000_0000_8000_0000;
+
+    // Subtract the lower 31 bits of each lane without borrowing across lanes, then fix up the highest bit.
+    let difference = ((a | HIGH_BITS) - (b & !HIGH_BITS)) ^ ((a ^ !b) & HIGH_BITS);
+    // A lane borrows out of its highest bit when `a`'s bit is zero and `b`'s is one, or when they match and the lower
+    // bits borrowed into it.
+    let borrows = ((!a & b) | (!(a ^ b) & difference)) & HIGH_BITS;
+    let no_borrow_mask = ((!borrows & HIGH_BITS) >> 31) * u32::MAX as u64;
+
+    (difference, no_borrow_mask)
+}
+
+/// Returns the integer square roots of the upper and lower 32 bits of `packed` in the upper and lower 32 bits of the
+/// result.
+pub const fn isqrt_two_u32(packed: u64) -> u64 {
+    // This is the same algorithm as the `original` module, run on both lanes at once. It starts from the highest even
+    // bit rather than from the input's highest set bit, as the lanes can have different bit lengths. The sums of `res`
+    // and `one` stay below 2^31, so they never carry into the next lane.
+    const LOWER_31_BITS: u64 = 0x7FFF_FFFF_7FFF_FFFF;
+
+    let mut op = packed;
+    let mut res = 0;
+    let mut one: u64 = (1 << 62) | (1 << 30);
+
+    let mut i = 0;
+    while i < 16 {
+        let (difference, mask) = sub_32x2(op, res + one);
+        op = (difference & mask) | (op & !mask);
+        res = ((res >> 1) & LOWER_31_BITS) + (one & mask);
+        one >>= 2;
+        i += 1;
+    }
+
+    res
+}
+
+/// Returns the integer square roots of the four bytes of `packed`, each in the byte it came from.
+///
+/// The lanes are numeric rather than in memory order: the root of bits `8 * i` through `8 * i + 7` of `packed` ends up
+/// in the same bits of the result, whatever the target's endianness. Each root is looked up in `tables::ISQRT_8_BIT`.
+///
+/// ```
+/// use isqrt::swar::isqrt_u8x4;
+///
+/// assert_eq!(isqrt_u8x4(0xFF_64_10_00), 0x0F_0A_04_00);
+/// ```
+pub const fn isqrt_u8x4(packed: u32) -> u32 {
+    let [a, b, c, d] = packed.to_le_bytes();
+    u32::from_le_bytes([
+        ISQRT_8_BIT[a as usize],
+        ISQRT_8_BIT[b as usize],
+        ISQRT_8_BIT[c as usize],
+        ISQRT_8_BIT[d as usize],
+    ])
+}
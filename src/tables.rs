@@ -0,0 +1,73 @@
+//! The 8-bit lookup tables that the `heron` and `floating_point_and_karatsuba` modules start from, for building other
+//! square root kernels on top of.
+//!
+//! Both are indexed by the 8-bit argument.
+
+/// The integer square root of each `u8`: `ISQRT_8_BIT[n]` is `n.isqrt()`.
+///
+/// ```
+/// use isqrt::tables::ISQRT_8_BIT;
+///
+/// assert_eq!(ISQRT_8_BIT[0], 0);
+/// assert_eq!(ISQRT_8_BIT[99], 9);
+/// assert_eq!(ISQRT_8_BIT[255], 15);
+/// ```
+pub const ISQRT_8_BIT: [u8; 256] = {
+    let mut result = [0; 256];
+
+    let mut sqrt = 0;
+    let mut i = 0;
+    'outer: loop {
+        let mut remaining = 2 * sqrt + 1;
+        while remaining > 0 {
+            result[i] = sqrt;
+            i += 1;
+            if i >= result.len() {
+                break 'outer;
+            }
+
+            remaining -= 1;
+        }
+        sqrt += 1;
+    }
+
+    result
+};
+
+/// The integer square root and remainder of each `u8`, packed into a byte.
+///
+/// The top three bits of each entry are the low three bits of the square root, and the low five bits are the remainder,
+/// `n - n.isqrt().pow(2)`, which is at most 30. The square root of a `u8` has four bits, and the fourth is set exactly
+/// when `n >= 64`, so it's left out of the table:
+///
+/// ```
+/// use isqrt::tables::ISQRT_AND_REMAINDER_8_BIT;
+///
+/// fn isqrt_rem(n: u8) -> (u8, u8) {
+///     let entry = ISQRT_AND_REMAINDER_8_BIT[n as usize];
+///     (((n >= 64) as u8) << 3 | entry >> 5, entry & 0b11111)
+/// }
+///
+/// assert_eq!(isqrt_rem(99), (9, 18));
+/// assert_eq!(isqrt_rem(255), (15, 30));
+/// ```
+pub const ISQRT_AND_REMAINDER_8_BIT: [u8; 256] = {
+    let mut result = [0; 256];
+
+    let mut sqrt = 0;
+    let mut i = 0;
+    'outer: loop {
+        let mut remaining = 2 * sqrt + 1;
+        while remaining > 0 {
+            result[i] = (sqrt << 5) | (2 * sqrt + 1 - remaining);
+            i += 1;
+            if i >= result.len() {
+                break 'outer;
+            }
+            remaining -= 1;
+        }
+        sqrt += 1;
+    }
+
+    result
+};
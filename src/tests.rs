@@ -10,27 +10,105 @@ macro_rules! tests {
                         // `$SignedT::MIN` will be negative, so we don't want to handle `n` as if it's nonnegative.
                         if n >= 0 {
                             assert_eq!(
-                                Some(n.isqrt()),
-                                n.checked_isqrt(),
+                                Some(SignedIsqrt::isqrt(n)),
+                                SignedIsqrt::checked_isqrt(n),
                                 "`{n}.checked_isqrt()` should match `Some({n}.isqrt())`.",
                             );
+                            assert_eq!(
+                                SignedIsqrt::try_isqrt(n),
+                                Ok(SignedIsqrt::isqrt(n)),
+                                "`{n}.try_isqrt()` should match `Ok({n}.isqrt())`.",
+                            );
+                            assert_eq!(
+                                SignedIsqrt::isqrt_or(n, -1),
+                                SignedIsqrt::isqrt(n),
+                                "`{n}.isqrt_or(-1)` should match `{n}.isqrt()`.",
+                            );
+                            assert_eq!(
+                                SignedIsqrt::isqrt_or_zero(n),
+                                SignedIsqrt::isqrt(n),
+                                "`{n}.isqrt_or_zero()` should match `{n}.isqrt()`.",
+                            );
+                            assert_eq!(
+                                SignedIsqrt::saturating_isqrt(n),
+                                SignedIsqrt::isqrt(n),
+                                "`{n}.saturating_isqrt()` should match `{n}.isqrt()`.",
+                            );
                         }
 
                         let negative_n = n.wrapping_neg();
                         // `n` could be zero, so we don't want to handle `negative_n` as if it's negative.
                         if negative_n < 0 {
                             assert_eq!(
-                                negative_n.checked_isqrt(),
+                                SignedIsqrt::checked_isqrt(negative_n),
                                 None,
                                 "`({negative_n}).checked_isqrt()` should be `None`, as {negative_n} is negative.",
                             );
+                            assert_eq!(
+                                SignedIsqrt::try_isqrt(negative_n),
+                                Err(crate::NegativeInputError),
+                                "`({negative_n}).try_isqrt()` should be an error, as {negative_n} is negative.",
+                            );
+                            assert_eq!(
+                                SignedIsqrt::isqrt_or(negative_n, -1),
+                                -1,
+                                "`({negative_n}).isqrt_or(-1)` should be -1, as {negative_n} is negative.",
+                            );
+                            assert_eq!(
+                                SignedIsqrt::isqrt_or_zero(negative_n),
+                                0,
+                                "`({negative_n}).isqrt_or_zero()` should be 0, as {negative_n} is negative.",
+                            );
+                            assert_eq!(
+                                SignedIsqrt::saturating_isqrt(negative_n),
+                                0,
+                                "`({negative_n}).saturating_isqrt()` should be 0, as {negative_n} is negative.",
+                            );
+                            assert_eq!(
+                                SignedIsqrt::checked_ceil_isqrt(negative_n),
+                                None,
+                                "`({negative_n}).checked_ceil_isqrt()` should be `None`, as {negative_n} is negative.",
+                            );
+                            assert!(
+                                !SignedIsqrt::is_perfect_square(negative_n),
+                                "`({negative_n}).is_perfect_square()` should be `false`, as {negative_n} is negative.",
+                            );
 
-                            std::panic::catch_unwind(|| (-n).isqrt()).expect_err(
+                            #[cfg(not(feature = "no-panic-signed"))]
+                            std::panic::catch_unwind(|| SignedIsqrt::isqrt(negative_n)).expect_err(
                                 &format!("`({negative_n}).isqrt()` should have panicked, as {negative_n} is negative.")
                             );
+
+                            #[cfg(feature = "no-panic-signed")]
+                            assert_eq!(
+                                SignedIsqrt::isqrt(negative_n),
+                                0,
+                                "`({negative_n}).isqrt()` should be 0, as {negative_n} is negative and `no-panic-signed` is enabled.",
+                            );
                         }
                     }
 
+                    #[test]
+                    fn test_isqrt_max() {
+                        assert_eq!(
+                            <$SignedT as SignedIsqrt>::ISQRT_MAX,
+                            SignedIsqrt::isqrt($SignedT::MAX),
+                            "`ISQRT_MAX` should be the square root of `MAX`."
+                        );
+                    }
+
+                    // `MIN` has no positive counterpart, so a cast or negation before the sign check would overflow or
+                    // find a square root instead of panicking with the usual message.
+                    #[test]
+                    #[cfg_attr(
+                        not(feature = "no-panic-signed"),
+                        should_panic = "argument of integer square root must be non-negative"
+                    )]
+                    fn test_isqrt_min() {
+                        assert_eq!(SignedIsqrt::checked_isqrt($SignedT::MIN), None);
+                        assert_eq!(SignedIsqrt::isqrt($SignedT::MIN), 0);
+                    }
+
                     #[test]
                     fn test_isqrt() {
                         // Check the minimum value.
@@ -44,7 +122,7 @@ macro_rules! tests {
                             .chain((0..$SignedT::BITS - 1).map(|exponent| 1 << exponent))
                         {
                             isqrt_consistency_check(n);
-                            let sqrt_n = n.isqrt();
+                            let sqrt_n = SignedIsqrt::isqrt(n);
 
                             assert!(
                                 sqrt_n * sqrt_n <= n,
@@ -55,6 +133,37 @@ macro_rules! tests {
                                 (sqrt_n + 1).checked_mul(sqrt_n + 1).map(|higher_than_n| n < higher_than_n).unwrap_or(true),
                                 "The integer square root of {n} should be higher than {sqrt_n} (the current return value of `{n}.isqrt()`)."
                             );
+
+                            // The square root never exceeds its argument and only equals it for 0 and 1.
+                            assert!(
+                                sqrt_n <= n,
+                                "The integer square root of {n} should not exceed {n}, but `{n}.isqrt()` returned {sqrt_n}."
+                            );
+                            assert_eq!(
+                                sqrt_n == n,
+                                n <= 1,
+                                "`{n}.isqrt()` should equal {n} only when {n} is 0 or 1, but it returned {sqrt_n}."
+                            );
+
+                            let ceil_sqrt_n = SignedIsqrt::checked_ceil_isqrt(n).unwrap();
+                            if sqrt_n * sqrt_n == n {
+                                assert_eq!(ceil_sqrt_n, sqrt_n, "`{n}.checked_ceil_isqrt()` should be `Some({sqrt_n})`.");
+                            } else {
+                                assert!(
+                                    (ceil_sqrt_n - 1) * (ceil_sqrt_n - 1) < n,
+                                    "The ceiling integer square root of {n} should be lower than {ceil_sqrt_n} (the current return value of `{n}.checked_ceil_isqrt()`)."
+                                );
+                                assert!(
+                                    ceil_sqrt_n.checked_mul(ceil_sqrt_n).map(|at_least_n| n <= at_least_n).unwrap_or(true),
+                                    "The ceiling integer square root of {n} should be higher than {ceil_sqrt_n} (the current return value of `{n}.checked_ceil_isqrt()`)."
+                                );
+                            }
+
+                            assert_eq!(
+                                SignedIsqrt::is_perfect_square(n),
+                                sqrt_n * sqrt_n == n,
+                                "`{n}.is_perfect_square()` should match whether {n} is the square of {sqrt_n}."
+                            );
                         }
                     }
 
@@ -89,7 +198,7 @@ macro_rules! tests {
                         let mut n: $SignedT = 0;
                         for sqrt_n in 0..1024.min((1_u128 << (($SignedT::BITS - 1)/2)) - 1) as $SignedT {
                             assert_eq!(
-                                n.isqrt(),
+                                SignedIsqrt::isqrt(n),
                                 sqrt_n,
                                 "`{sqrt_n}.pow(2).isqrt()` should be {sqrt_n}."
                             );
@@ -97,7 +206,7 @@ macro_rules! tests {
 
                             n += sqrt_n;
                             assert_eq!(
-                                n.isqrt(),
+                                SignedIsqrt::isqrt(n),
                                 sqrt_n,
                                 "`isqrt` of a number halfway between `{sqrt_n}.pow(2)` and `{}.pow(2)` should be {sqrt_n}.",
                                 sqrt_n + 1
@@ -106,7 +215,7 @@ macro_rules! tests {
 
                             n += sqrt_n;
                             assert_eq!(
-                                n.isqrt(),
+                                SignedIsqrt::isqrt(n),
                                 sqrt_n,
                                 "`({}.pow(2) - 1).isqrt()` should be {sqrt_n}.",
                                 sqrt_n + 1
@@ -117,13 +226,13 @@ macro_rules! tests {
                         }
 
                         // Similarly, check the last 1,024 perfect squares.
-                        let maximum_sqrt = $SignedT::MAX.isqrt(); // Maximum `isqrt` return value verified above.
+                        let maximum_sqrt = SignedIsqrt::isqrt($SignedT::MAX); // Maximum `isqrt` return value verified above.
                         let mut n = maximum_sqrt * maximum_sqrt;
-                        assert_eq!(n.isqrt(), maximum_sqrt);
+                        assert_eq!(SignedIsqrt::isqrt(n), maximum_sqrt);
                         for sqrt_n in (maximum_sqrt - 1024.min((1_u128 << (($SignedT::BITS - 1)/2)) - 1) as $SignedT..maximum_sqrt).rev() {
                             n -= 1;
                             assert_eq!(
-                                n.isqrt(),
+                                SignedIsqrt::isqrt(n),
                                 sqrt_n,
                                 "`({}.pow(2) - 1).isqrt()` should be {sqrt_n}.",
                                 sqrt_n + 1
@@ -132,7 +241,7 @@ macro_rules! tests {
 
                             n -= sqrt_n;
                             assert_eq!(
-                                n.isqrt(),
+                                SignedIsqrt::isqrt(n),
                                 sqrt_n,
                                 "`isqrt` of a number halfway between `{sqrt_n}.pow(2)` and `{}.pow(2)` should be {sqrt_n}.",
                                 sqrt_n + 1
@@ -141,7 +250,7 @@ macro_rules! tests {
 
                             n -= sqrt_n;
                             assert_eq!(
-                                n.isqrt(),
+                                SignedIsqrt::isqrt(n),
                                 sqrt_n,
                                 "`{sqrt_n}.pow(2).isqrt()` should be {sqrt_n}."
                             );
@@ -154,6 +263,15 @@ macro_rules! tests {
                     #[allow(unused)]
                     use crate::$module::UnsignedIsqrt;
 
+                    #[test]
+                    fn test_isqrt_max() {
+                        assert_eq!(
+                            <$UnsignedT as UnsignedIsqrt>::ISQRT_MAX,
+                            UnsignedIsqrt::isqrt($UnsignedT::MAX),
+                            "`ISQRT_MAX` should be the square root of `MAX`."
+                        );
+                    }
+
                     #[test]
                     fn test_isqrt() {
                         // Check the square roots of the first and last 128 nonnegative values, of the powers of two minus one,
@@ -163,7 +281,7 @@ macro_rules! tests {
                             .chain((0..$UnsignedT::BITS).map(|exponent| (1 << exponent) - 1))
                             .chain((0..$UnsignedT::BITS).map(|exponent| 1 << exponent))
                         {
-                            let sqrt_n = n.isqrt();
+                            let sqrt_n = UnsignedIsqrt::isqrt(n);
 
                             assert!(
                                 sqrt_n * sqrt_n <= n,
@@ -174,6 +292,117 @@ macro_rules! tests {
                                 (sqrt_n + 1).checked_mul(sqrt_n + 1).map(|higher_than_n| n < higher_than_n).unwrap_or(true),
                                 "The integer square root of {n} should be higher than {sqrt_n} (the current return value of `{n}.isqrt()`)."
                             );
+
+                            // The square root never exceeds its argument and only equals it for 0 and 1.
+                            assert!(
+                                sqrt_n <= n,
+                                "The integer square root of {n} should not exceed {n}, but `{n}.isqrt()` returned {sqrt_n}."
+                            );
+                            assert_eq!(
+                                sqrt_n == n,
+                                n <= 1,
+                                "`{n}.isqrt()` should equal {n} only when {n} is 0 or 1, but it returned {sqrt_n}."
+                            );
+
+                            assert_eq!(UnsignedIsqrt::isqrt_floor(n), sqrt_n, "`{n}.isqrt_floor()` should be {sqrt_n}.");
+
+                            let ceil_sqrt_n = UnsignedIsqrt::ceil_isqrt(n);
+                            if sqrt_n * sqrt_n == n {
+                                assert_eq!(ceil_sqrt_n, sqrt_n, "`{n}.ceil_isqrt()` should be {sqrt_n}.");
+                            } else {
+                                assert!(
+                                    (ceil_sqrt_n - 1) * (ceil_sqrt_n - 1) < n,
+                                    "The ceiling integer square root of {n} should be lower than {ceil_sqrt_n} (the current return value of `{n}.ceil_isqrt()`)."
+                                );
+                                assert!(
+                                    ceil_sqrt_n.checked_mul(ceil_sqrt_n).map(|at_least_n| n <= at_least_n).unwrap_or(true),
+                                    "The ceiling integer square root of {n} should be higher than {ceil_sqrt_n} (the current return value of `{n}.ceil_isqrt()`)."
+                                );
+                            }
+
+                            assert_eq!(
+                                UnsignedIsqrt::is_perfect_square(n),
+                                sqrt_n * sqrt_n == n,
+                                "`{n}.is_perfect_square()` should match whether {n} is the square of {sqrt_n}."
+                            );
+
+                            assert_eq!(
+                                UnsignedIsqrt::prev_perfect_square(n),
+                                sqrt_n * sqrt_n,
+                                "`{n}.prev_perfect_square()` should be the square of {sqrt_n}."
+                            );
+                            assert_eq!(
+                                UnsignedIsqrt::isqrt_exact(n),
+                                (sqrt_n * sqrt_n == n).then_some(sqrt_n),
+                                "`{n}.isqrt_exact()` should be `Some({sqrt_n})` exactly when {n} is the square of {sqrt_n}."
+                            );
+                            assert_eq!(
+                                UnsignedIsqrt::isqrt_with_exactness(n),
+                                (sqrt_n, sqrt_n * sqrt_n == n),
+                                "`{n}.isqrt_with_exactness()` should be {sqrt_n} and whether {n} is its square."
+                            );
+                            assert_eq!(
+                                UnsignedIsqrt::checked_next_perfect_square(n),
+                                (sqrt_n + 1).checked_mul(sqrt_n + 1),
+                                "`{n}.checked_next_perfect_square()` should be the square of {sqrt_n} + 1, if it fits."
+                            );
+                            assert_eq!(
+                                UnsignedIsqrt::overflowing_next_perfect_square(n),
+                                (sqrt_n + 1).overflowing_mul(sqrt_n + 1),
+                                "`{n}.overflowing_next_perfect_square()` should be the wrapped square of {sqrt_n} + 1."
+                            );
+                        }
+                    }
+
+                    #[test]
+                    fn test_isqrt_exact() {
+                        assert_eq!(UnsignedIsqrt::isqrt_exact(16 as $UnsignedT), Some(4));
+                        assert_eq!(UnsignedIsqrt::isqrt_exact(17 as $UnsignedT), None);
+
+                        let max_sqrt: $UnsignedT = (1 << ($UnsignedT::BITS / 2)) - 1;
+                        assert_eq!(UnsignedIsqrt::isqrt_exact(max_sqrt * max_sqrt), Some(max_sqrt));
+                        assert_eq!(UnsignedIsqrt::isqrt_exact($UnsignedT::MAX), None);
+                    }
+
+                    #[test]
+                    fn test_isqrt_with_exactness() {
+                        assert_eq!(UnsignedIsqrt::isqrt_with_exactness(0 as $UnsignedT), (0, true));
+                        assert_eq!(UnsignedIsqrt::isqrt_with_exactness(1 as $UnsignedT), (1, true));
+                        for sqrt in [2, 3, 15, (1 << ($UnsignedT::BITS / 2)) - 1] {
+                            let square: $UnsignedT = sqrt * sqrt;
+                            assert_eq!(UnsignedIsqrt::isqrt_with_exactness(square), (sqrt, true));
+                            assert_eq!(UnsignedIsqrt::isqrt_with_exactness(square - 1), (sqrt - 1, false));
+                        }
+                        assert_eq!(
+                            UnsignedIsqrt::isqrt_with_exactness($UnsignedT::MAX),
+                            ((1 << ($UnsignedT::BITS / 2)) - 1, false)
+                        );
+                    }
+
+                    #[test]
+                    fn test_next_perfect_square_at_max() {
+                        // Above the largest perfect square that fits, there's no next perfect square.
+                        let max_sqrt: $UnsignedT = (1 << ($UnsignedT::BITS / 2)) - 1;
+                        let max_square = max_sqrt * max_sqrt;
+
+                        assert_eq!(UnsignedIsqrt::next_perfect_square(max_square - 1), max_square);
+                        assert_eq!(UnsignedIsqrt::checked_next_perfect_square(max_square - 1), Some(max_square));
+                        assert_eq!(UnsignedIsqrt::overflowing_next_perfect_square(max_square - 1), (max_square, false));
+                        for n in [max_square, max_square + 1, $UnsignedT::MAX] {
+                            assert_eq!(
+                                UnsignedIsqrt::overflowing_next_perfect_square(n),
+                                (0, true),
+                                "`{n}.overflowing_next_perfect_square()` should wrap around to zero."
+                            );
+                            assert_eq!(
+                                UnsignedIsqrt::checked_next_perfect_square(n),
+                                None,
+                                "`{n}.checked_next_perfect_square()` should be `None`, as no larger perfect square fits."
+                            );
+                            std::panic::catch_unwind(|| UnsignedIsqrt::next_perfect_square(n)).expect_err(
+                                &format!("`{n}.next_perfect_square()` should have panicked, as no larger perfect square fits.")
+                            );
+                            assert_eq!(UnsignedIsqrt::prev_perfect_square(n), max_square);
                         }
                     }
 
@@ -208,14 +437,14 @@ macro_rules! tests {
                         let mut n: $UnsignedT = 0;
                         for sqrt_n in 0..1024.min((1_u128 << ($UnsignedT::BITS/2)) - 1) as $UnsignedT {
                             assert_eq!(
-                                n.isqrt(),
+                                UnsignedIsqrt::isqrt(n),
                                 sqrt_n,
                                 "`{sqrt_n}.pow(2).isqrt()` should be {sqrt_n}."
                             );
 
                             n += sqrt_n;
                             assert_eq!(
-                                n.isqrt(),
+                                UnsignedIsqrt::isqrt(n),
                                 sqrt_n,
                                 "`isqrt` of a number halfway between `{sqrt_n}.pow(2)` and `{}.pow(2)` should be {sqrt_n}.",
                                 sqrt_n + 1
@@ -223,7 +452,7 @@ macro_rules! tests {
 
                             n += sqrt_n;
                             assert_eq!(
-                                n.isqrt(),
+                                UnsignedIsqrt::isqrt(n),
                                 sqrt_n,
                                 "`({}.pow(2) - 1).isqrt()` should be {sqrt_n}.",
                                 sqrt_n + 1
@@ -233,13 +462,13 @@ macro_rules! tests {
                         }
 
                         // Similarly, check the last 1,024 perfect squares.
-                        let maximum_sqrt = $UnsignedT::MAX.isqrt(); // Maximum `isqrt` return value verified above.
+                        let maximum_sqrt = UnsignedIsqrt::isqrt($UnsignedT::MAX); // Maximum `isqrt` return value verified above.
                         let mut n = maximum_sqrt * maximum_sqrt;
-                        assert_eq!(n.isqrt(), maximum_sqrt);
+                        assert_eq!(UnsignedIsqrt::isqrt(n), maximum_sqrt);
                         for sqrt_n in (maximum_sqrt - 1024.min((1_u128 << ($UnsignedT::BITS/2)) - 1) as $UnsignedT..maximum_sqrt).rev() {
                             n -= 1;
                             assert_eq!(
-                                n.isqrt(),
+                                UnsignedIsqrt::isqrt(n),
                                 sqrt_n,
                                 "`({}.pow(2) - 1).isqrt()` should be {sqrt_n}.",
                                 sqrt_n + 1
@@ -247,7 +476,7 @@ macro_rules! tests {
 
                             n -= sqrt_n;
                             assert_eq!(
-                                n.isqrt(),
+                                UnsignedIsqrt::isqrt(n),
                                 sqrt_n,
                                 "`isqrt` of a number halfway between `{sqrt_n}.pow(2)` and `{}.pow(2)` should be {sqrt_n}.",
                                 sqrt_n + 1
@@ -255,7 +484,7 @@ macro_rules! tests {
 
                             n -= sqrt_n;
                             assert_eq!(
-                                n.isqrt(),
+                                UnsignedIsqrt::isqrt(n),
                                 sqrt_n,
                                 "`{sqrt_n}.pow(2).isqrt()` should be {sqrt_n}."
                             );
@@ -267,37 +496,3079 @@ macro_rules! tests {
     };
 }
 
-tests!(floating_point; i8 u8, i16 u16, i32 u32, i64 u64, i128 u128);
-tests!(floating_point_and_karatsuba; i8 u8, i16 u16, i32 u32, i64 u64, i128 u128);
-tests!(karatsuba; i8 u8, i16 u16, i32 u32, i64 u64, i128 u128);
-tests!(karatsuba_2; i8 u8, i16 u16, i32 u32, i64 u64, i128 u128);
-tests!(original; i8 u8, i16 u16, i32 u32, i64 u64, i128 u128);
-//tests!(table; i8 u8, i16 u16, i32 u32, i64 u64, i128 u128);
-//tests!(libgmp; i8 u8, i16 u16, i32 u32, i64 u64, i128 u128);
+#[cfg(all(feature = "algo-floating", not(feature = "no-float")))]
+tests!(floating_point; i8 u8, i16 u16, i32 u32, i64 u64, i128 u128, isize usize);
+#[cfg(all(feature = "algo-floating-karatsuba", not(feature = "no-float")))]
+tests!(floating_point_and_karatsuba; i8 u8, i16 u16, i32 u32, i64 u64, i128 u128, isize usize);
+tests!(heron; i8 u8, i16 u16, i32 u32, i64 u64, i128 u128, isize usize);
+#[cfg(feature = "algo-karatsuba")]
+tests!(karatsuba; i8 u8, i16 u16, i32 u32, i64 u64, i128 u128, isize usize);
+#[cfg(feature = "algo-karatsuba-2")]
+tests!(karatsuba_2; i8 u8, i16 u16, i32 u32, i64 u64, i128 u128, isize usize);
+tests!(newton; i8 u8, i16 u16, i32 u32, i64 u64, i128 u128, isize usize);
+#[cfg(feature = "algo-original")]
+tests!(original; i8 u8, i16 u16, i32 u32, i64 u64, i128 u128, isize usize);
+tests!(table; i8 u8, i16 u16, i32 u32, i64 u64, i128 u128, isize usize);
+#[cfg(feature = "libgmp")]
+tests!(libgmp; i8 u8, i16 u16, i32 u32, i64 u64, i128 u128, isize usize);
 
-fn floating_u64_bug_cases() {
-    use crate::floating_point::UnsignedIsqrt;
-    assert_eq!(
-        4_503_599_761_588_224_u64.isqrt(),
-        67_108_864,
-        "`4_503_599_761_588_224.isqrt()` should be `67_108_864`."
+mod saturating_isqrt {
+    use crate::heron::SignedIsqrt;
+
+    #[test]
+    fn clamps_negatives_to_zero() {
+        assert_eq!(SignedIsqrt::saturating_isqrt(-5_i32), 0);
+        assert_eq!(SignedIsqrt::saturating_isqrt(26_i32), 5);
+        assert_eq!(SignedIsqrt::saturating_isqrt(i64::MIN), 0);
+    }
+}
+
+#[cfg(feature = "algo-original")]
+mod de_bruijn_ilog2 {
+    use crate::original::{de_bruijn_ilog2_32, de_bruijn_ilog2_64};
+    use rand::Rng;
+
+    macro_rules! de_bruijn_ilog2_tests {
+        ($($UnsignedT:ident $de_bruijn_ilog2:ident),+) => {
+            $(
+                #[test]
+                fn $UnsignedT() {
+                    let mut rng = rand::thread_rng();
+                    for n in (0..$UnsignedT::BITS)
+                        .flat_map(|exponent| [1 << exponent, ((1 << exponent) - 1).max(1), (1 << exponent) + 1])
+                        .chain([$UnsignedT::MAX])
+                        .chain((0..10_000).map(|_| rng.gen_range(1..=$UnsignedT::MAX)))
+                    {
+                        assert_eq!(
+                            $de_bruijn_ilog2(n),
+                            n.ilog2(),
+                            "`{}({n})` should match `{n}.ilog2()`.",
+                            stringify!($de_bruijn_ilog2)
+                        );
+                    }
+                }
+            )+
+        };
+    }
+
+    de_bruijn_ilog2_tests!(u32 de_bruijn_ilog2_32, u64 de_bruijn_ilog2_64);
+}
+
+mod tables {
+    use crate::tables::{ISQRT_8_BIT, ISQRT_AND_REMAINDER_8_BIT};
+
+    #[test]
+    fn match_std_for_every_byte() {
+        for n in 0..=u8::MAX {
+            let sqrt = n.isqrt();
+            let remainder = n - sqrt * sqrt;
+            let entry = ISQRT_AND_REMAINDER_8_BIT[n as usize];
+
+            assert_eq!(
+                ISQRT_8_BIT[n as usize], sqrt,
+                "`ISQRT_8_BIT[{n}]` should be {sqrt}."
+            );
+            assert_eq!(
+                (((n >= 64) as u8) << 3 | entry >> 5, entry & 0b11111),
+                (sqrt, remainder),
+                "`ISQRT_AND_REMAINDER_8_BIT[{n}]` should decode to ({sqrt}, {remainder})."
+            );
+        }
+    }
+}
+
+#[cfg(feature = "algo-original")]
+mod original_const {
+    use crate::original::{checked_isqrt_i32, checked_isqrt_i8, isqrt_i128, isqrt_i32, isqrt_u64};
+
+    #[test]
+    fn known_roots_at_compile_time() {
+        const R: i32 = isqrt_i32(50);
+        const NEGATIVE: Option<i8> = checked_isqrt_i8(-1);
+        const MIN: Option<i32> = checked_isqrt_i32(i32::MIN);
+        const MAX_ROOT: i128 = isqrt_i128(i128::MAX);
+        const UNSIGNED: u64 = isqrt_u64(u64::MAX);
+
+        assert_eq!(R, 7);
+        assert_eq!(NEGATIVE, None);
+        assert_eq!(MIN, None);
+        assert_eq!(MAX_ROOT, 13_043_817_825_332_782_212);
+        assert_eq!(UNSIGNED, u32::MAX as u64);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(feature = "no-panic-signed"),
+        should_panic = "argument of integer square root must be non-negative"
+    )]
+    fn negative_panics_at_runtime() {
+        assert_eq!(isqrt_i32(core::hint::black_box(-1)), 0);
+    }
+}
+
+#[cfg(all(feature = "algo-floating-karatsuba", not(feature = "no-float")))]
+mod floating_point_and_karatsuba_const {
+    use crate::floating_point_and_karatsuba::{
+        isqrt_u128, isqrt_u16, isqrt_u32, isqrt_u64, isqrt_u8,
+    };
+    use core::hint::black_box;
+
+    macro_rules! tests {
+        ($($name:ident: $type:ty, $isqrt:ident);+) => {
+            $(
+                #[test]
+                fn $name() {
+                    const MAX_ROOT: $type = $isqrt(<$type>::MAX);
+                    const LAST_SQUARE: $type = $isqrt(MAX_ROOT * MAX_ROOT);
+                    const BELOW_LAST_SQUARE: $type = $isqrt(MAX_ROOT * MAX_ROOT - 1);
+
+                    assert_eq!(MAX_ROOT, <$type>::MAX.isqrt());
+                    assert_eq!(LAST_SQUARE, MAX_ROOT);
+                    assert_eq!(BELOW_LAST_SQUARE, MAX_ROOT - 1);
+                    assert_eq!($isqrt(black_box(<$type>::MAX)), MAX_ROOT);
+                    assert_eq!($isqrt(black_box(MAX_ROOT * MAX_ROOT - 1)), BELOW_LAST_SQUARE);
+                }
+            )+
+        };
+    }
+
+    tests!(
+        roots_u8: u8, isqrt_u8;
+        roots_u16: u16, isqrt_u16;
+        roots_u32: u32, isqrt_u32;
+        roots_u64: u64, isqrt_u64;
+        roots_u128: u128, isqrt_u128
     );
 }
 
-/*#[test]
-fn extended_floating_u64() {
+#[cfg(all(feature = "algo-floating", not(feature = "no-float")))]
+mod floating_point_const {
+    use crate::floating_point::{checked_isqrt_i128, isqrt_u128};
+    use rand::Rng;
+
+    #[test]
+    fn known_roots_at_compile_time() {
+        const MAX_ROOT: u128 = isqrt_u128(u128::MAX);
+        const TEN_TO_THE_18: u128 = isqrt_u128(10_u128.pow(36));
+        const NOT_A_SQUARE: u128 = isqrt_u128(10_u128.pow(36) - 1);
+        const SIGNED: Option<i128> = checked_isqrt_i128(i128::MAX);
+        const NEGATIVE: Option<i128> = checked_isqrt_i128(-1);
+
+        assert_eq!(MAX_ROOT, u64::MAX as u128);
+        assert_eq!(TEN_TO_THE_18, 10_u128.pow(18));
+        assert_eq!(NOT_A_SQUARE, 10_u128.pow(18) - 1);
+        assert_eq!(SIGNED, Some(13_043_817_825_332_782_212));
+        assert_eq!(NEGATIVE, None);
+    }
+
+    #[test]
+    fn runtime_matches_compile_time() {
+        let mut rng = rand::thread_rng();
+        for n in (0..u128::BITS)
+            .flat_map(|exponent| [(1 << exponent) - 1, 1 << exponent])
+            .chain([u128::MAX])
+            .chain((0..10_000).map(|_| rng.gen::<u128>()))
+        {
+            assert_eq!(
+                isqrt_u128(n),
+                crate::karatsuba::isqrt_u128(n),
+                "`isqrt_u128({n})` at runtime should match the `karatsuba` method it uses at compile time."
+            );
+        }
+    }
+}
+
+#[cfg(all(feature = "algo-floating", not(feature = "no-float")))]
+mod floating_point_branchless {
+    use crate::floating_point::{isqrt_u64_branchless, UnsignedIsqrt};
+    use rand::Rng;
+
+    #[test]
+    fn matches_floating_point() {
+        let mut rng = rand::thread_rng();
+        for n in (0..=1_000)
+            .chain(u64::MAX - 1_000..=u64::MAX)
+            .chain((0..u64::BITS).flat_map(|exponent| [(1 << exponent) - 1, 1 << exponent]))
+            // Perfect squares and their neighbors, including those of the largest roots, where `f64` is least precise.
+            .chain(
+                (0..1_000)
+                    .map(|_| rng.gen::<u32>() as u64)
+                    .chain(u32::MAX as u64 - 1_000..=u32::MAX as u64)
+                    .flat_map(|sqrt| {
+                        [
+                            sqrt * sqrt - (sqrt != 0) as u64,
+                            sqrt * sqrt,
+                            sqrt * sqrt + 1,
+                        ]
+                    }),
+            )
+            .chain((0..10_000).map(|_| rand::thread_rng().gen::<u64>()))
+        {
+            assert_eq!(
+                isqrt_u64_branchless(n),
+                UnsignedIsqrt::isqrt(n),
+                "`isqrt_u64_branchless({n})` should match the `floating_point` method."
+            );
+        }
+    }
+}
+
+// `floating_point`'s `u16` and `u32` methods take the square root with a single instruction and no correction, which
+// relies on it never rounding up to the next integer.
+#[cfg(all(feature = "algo-floating", not(feature = "no-float")))]
+mod floating_point_newton {
+    use crate::floating_point::{isqrt_u128_newton, UnsignedIsqrt};
+    use rand::Rng;
+
+    #[test]
+    fn matches_floating_point() {
+        let mut rng = rand::thread_rng();
+        for n in (0..=1_000)
+            .chain(u128::MAX - 1_000..=u128::MAX)
+            .chain((0..u128::BITS).flat_map(|exponent| [(1 << exponent) - 1, 1 << exponent]))
+            // Perfect squares and their neighbors, including those of the largest roots, where `f64` is least precise.
+            .chain(
+                (0..10_000)
+                    .map(|_| rng.gen::<u64>() as u128)
+                    .chain(u64::MAX as u128 - 1_000..=u64::MAX as u128)
+                    .chain(1 << 32..(1 << 32) + 1_000)
+                    .flat_map(|sqrt| {
+                        [
+                            sqrt * sqrt - (sqrt != 0) as u128,
+                            sqrt * sqrt,
+                            sqrt * sqrt + sqrt + sqrt,
+                        ]
+                    }),
+            )
+            .chain((0..100_000).map(|_| rand::thread_rng().gen::<u128>()))
+        {
+            assert_eq!(
+                isqrt_u128_newton(n),
+                UnsignedIsqrt::isqrt(n),
+                "`isqrt_u128_newton({n})` should match the `floating_point` method."
+            );
+        }
+    }
+}
+
+#[cfg(all(feature = "algo-floating", not(feature = "no-float")))]
+mod floating_point_uncorrected {
     use crate::floating_point::UnsignedIsqrt;
+    use rand::Rng;
 
-    let mut n = 0_u64;
-    for sqrt_n in 0_u64..1 << 32 {
-        assert_eq!(n.isqrt(), sqrt_n, "{n}");
+    fn assert_exact(n: u64, sqrt: u64) {
+        assert!(
+            sqrt * sqrt <= n,
+            "`{n}.isqrt()` is {sqrt}, which is too big."
+        );
+        assert!(
+            n < (sqrt + 1) * (sqrt + 1),
+            "`{n}.isqrt()` is {sqrt}, which is too small."
+        );
+    }
 
-        n += sqrt_n;
-        assert_eq!(n.isqrt(), sqrt_n, "{n}");
+    #[test]
+    fn every_u16() {
+        for n in 0..=u16::MAX {
+            assert_exact(n as u64, UnsignedIsqrt::isqrt(n) as u64);
+        }
+    }
 
-        n += sqrt_n;
-        assert_eq!(n.isqrt(), sqrt_n, "{n}");
+    #[test]
+    #[cfg(not(miri))]
+    fn u32_sample() {
+        // One less than each perfect square is closest to rounding up to the next integer.
+        for sqrt in 1..=u16::MAX as u32 {
+            for n in [sqrt * sqrt - 1, sqrt * sqrt] {
+                assert_exact(n as u64, UnsignedIsqrt::isqrt(n) as u64);
+            }
+        }
 
-        n = n.wrapping_add(1);
+        let mut rng = rand::thread_rng();
+        for n in (u32::MAX - 100_000..=u32::MAX).chain((0..1_000_000).map(|_| rng.gen::<u32>())) {
+            assert_exact(n as u64, UnsignedIsqrt::isqrt(n) as u64);
+        }
     }
-}*/
+}
+
+// Without `std`, the floating-point modules take their square roots with `libm`, which must give the same correctly
+// rounded results as the standard library, so that those modules' results don't change. Running the tests with
+// `--no-default-features --features libm,...` checks the modules themselves that way.
+#[cfg(all(feature = "libm", not(feature = "no-float"), not(miri)))]
+mod libm_sqrt {
+    use rand::Rng;
+
+    #[test]
+    fn matches_std() {
+        for n in 0..=u16::MAX {
+            let n = n as f32;
+            assert_eq!(libm::sqrtf(n).to_bits(), n.sqrt().to_bits(), "{n}");
+        }
+
+        for n in (u64::MAX - 1_000..=u64::MAX)
+            .chain((0..u64::BITS).flat_map(|exponent| [(1 << exponent) - 1, 1 << exponent]))
+            .chain((0..100_000).map(|_| rand::thread_rng().gen::<u64>()))
+            .chain((0..100_000).map(|_| rand::thread_rng().gen::<u32>() as u64))
+        {
+            let n = n as f64;
+            assert_eq!(libm::sqrt(n).to_bits(), n.sqrt().to_bits(), "{n}");
+        }
+    }
+}
+
+#[cfg(all(feature = "std", not(feature = "no-float")))]
+mod cbrt {
+    use crate::cbrt::{SignedCbrt, UnsignedCbrt};
+    use rand::Rng;
+
+    macro_rules! cbrt_tests {
+        ($($SignedT:ident $UnsignedT:ident),+) => {
+            $(
+                mod $UnsignedT {
+                    use super::*;
+
+                    fn check(n: $UnsignedT) {
+                        let cbrt = UnsignedCbrt::icbrt(n);
+                        assert!(
+                            cbrt.pow(3) <= n && (cbrt + 1).checked_pow(3).map_or(true, |higher_than_n| n < higher_than_n),
+                            "`{n}.icbrt()` shouldn't be {cbrt}."
+                        );
+                    }
+
+                    #[test]
+                    fn small_and_large_values() {
+                        let mut rng = rand::thread_rng();
+                        for n in (0..=127)
+                            .chain($UnsignedT::MAX - 127..=$UnsignedT::MAX)
+                            .chain((0..$UnsignedT::BITS).map(|exponent| (1 << exponent) - 1))
+                            .chain((0..$UnsignedT::BITS).map(|exponent| 1 << exponent))
+                            .chain((0..10_000).map(|_| rng.gen::<$UnsignedT>()))
+                        {
+                            check(n);
+                        }
+                    }
+
+                    #[test]
+                    #[cfg(not(miri))]
+                    fn perfect_cubes() {
+                        // As with the perfect-square walk in `tests!`, step from one perfect cube to the next, as
+                        // `(k + 1)^3 = k^3 + 3k^2 + 3k + 1`, checking each cube, a value about halfway to the next,
+                        // and the next cube minus one.
+                        let max_cbrt = UnsignedCbrt::icbrt($UnsignedT::MAX);
+
+                        let mut n: $UnsignedT = 0;
+                        for k in 0..(max_cbrt as u128).min(1024) as $UnsignedT {
+                            let step = 3 * k * k + 3 * k + 1;
+                            assert_eq!(UnsignedCbrt::icbrt(n), k, "`{n}.icbrt()` should be {k}.");
+                            assert_eq!(UnsignedCbrt::icbrt(n + step / 2), k, "`{}.icbrt()` should be {k}.", n + step / 2);
+                            assert_eq!(UnsignedCbrt::icbrt(n + step - 1), k, "`{}.icbrt()` should be {k}.", n + step - 1);
+                            n += step;
+                        }
+
+                        // The last cube that fits and the values around it.
+                        let max_cube = max_cbrt.pow(3);
+                        assert!((max_cbrt + 1).checked_pow(3).is_none());
+                        assert_eq!(UnsignedCbrt::icbrt(max_cube), max_cbrt);
+                        assert_eq!(UnsignedCbrt::icbrt(max_cube - 1), max_cbrt - 1);
+                    }
+                }
+
+                mod $SignedT {
+                    use super::*;
+
+                    #[test]
+                    fn matches_magnitude() {
+                        for n in (-128..=127).chain([$SignedT::MIN, $SignedT::MIN + 1, $SignedT::MAX]) {
+                            let cbrt = SignedCbrt::icbrt(n);
+                            assert_eq!(
+                                cbrt.unsigned_abs(),
+                                UnsignedCbrt::icbrt(n.unsigned_abs()),
+                                "`({n}).icbrt()` should have the magnitude of its magnitude's cube root."
+                            );
+                            assert!(cbrt == 0 || (cbrt < 0) == (n < 0), "`({n}).icbrt()` should have the sign of {n}.");
+                            assert_eq!(SignedCbrt::checked_icbrt(n), (n >= 0).then_some(cbrt));
+                        }
+
+                        assert_eq!(SignedCbrt::icbrt(-27 as $SignedT), -3);
+                        assert_eq!(SignedCbrt::icbrt(-28 as $SignedT), -3);
+                        assert_eq!(SignedCbrt::icbrt(-26 as $SignedT), -2);
+                    }
+                }
+            )+
+        };
+    }
+
+    cbrt_tests!(i8 u8, i16 u16, i32 u32, i64 u64, i128 u128, isize usize);
+}
+
+#[cfg(feature = "algo-karatsuba")]
+mod root {
+    use crate::karatsuba::UnsignedIsqrt;
+    use crate::root::UnsignedRoot;
+    use rand::Rng;
+
+    macro_rules! root_tests {
+        ($($UnsignedT:ident),+) => {
+            $(
+                mod $UnsignedT {
+                    use super::*;
+
+                    #[test]
+                    fn square_root_matches_isqrt() {
+                        let mut rng = rand::thread_rng();
+                        for n in (0..=127)
+                            .chain($UnsignedT::MAX - 127..=$UnsignedT::MAX)
+                            .chain((0..$UnsignedT::BITS).map(|exponent| (1 << exponent) - 1))
+                            .chain((0..$UnsignedT::BITS).map(|exponent| 1 << exponent))
+                            .chain((0..10_000).map(|_| rng.gen::<$UnsignedT>()))
+                        {
+                            assert_eq!(
+                                n.nth_root(2),
+                                UnsignedIsqrt::isqrt(n),
+                                "`{n}.nth_root(2)` should match `{n}.isqrt()`."
+                            );
+                        }
+                    }
+
+                    #[test]
+                    fn perfect_powers() {
+                        for n in 1..=8 {
+                            // The largest root whose `n`th power fits, and the powers of a range of smaller roots.
+                            let max_root = $UnsignedT::MAX.nth_root(n);
+                            assert!(max_root.checked_pow(n).is_some());
+                            assert!(
+                                max_root.checked_add(1).and_then(|higher| higher.checked_pow(n)).is_none(),
+                                "`MAX.nth_root({n})` is too small."
+                            );
+
+                            let walk = (max_root as u128).min(1024) as $UnsignedT;
+                            for root in (1..=walk).chain(max_root - walk + 1..=max_root) {
+                                let power = root.pow(n);
+                                assert_eq!(power.nth_root(n), root, "`{power}.nth_root({n})` should be {root}.");
+                                assert_eq!(
+                                    (power - 1).nth_root(n),
+                                    root - 1,
+                                    "`{}.nth_root({n})` should be {}.",
+                                    power - 1,
+                                    root - 1
+                                );
+                            }
+                        }
+                    }
+
+                    #[test]
+                    fn small_values_and_large_roots() {
+                        for n in 1..=2 * $UnsignedT::BITS {
+                            assert_eq!((0 as $UnsignedT).nth_root(n), 0);
+                            assert_eq!((1 as $UnsignedT).nth_root(n), 1);
+                        }
+                        assert_eq!($UnsignedT::MAX.nth_root(1), $UnsignedT::MAX);
+                        assert_eq!($UnsignedT::MAX.nth_root($UnsignedT::BITS - 1), 2);
+                        assert_eq!($UnsignedT::MAX.nth_root($UnsignedT::BITS), 1);
+                        assert_eq!($UnsignedT::MAX.nth_root(u32::MAX), 1);
+                    }
+                }
+            )+
+        };
+    }
+
+    root_tests!(u8, u16, u32, u64, u128, usize);
+
+    #[test]
+    #[should_panic = "the zeroth root is undefined"]
+    fn zeroth_root() {
+        let _ = 4_u32.nth_root(0);
+    }
+}
+
+#[cfg(all(feature = "algo-karatsuba", feature = "alloc"))]
+mod decimal {
+    use crate::decimal::{isqrt_decimal, InvalidDigitError};
+    use crate::karatsuba::UnsignedIsqrt;
+    use rand::Rng;
+
+    #[test]
+    fn matches_u128() {
+        let mut rng = rand::thread_rng();
+        for n in (0..=1_000)
+            .chain(u128::MAX - 1_000..=u128::MAX)
+            .chain((0..u128::BITS).flat_map(|exponent| [(1 << exponent) - 1, 1 << exponent]))
+            .chain((0..1_000).map(|_| rng.gen::<u128>() >> rng.gen_range(0..u128::BITS)))
+        {
+            assert_eq!(
+                isqrt_decimal(&n.to_string()),
+                Ok(UnsignedIsqrt::isqrt(n).to_string()),
+                "The decimal integer square root of {n} should match the `u128` one."
+            );
+        }
+    }
+
+    #[test]
+    fn large_values() {
+        // 10^100, 10^100 - 1, 2 * 10^100, and 2^256 - 1.
+        let ten_to_the_100 = format!("1{}", "0".repeat(100));
+        assert_eq!(
+            isqrt_decimal(&ten_to_the_100),
+            Ok(format!("1{}", "0".repeat(50)))
+        );
+        assert_eq!(isqrt_decimal(&"9".repeat(100)), Ok("9".repeat(50)));
+        assert_eq!(
+            isqrt_decimal(&format!("2{}", "0".repeat(100))).as_deref(),
+            Ok("141421356237309504880168872420969807856967187537694")
+        );
+        assert_eq!(
+            isqrt_decimal(
+                "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+            )
+            .as_deref(),
+            Ok("340282366920938463463374607431768211455")
+        );
+    }
+
+    #[test]
+    fn leading_zeros() {
+        assert_eq!(isqrt_decimal("0").as_deref(), Ok("0"));
+        assert_eq!(isqrt_decimal("000").as_deref(), Ok("0"));
+        assert_eq!(isqrt_decimal("0001").as_deref(), Ok("1"));
+        assert_eq!(isqrt_decimal("00099").as_deref(), Ok("9"));
+        assert_eq!(isqrt_decimal("0100").as_deref(), Ok("10"));
+    }
+
+    #[test]
+    fn invalid_digits() {
+        for digits in ["", "-4", "+4", " 4", "4 ", "1_000", "12a", "٣", "1e6"] {
+            assert_eq!(
+                isqrt_decimal(digits),
+                Err(InvalidDigitError),
+                "{digits:?} should be rejected."
+            );
+        }
+        assert_eq!(
+            InvalidDigitError.to_string(),
+            "argument of decimal integer square root must be a nonempty string of ASCII digits"
+        );
+    }
+}
+
+#[cfg(all(feature = "algo-karatsuba", feature = "alloc"))]
+mod fixed {
+    use crate::fixed::isqrt_be;
+    use crate::wide::isqrt_u256;
+    use rand::Rng;
+
+    fn zero_extended(n: u128) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        bytes[16..].copy_from_slice(&n.to_be_bytes());
+        bytes
+    }
+
+    #[test]
+    fn matches_u128() {
+        let mut rng = rand::thread_rng();
+        for n in (0..=1_000)
+            .chain(u128::MAX - 1_000..=u128::MAX)
+            .chain((0..u128::BITS).flat_map(|exponent| [(1 << exponent) - 1, 1 << exponent]))
+            .chain((0..10_000).map(|_| rng.gen()))
+        {
+            assert_eq!(
+                isqrt_be(zero_extended(n)),
+                zero_extended(n.isqrt()),
+                "`isqrt_be` should match `u128::isqrt` for {n}."
+            );
+        }
+    }
+
+    #[test]
+    fn matches_isqrt_u256() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10_000 {
+            let (hi, lo): (u128, u128) = (rng.gen(), rng.gen());
+            let mut n = [0; 32];
+            n[..16].copy_from_slice(&hi.to_be_bytes());
+            n[16..].copy_from_slice(&lo.to_be_bytes());
+
+            assert_eq!(isqrt_be(n), zero_extended(isqrt_u256(hi, lo)));
+        }
+    }
+
+    #[test]
+    fn sixty_four_bytes() {
+        assert_eq!(isqrt_be([0; 64]), [0; 64]);
+
+        let mut all_ones_sqrt = [0; 64];
+        all_ones_sqrt[32..].fill(u8::MAX);
+        assert_eq!(isqrt_be([u8::MAX; 64]), all_ones_sqrt);
+
+        // `2^510` is the square of `2^255`.
+        let mut power = [0; 64];
+        power[0] = 0b0100_0000;
+        let mut power_sqrt = [0; 64];
+        power_sqrt[32] = 0b1000_0000;
+        assert_eq!(isqrt_be(power), power_sqrt);
+    }
+}
+
+#[cfg(all(feature = "algo-karatsuba", feature = "alloc"))]
+mod bytes {
+    use crate::bytes::isqrt_bytes_le;
+    use crate::decimal::isqrt_decimal;
+    use crate::karatsuba::UnsignedIsqrt;
+    use rand::Rng;
+
+    fn trimmed(bytes: &[u8]) -> &[u8] {
+        let len = bytes
+            .iter()
+            .rposition(|&byte| byte != 0)
+            .map_or(0, |top| top + 1);
+        &bytes[..len]
+    }
+
+    /// Writes little-endian bytes in decimal by repeatedly dividing by ten.
+    fn to_decimal(bytes: &[u8]) -> String {
+        let mut n = trimmed(bytes).to_vec();
+        let mut digits = Vec::new();
+        while !n.is_empty() {
+            let mut remainder = 0;
+            for byte in n.iter_mut().rev() {
+                let numerator = remainder << 8 | *byte as u16;
+                *byte = (numerator / 10) as u8;
+                remainder = numerator % 10;
+            }
+            digits.push(b'0' + remainder as u8);
+            n.truncate(trimmed(&n).len());
+        }
+        if digits.is_empty() {
+            digits.push(b'0');
+        }
+        digits.reverse();
+        String::from_utf8(digits).unwrap()
+    }
+
+    #[test]
+    fn matches_u128() {
+        let mut rng = rand::thread_rng();
+        for n in (0..=1_000)
+            .chain(u128::MAX - 1_000..=u128::MAX)
+            .chain((0..u128::BITS).flat_map(|exponent| [(1 << exponent) - 1, 1 << exponent]))
+            .chain((0..10_000).map(|_| rng.gen::<u128>() >> rng.gen_range(0..u128::BITS)))
+        {
+            // Both with and without trailing zero bytes.
+            let bytes = n.to_le_bytes();
+            let expected = UnsignedIsqrt::isqrt(n).to_le_bytes();
+            assert_eq!(
+                isqrt_bytes_le(&bytes),
+                trimmed(&expected),
+                "The square root of {n} is wrong."
+            );
+            assert_eq!(
+                isqrt_bytes_le(trimmed(&bytes)),
+                trimmed(&expected),
+                "The square root of {n} is wrong."
+            );
+        }
+    }
+
+    #[test]
+    fn matches_u256() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10_000 {
+            let (hi, lo) = (
+                rng.gen::<u128>() >> rng.gen_range(0..u128::BITS),
+                rng.gen::<u128>(),
+            );
+            let bytes: Vec<u8> = lo
+                .to_le_bytes()
+                .into_iter()
+                .chain(hi.to_le_bytes())
+                .collect();
+            let expected = crate::wide::isqrt_u256(hi, lo).to_le_bytes();
+            assert_eq!(
+                isqrt_bytes_le(&bytes),
+                trimmed(&expected),
+                "The square root of {hi} * 2^128 + {lo} is wrong."
+            );
+        }
+    }
+
+    #[test]
+    fn matches_decimal() {
+        let mut rng = rand::thread_rng();
+        for len in (17..=80).chain([200, 513]) {
+            for _ in 0..10 {
+                let bytes: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+                assert_eq!(
+                    to_decimal(&isqrt_bytes_le(&bytes)),
+                    isqrt_decimal(&to_decimal(&bytes)).unwrap(),
+                    "The square root of {} is wrong.",
+                    to_decimal(&bytes)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn powers_of_two() {
+        for bits in 0..=1_000 {
+            // `2^(2 * bits)` and `2^(2 * bits) - 1`, whose square roots are `2^bits` and `2^bits - 1`.
+            let mut square = vec![0; bits / 4];
+            square.push(1 << (bits % 4 * 2));
+            let mut sqrt = vec![0; bits / 8];
+            sqrt.push(1 << (bits % 8));
+            assert_eq!(
+                isqrt_bytes_le(&square),
+                sqrt,
+                "The square root of 2^{} is wrong.",
+                2 * bits
+            );
+
+            let square_minus_one: Vec<u8> = vec![0xFF; 2 * bits / 8]
+                .into_iter()
+                .chain((2 * bits % 8 != 0).then(|| (1 << (2 * bits % 8)) - 1))
+                .collect();
+            let sqrt_minus_one: Vec<u8> = vec![0xFF; bits / 8]
+                .into_iter()
+                .chain((bits % 8 != 0).then(|| (1 << (bits % 8)) - 1))
+                .collect();
+            assert_eq!(
+                isqrt_bytes_le(&square_minus_one),
+                sqrt_minus_one,
+                "The square root of 2^{} - 1 is wrong.",
+                2 * bits
+            );
+        }
+    }
+}
+
+#[cfg(all(feature = "algo-floating", not(feature = "no-float")))]
+mod float {
+    use crate::float::FloatIsqrt;
+    use crate::floating_point::UnsignedIsqrt;
+    use rand::Rng;
+
+    #[test]
+    fn floors_before_taking_the_square_root() {
+        assert_eq!(15.99_f64.isqrt_of_floor(), 3);
+        assert_eq!(16.0_f64.isqrt_of_floor(), 4);
+        assert_eq!(16.5_f64.isqrt_of_floor(), 4);
+        assert_eq!(24.999_f32.isqrt_of_floor(), 4);
+        assert_eq!(25.0_f32.isqrt_of_floor(), 5);
+        assert_eq!(0.999_f64.isqrt_of_floor(), 0);
+        assert_eq!(1.0_f64.isqrt_of_floor(), 1);
+    }
+
+    #[test]
+    fn matches_integers() {
+        let mut rng = rand::thread_rng();
+        for n in (0..1_000_u64)
+            .chain((0..u64::BITS).flat_map(|exponent| [(1 << exponent) - 1, 1 << exponent]))
+            .chain((0..10_000).map(|_| rng.gen::<u64>()))
+        {
+            // The conversion rounds large integers to nearby ones, but those are still integers, so they're their own
+            // floors.
+            let float = n as f64;
+            assert_eq!(
+                float.isqrt_of_floor(),
+                UnsignedIsqrt::isqrt(float as u64),
+                "`{float}.isqrt_of_floor()` is wrong."
+            );
+        }
+        for n in (0..1_000_u32).chain((0..10_000).map(|_| rng.gen::<u32>())) {
+            let float = n as f32;
+            assert_eq!(
+                float.isqrt_of_floor(),
+                UnsignedIsqrt::isqrt(float as u32),
+                "`{float}.isqrt_of_floor()` is wrong."
+            );
+        }
+    }
+
+    #[test]
+    fn negatives_and_nan_give_zero() {
+        for float in [
+            -0.0,
+            -0.5,
+            -1.0,
+            -1e300,
+            f64::MIN,
+            f64::NEG_INFINITY,
+            f64::NAN,
+            -f64::NAN,
+        ] {
+            assert_eq!(
+                float.isqrt_of_floor(),
+                0,
+                "`{float}.isqrt_of_floor()` should be 0."
+            );
+        }
+        for float in [-0.5, -1.0, f32::MIN, f32::NEG_INFINITY, f32::NAN] {
+            assert_eq!(
+                float.isqrt_of_floor(),
+                0,
+                "`{float}.isqrt_of_floor()` should be 0."
+            );
+        }
+    }
+
+    #[test]
+    fn large_values_saturate() {
+        // `2^64` is the smallest `f64` above `u64::MAX`.
+        for float in [18_446_744_073_709_551_616.0, 1e30, f64::MAX, f64::INFINITY] {
+            assert_eq!(
+                float.isqrt_of_floor(),
+                <u64 as UnsignedIsqrt>::ISQRT_MAX,
+                "`{float}.isqrt_of_floor()` should saturate."
+            );
+        }
+        for float in [4_294_967_296.0, 1e30, f32::MAX, f32::INFINITY] {
+            assert_eq!(
+                float.isqrt_of_floor(),
+                <u32 as UnsignedIsqrt>::ISQRT_MAX,
+                "`{float}.isqrt_of_floor()` should saturate."
+            );
+        }
+
+        // The largest `f64` below `2^64` is `2^64 - 2^11`, whose square root is `2^32 - 1`.
+        assert_eq!(
+            18_446_744_073_709_549_568.0_f64.isqrt_of_floor(),
+            (1 << 32) - 1
+        );
+        // The largest `f32` below `2^32` is `2^32 - 2^8`, whose square root is `2^16 - 1`.
+        assert_eq!(4_294_967_040.0_f32.isqrt_of_floor(), (1 << 16) - 1);
+    }
+
+    #[test]
+    fn isqrt_f64_integer_part_matches_u64() {
+        let mut rng = rand::thread_rng();
+        // Near `2^26`, `f64` starts rounding squares' neighbors, and near `2^32`, the fraction is closest to the
+        // next integer.
+        for n in (0..1_000_u64)
+            .chain(
+                ((1 << 26) - 1_000..=(1 << 26) + 1_000)
+                    .chain(u32::MAX as u64 - 1_000..=u32::MAX as u64)
+                    .flat_map(|sqrt| [sqrt * sqrt - 1, sqrt * sqrt, sqrt * sqrt + 1]),
+            )
+            .chain([u64::MAX - 1, u64::MAX])
+            .chain((0..10_000).map(|_| rng.gen::<u64>()))
+        {
+            let root = UnsignedIsqrt::isqrt_f64(n);
+            assert_eq!(
+                root.floor() as u64,
+                UnsignedIsqrt::isqrt(n),
+                "The integer part of `{n}.isqrt_f64()`, {root}, is wrong."
+            );
+            assert!(
+                (root - (n as f64).sqrt()).abs() <= 1e-6 * root.max(1.0),
+                "`{n}.isqrt_f64()`, {root}, is too far from the square root."
+            );
+        }
+    }
+
+    #[test]
+    fn isqrt_f64_integer_part_matches_u128() {
+        let mut rng = rand::thread_rng();
+        for n in (0..1_000_u128)
+            .chain(
+                ((1 << 52) - 1_000..(1 << 53))
+                    .step_by(1 << 40)
+                    .chain((1 << 53) - 1_000..(1 << 53))
+                    .chain(u64::MAX as u128 - 1_000..=u64::MAX as u128)
+                    .flat_map(|sqrt| [sqrt * sqrt - 1, sqrt * sqrt, sqrt * sqrt + 1]),
+            )
+            .chain([u128::MAX - 1, u128::MAX])
+            .chain((0..10_000).map(|_| rng.gen::<u128>() >> rng.gen_range(0..128)))
+        {
+            let root = UnsignedIsqrt::isqrt_f64(n);
+            let sqrt = UnsignedIsqrt::isqrt(n);
+            if sqrt < 1 << 53 {
+                assert_eq!(
+                    root.floor() as u128,
+                    sqrt,
+                    "The integer part of `{n}.isqrt_f64()`, {root}, is wrong."
+                );
+            } else {
+                assert!(
+                    root as u128 <= sqrt && root as u128 >= sqrt - (sqrt >> 52),
+                    "`{n}.isqrt_f64()`, {root}, should be `{sqrt}` rounded down."
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn isqrt_f64_narrow_and_wrapped() {
+        for n in (0..=u16::MAX as u32).chain(u32::MAX - 1_000..=u32::MAX) {
+            let root = UnsignedIsqrt::isqrt_f64(n);
+            assert_eq!(root, (n as f64).sqrt(), "`{n}.isqrt_f64()` is wrong.");
+            assert_eq!(root.floor() as u32, UnsignedIsqrt::isqrt(n));
+        }
+        assert_eq!(UnsignedIsqrt::isqrt_f64(u8::MAX), 255_f64.sqrt());
+        assert_eq!(
+            UnsignedIsqrt::isqrt_f64(usize::MAX).floor() as usize,
+            UnsignedIsqrt::isqrt(usize::MAX)
+        );
+
+        let n = 94_906_267_u64.pow(2) - 1;
+        let expected = UnsignedIsqrt::isqrt_f64(n);
+        assert_eq!(
+            UnsignedIsqrt::isqrt_f64(core::num::NonZero::new(n).unwrap()),
+            expected
+        );
+        assert_eq!(UnsignedIsqrt::isqrt_f64(core::num::Wrapping(n)), expected);
+        assert_eq!(UnsignedIsqrt::isqrt_f64(core::num::Saturating(n)), expected);
+    }
+}
+
+#[cfg(feature = "algo-original")]
+mod heron_reference {
+    use crate::heron::UnsignedIsqrt;
+
+    #[test]
+    #[cfg(not(miri))]
+    fn exhaustive_u16() {
+        for n in 0..=u16::MAX {
+            assert_eq!(
+                UnsignedIsqrt::isqrt(n),
+                crate::original::UnsignedIsqrt::isqrt(n),
+                "`{n}.isqrt()` should match the `original` module."
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(not(miri))]
+    fn sampled_u64() {
+        use rand::Rng;
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..1_000_000 {
+            // Spread the samples over every bit length rather than just the largest ones.
+            let n = rng.gen::<u64>() >> rng.gen_range(0..u64::BITS);
+            assert_eq!(
+                UnsignedIsqrt::isqrt(n),
+                crate::original::UnsignedIsqrt::isqrt(n),
+                "`{n}.isqrt()` should match the `original` module."
+            );
+        }
+    }
+}
+
+macro_rules! precondition_shift_tests {
+    ($module:ident ; $($UnsignedT:ident),+) => {
+        mod $module {
+            $(
+                mod $UnsignedT {
+                    use crate::$module::UnsignedIsqrt;
+
+                    fn check(n: $UnsignedT) {
+                        let sqrt_n = UnsignedIsqrt::isqrt(n);
+
+                        assert!(
+                            sqrt_n * sqrt_n <= n,
+                            "The integer square root of {n} should be lower than {sqrt_n} (the current return value of `{n}.isqrt()`)."
+                        );
+
+                        assert!(
+                            (sqrt_n + 1).checked_mul(sqrt_n + 1).map(|higher_than_n| n < higher_than_n).unwrap_or(true),
+                            "The integer square root of {n} should be higher than {sqrt_n} (the current return value of `{n}.isqrt()`)."
+                        );
+                    }
+
+                    #[test]
+                    fn test_precondition_shift() {
+                        // The Karatsuba methods shift the input left by `precondition_shift`, the number of leading zeros
+                        // rounded down to an even number, and then shift the result right by half of that. Inputs with
+                        // `precondition_shift` leading zeros and inputs with `precondition_shift + 1` leading zeros share
+                        // the same shift, so for each even `precondition_shift` we check:
+                        //
+                        // * `1 << (BITS - 1 - precondition_shift)`, the lowest input with `precondition_shift` leading
+                        //   zeros, and its neighbors,
+                        // * `MAX >> precondition_shift`, the highest input with `precondition_shift` leading zeros,
+                        // * `1 << (BITS - 2 - precondition_shift)`, the lowest input with `precondition_shift + 1`
+                        //   leading zeros, and its neighbors,
+                        // * `MAX >> (precondition_shift + 1)`, the highest input with `precondition_shift + 1` leading
+                        //   zeros,
+                        // * and the largest perfect square below each of the highest inputs, along with its neighbors.
+                        //
+                        // Shifts of `HALF_BITS` and above are also covered, as some methods handle them separately.
+                        for precondition_shift in (0..$UnsignedT::BITS).step_by(2) {
+                            for leading_zeros in [precondition_shift, precondition_shift + 1] {
+                                let lowest: $UnsignedT = 1 << ($UnsignedT::BITS - 1 - leading_zeros);
+                                let highest = $UnsignedT::MAX >> leading_zeros;
+                                assert_eq!(lowest.leading_zeros(), leading_zeros);
+                                assert_eq!(highest.leading_zeros(), leading_zeros);
+
+                                let sqrt_highest = UnsignedIsqrt::isqrt(highest);
+                                let square = sqrt_highest * sqrt_highest;
+
+                                for n in [lowest - 1, lowest, lowest + 1, highest, square - 1, square, square + 1] {
+                                    check(n);
+                                }
+                            }
+                        }
+                    }
+                }
+            )*
+        }
+    };
+}
+
+mod precondition_shift {
+    #[cfg(all(feature = "algo-floating-karatsuba", not(feature = "no-float")))]
+    precondition_shift_tests!(floating_point_and_karatsuba; u8, u16, u32, u64, u128);
+    #[cfg(feature = "algo-karatsuba")]
+    precondition_shift_tests!(karatsuba; u8, u16, u32, u64, u128);
+    #[cfg(feature = "algo-karatsuba-2")]
+    precondition_shift_tests!(karatsuba_2; u8, u16, u32, u64, u128);
+    precondition_shift_tests!(table; u8, u16, u32, u64, u128);
+}
+
+// `karatsuba_2`'s last stage only checks its candidate square root for overflow when the candidate is past the largest
+// square root, which happens right around `ISQRT_MAX^2`, so check each side of that and the top of the range.
+#[cfg(feature = "algo-karatsuba-2")]
+mod karatsuba_2_stages {
+    use crate::karatsuba_2::*;
+    use rand::Rng;
+
+    // Runs every stage on `n`, which must have one of its top two bits set, checking each root and remainder on the way.
+    fn check_stages(n: u128) {
+        let (s, r) = karatsuba_first_stage((n >> 120) as u8);
+        assert_eq!(s as u128 * s as u128 + r as u128, n >> 120);
+        let (s, r) = karatsuba_middle_stage_u16((n >> 112) as u16, s, r);
+        assert_eq!(s as u128 * s as u128 + r as u128, n >> 112);
+        let (s, r) = karatsuba_middle_stage_u32((n >> 96) as u32, s, r);
+        assert_eq!(s as u128 * s as u128 + r as u128, n >> 96);
+        let (s, r) = karatsuba_middle_stage_u64((n >> 64) as u64, s, r);
+        assert_eq!(s as u128 * s as u128 + r as u128, n >> 64);
+        assert!(
+            r <= 2 * s,
+            "The remainder of the top 64 bits of {n} should be at most twice the root."
+        );
+
+        assert_eq!(
+            karatsuba_last_stage_u128(n, s, r),
+            UnsignedIsqrt::isqrt(n),
+            "The stages should give the square root of {n}."
+        );
+        assert_eq!(
+            karatsuba_middle_stage_u128(n, s, r),
+            UnsignedIsqrt::isqrt_rem(n),
+            "The stages should give the square root and remainder of {n}."
+        );
+    }
+
+    #[test]
+    fn assemble_a_u128_root() {
+        for n in [
+            1 << 126,
+            u128::MAX,
+            (1 << 127) - 1,
+            1 << 127,
+            u128::MAX - (1 << 64),
+        ] {
+            check_stages(n);
+        }
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..100_000 {
+            check_stages(rng.gen::<u128>() | 1 << 127);
+            check_stages(rng.gen::<u128>() >> 1 | 1 << 126);
+        }
+    }
+}
+
+#[cfg(feature = "algo-karatsuba-2")]
+mod karatsuba_2_top_range {
+    use crate::karatsuba_2::UnsignedIsqrt;
+
+    macro_rules! top_range_tests {
+        ($($type:ident),+) => {
+            $(
+                #[test]
+                fn $type() {
+                    let max_square = <$type as UnsignedIsqrt>::ISQRT_MAX * <$type as UnsignedIsqrt>::ISQRT_MAX;
+                    for n in (max_square - 1000..=max_square.saturating_add(1000)).chain($type::MAX - 1000..=$type::MAX) {
+                        let sqrt = UnsignedIsqrt::isqrt(n);
+                        assert!(sqrt * sqrt <= n, "`{n}.isqrt()` is {sqrt}, which is too big.");
+                        assert!(
+                            (sqrt + 1).checked_mul(sqrt + 1).map_or(true, |next_square| n < next_square),
+                            "`{n}.isqrt()` is {sqrt}, which is too small."
+                        );
+                    }
+                }
+            )+
+        };
+    }
+
+    top_range_tests!(u16, u32, u64, u128);
+}
+
+#[cfg(all(feature = "algo-floating", not(feature = "no-float")))]
+fn floating_u64_bug_cases() {
+    use crate::floating_point::UnsignedIsqrt;
+    assert_eq!(
+        UnsignedIsqrt::isqrt(4_503_599_761_588_224_u64),
+        67_108_864,
+        "`4_503_599_761_588_224.isqrt()` should be `67_108_864`."
+    );
+}
+
+/*#[test]
+fn extended_floating_u64() {
+    use crate::floating_point::UnsignedIsqrt;
+
+    let mut n = 0_u64;
+    for sqrt_n in 0_u64..1 << 32 {
+        assert_eq!(UnsignedIsqrt::isqrt(n), sqrt_n, "{n}");
+
+        n += sqrt_n;
+        assert_eq!(UnsignedIsqrt::isqrt(n), sqrt_n, "{n}");
+
+        n += sqrt_n;
+        assert_eq!(UnsignedIsqrt::isqrt(n), sqrt_n, "{n}");
+
+        n = n.wrapping_add(1);
+    }
+}*/
+
+#[cfg(feature = "algo-karatsuba")]
+mod isqrt_scaled {
+    use crate::karatsuba::UnsignedIsqrt;
+
+    macro_rules! exhaustive_float_reference_tests {
+        ($($UnsignedT:ident),+) => {
+            $(
+                #[test]
+                fn $UnsignedT() {
+                    // The square roots involved are small enough that `f64` always floors to the correct value.
+                    for output_bits in 0..=$UnsignedT::BITS {
+                        let scale = 2_f64.powi(output_bits as i32 - ($UnsignedT::BITS / 2) as i32);
+                        for n in 0..=$UnsignedT::MAX {
+                            assert_eq!(
+                                UnsignedIsqrt::isqrt_scaled(n, output_bits),
+                                ((n as f64).sqrt() * scale).floor() as $UnsignedT,
+                                "`{n}.isqrt_scaled({output_bits})` should match the `f64` reference.",
+                            );
+                        }
+                    }
+                }
+            )*
+        };
+    }
+
+    macro_rules! exact_bounds_tests {
+        ($($UnsignedT:ident),+) => {
+            $(
+                #[test]
+                fn $UnsignedT() {
+                    for output_bits in 0..=$UnsignedT::BITS {
+                        for n in (0..=127)
+                            .chain($UnsignedT::MAX - 127..=$UnsignedT::MAX)
+                            .chain((0..$UnsignedT::BITS).map(|exponent| (1 << exponent) - 1))
+                            .chain((0..$UnsignedT::BITS).map(|exponent| 1 << exponent))
+                            .chain((0..$UnsignedT::BITS).map(|exponent| (1 << exponent) + 1))
+                        {
+                            let s = UnsignedIsqrt::isqrt_scaled(n, output_bits) as u128;
+
+                            // Compare against `n * 2^(output_bits - BITS / 2)` squared, keeping everything integral.
+                            let (scaled_n, s) = if output_bits >= $UnsignedT::BITS / 2 {
+                                ((n as u128) << 2 * (output_bits - $UnsignedT::BITS / 2), s)
+                            } else {
+                                (n as u128, s << ($UnsignedT::BITS / 2 - output_bits))
+                            };
+                            let step = if output_bits >= $UnsignedT::BITS / 2 {
+                                1
+                            } else {
+                                1 << ($UnsignedT::BITS / 2 - output_bits)
+                            };
+
+                            assert!(
+                                s * s <= scaled_n,
+                                "`{n}.isqrt_scaled({output_bits})` is too high."
+                            );
+                            assert!(
+                                (s + step).checked_mul(s + step).map(|higher| scaled_n < higher).unwrap_or(true),
+                                "`{n}.isqrt_scaled({output_bits})` is too low."
+                            );
+                        }
+                    }
+                }
+            )*
+        };
+    }
+
+    exhaustive_float_reference_tests!(u8, u16);
+    exact_bounds_tests!(u32, u64);
+
+    #[test]
+    fn matches_isqrt_at_half_bits() {
+        for n in (0..=u64::from(u16::MAX)).chain(u64::MAX - 0xFFFF..=u64::MAX) {
+            assert_eq!(UnsignedIsqrt::isqrt_scaled(n, 32), UnsignedIsqrt::isqrt(n));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_many_output_bits() {
+        let _ = UnsignedIsqrt::isqrt_scaled(1_u32, 33);
+    }
+}
+
+#[cfg(all(feature = "algo-karatsuba", feature = "algo-original"))]
+mod bitpacked {
+    use crate::bitpacked::isqrt_bitpacked;
+    use rand::Rng;
+
+    fn get_bits(data: &[u8], start: usize, width: u32) -> u16 {
+        (0..width as usize).fold(0, |value, i| {
+            let bit = (data[(start + i) / 8] >> ((start + i) % 8)) & 1;
+            value | ((bit as u16) << i)
+        })
+    }
+
+    fn set_bits(data: &mut [u8], start: usize, width: u32, value: u16) {
+        for i in 0..width as usize {
+            let (byte, bit) = ((start + i) / 8, (start + i) % 8);
+            data[byte] = (data[byte] & !(1 << bit)) | ((((value >> i) & 1) as u8) << bit);
+        }
+    }
+
+    #[test]
+    fn six_bit_samples() {
+        // 0, 1, 63, and 36 packed as 6-bit values.
+        let packed: u32 = 1 << 6 | 63 << 12 | 36 << 18;
+        let mut out = [0; 2];
+        isqrt_bitpacked(&packed.to_le_bytes()[..3], 6, &mut out);
+        // 0, 1, 7, and 6 packed as 3-bit values.
+        assert_eq!(u16::from_le_bytes(out), 1 << 3 | 7 << 6 | 6 << 9);
+    }
+
+    #[test]
+    fn matches_scalar_unpack_root_pack() {
+        let mut rng = rand::thread_rng();
+        for value_bits in 2..=16_u32 {
+            let root_bits = value_bits.div_ceil(2);
+            for len in 0..64 {
+                let data: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+                let count = len * 8 / value_bits as usize;
+
+                let mut expected: Vec<u8> = (0..(count * root_bits as usize).div_ceil(8))
+                    .map(|_| rng.gen())
+                    .collect();
+                let mut out = expected.clone();
+                for i in 0..count {
+                    let value = get_bits(&data, i * value_bits as usize, value_bits);
+                    let root = crate::original::UnsignedIsqrt::isqrt(value);
+                    set_bits(&mut expected, i * root_bits as usize, root_bits, root);
+                }
+
+                isqrt_bitpacked(&data, value_bits, &mut out);
+                assert_eq!(
+                    out, expected,
+                    "{value_bits}-bit values should round-trip: {data:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_narrow() {
+        isqrt_bitpacked(&[0], 1, &mut [0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn output_too_short() {
+        isqrt_bitpacked(&[0; 4], 8, &mut [0]);
+    }
+}
+
+#[cfg(feature = "algo-karatsuba")]
+mod square_distance {
+    use crate::square_distance::{square_distance, square_distance_histogram};
+
+    const S: u64 = u32::MAX as u64;
+
+    #[test]
+    fn test_square_distance() {
+        for (n, distance) in [
+            (0, 0),
+            (1, 0),
+            (2, 1),
+            (3, 1),
+            (4, 0),
+            (6, 2),
+            (7, 2),
+            (12, 3),
+            (13, 3),
+            // The largest perfect square, the points midway between it and 2^64, and the input just below 2^64.
+            (S * S, 0),
+            (S * S + S, S),
+            (S * S + S + 1, S),
+            (u64::MAX, 1),
+        ] {
+            assert_eq!(
+                square_distance(n),
+                distance,
+                "{n} should be {distance} away from the nearest perfect square."
+            );
+        }
+    }
+
+    #[test]
+    fn test_square_distance_histogram() {
+        let xs = [
+            // Distance 0.
+            0,
+            1,
+            1 << 40,
+            // Distance 1.
+            2,
+            (1 << 40) + 1,
+            // Distances 2 and 3.
+            6,
+            13,
+            // Distances 4 through 7.
+            20,
+            (1 << 40) - 5,
+            // Distance 2^32 - 1.
+            S * S + S,
+            // Distance 1, as 2^64 is a perfect square.
+            u64::MAX,
+        ];
+
+        let mut expected = [0; 64];
+        expected[0] = 3;
+        expected[1] = 3;
+        expected[2] = 2;
+        expected[3] = 2;
+        expected[32] = 1;
+        assert_eq!(square_distance_histogram(&xs), expected);
+    }
+}
+
+#[cfg(any(
+    all(feature = "algo-floating-karatsuba", not(feature = "no-float")),
+    feature = "algo-karatsuba",
+    feature = "algo-karatsuba-2"
+))]
+macro_rules! isqrt_rem_tests {
+    ($module:ident ; $($UnsignedT:ident),+) => {
+        mod $module {
+            $(
+                #[test]
+                fn $UnsignedT() {
+                    use crate::$module::UnsignedIsqrt;
+
+                    // Check the first and last 128 values along with values around each power of two, which covers every
+                    // precondition shift.
+                    for n in (0..=127)
+                        .chain($UnsignedT::MAX - 127..=$UnsignedT::MAX)
+                        .chain((0..$UnsignedT::BITS).map(|exponent| (1 << exponent) - 1))
+                        .chain((0..$UnsignedT::BITS).map(|exponent| 1 << exponent))
+                        .chain((0..$UnsignedT::BITS).map(|exponent| (1 << exponent) + 1))
+                        .chain((0..$UnsignedT::BITS).map(|exponent| $UnsignedT::MAX >> exponent))
+                    {
+                        let s = UnsignedIsqrt::isqrt(n);
+                        assert_eq!(
+                            UnsignedIsqrt::isqrt_rem(n),
+                            (s, n - s * s),
+                            "`{n}.isqrt_rem()` should be `({s}, {})`.",
+                            n - s * s,
+                        );
+                    }
+                }
+            )*
+        }
+    };
+}
+
+mod isqrt_rem {
+    #[cfg(all(feature = "algo-floating-karatsuba", not(feature = "no-float")))]
+    isqrt_rem_tests!(floating_point_and_karatsuba; u8, u16, u32, u64, u128);
+    #[cfg(feature = "algo-karatsuba")]
+    isqrt_rem_tests!(karatsuba; u8, u16, u32, u64, u128);
+    #[cfg(feature = "algo-karatsuba-2")]
+    isqrt_rem_tests!(karatsuba_2; u8, u16, u32, u64, u128);
+}
+
+// Every 16-bit square root is the base case of the wider ones, so check them all, whichever base case is compiled in.
+#[cfg(feature = "algo-karatsuba")]
+#[test]
+#[cfg(not(miri))]
+fn karatsuba_16_bit_base_case() {
+    use crate::karatsuba::UnsignedIsqrt;
+
+    for n in 0..=u16::MAX {
+        let (s, r) = UnsignedIsqrt::isqrt_rem(n);
+        let (s, r, n) = (s as u32, r as u32, n as u32);
+        assert!(
+            s * s <= n && n < (s + 1) * (s + 1) && r == n - s * s,
+            "`{n}.isqrt_rem()` returned `({s}, {r})`."
+        );
+    }
+}
+
+#[cfg(any(
+    all(feature = "algo-floating-karatsuba", not(feature = "no-float")),
+    feature = "algo-karatsuba",
+    feature = "algo-karatsuba-2"
+))]
+macro_rules! remainder_bound_properties {
+    ($module:ident ; $($UnsignedT:ident),+) => {
+        mod $module {
+            $(
+                #[test]
+                fn $UnsignedT() {
+                    use crate::$module::UnsignedIsqrt;
+
+                    // A remainder of `2 * s + 1` or more would mean `(s + 1)^2 <= n`, so `s` is a full step too low.
+                    // `2 * s + 1` only overflows if `s` does, in which case any remainder is below it.
+                    fn remainder_below_next_square(n: $UnsignedT) -> bool {
+                        let (s, r) = UnsignedIsqrt::isqrt_rem(n);
+                        s.checked_mul(2)
+                            .and_then(|twice_s| twice_s.checked_add(1))
+                            .map_or(true, |bound| r < bound)
+                            && s * s + r == n
+                    }
+
+                    quickcheck::quickcheck(remainder_below_next_square as fn($UnsignedT) -> bool);
+                }
+            )*
+        }
+    };
+}
+
+// The properties run on random inputs, which Miri would take far too long over.
+#[cfg(not(miri))]
+mod remainder_bound {
+    #[cfg(all(feature = "algo-floating-karatsuba", not(feature = "no-float")))]
+    remainder_bound_properties!(floating_point_and_karatsuba; u8, u16, u32, u64, u128, usize);
+    #[cfg(feature = "algo-karatsuba")]
+    remainder_bound_properties!(karatsuba; u8, u16, u32, u64, u128, usize);
+    #[cfg(feature = "algo-karatsuba-2")]
+    remainder_bound_properties!(karatsuba_2; u8, u16, u32, u64, u128, usize);
+
+    // The public middle stages of `karatsuba_2`, fed the root and remainder of the top half. Their correction of a root
+    // that's one too high is only taken for some inputs, which the random ones reach.
+    #[cfg(feature = "algo-karatsuba-2")]
+    mod karatsuba_2_stages {
+        use crate::karatsuba_2::*;
+
+        macro_rules! stage_properties {
+            ($($name:ident: $middle_stage:ident, $UnsignedT:ident, $HalfT:ident);+) => {
+                $(
+                    #[test]
+                    fn $name() {
+                        fn remainder_below_next_square(n: $UnsignedT) -> bool {
+                            // The stages need one of the top two bits set.
+                            let n = if n.leading_zeros() >= 2 { n | 1 << ($UnsignedT::BITS - 2) } else { n };
+                            let (top_s, top_r) = UnsignedIsqrt::isqrt_rem((n >> $HalfT::BITS) as $HalfT);
+                            let (s, r) = $middle_stage(n, top_s, top_r);
+                            r <= 2 * s && s * s + r == n
+                        }
+
+                        quickcheck::quickcheck(remainder_below_next_square as fn($UnsignedT) -> bool);
+                    }
+                )+
+            };
+        }
+
+        stage_properties!(
+            middle_stage_u16: karatsuba_middle_stage_u16, u16, u8;
+            middle_stage_u32: karatsuba_middle_stage_u32, u32, u16;
+            middle_stage_u64: karatsuba_middle_stage_u64, u64, u32;
+            middle_stage_u128: karatsuba_middle_stage_u128, u128, u64
+        );
+    }
+}
+
+#[cfg(feature = "algo-original")]
+mod swar {
+    use crate::original::UnsignedIsqrt;
+    use crate::swar::{isqrt_two_u32, isqrt_u8x4};
+    use rand::Rng;
+
+    fn check_two_u32(hi: u32, lo: u32) {
+        let packed = ((hi as u64) << 32) | lo as u64;
+        let expected = ((UnsignedIsqrt::isqrt(hi) as u64) << 32) | UnsignedIsqrt::isqrt(lo) as u64;
+        assert_eq!(
+            isqrt_two_u32(packed),
+            expected,
+            "`isqrt_two_u32({packed:#018x})` should have the square roots of {hi} and {lo}."
+        );
+    }
+
+    #[test]
+    fn test_isqrt_two_u32() {
+        let edges = (0..=127)
+            .chain(u32::MAX - 127..=u32::MAX)
+            .chain((0..u32::BITS).map(|exponent| (1 << exponent) - 1))
+            .chain((0..u32::BITS).map(|exponent| 1 << exponent))
+            .collect::<Vec<_>>();
+        for &hi in &edges {
+            for &lo in &edges {
+                check_two_u32(hi, lo);
+            }
+        }
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..1_000_000 {
+            check_two_u32(rng.gen(), rng.gen());
+        }
+    }
+
+    #[test]
+    fn test_isqrt_u8x4() {
+        // Every byte value in every lane, with the other lanes cycling through different values.
+        for n in 0..=u8::MAX {
+            for lane in 0..4 {
+                let mut bytes = [n.wrapping_mul(37), n.wrapping_add(101), !n, n];
+                bytes.rotate_left(lane);
+                let packed = u32::from_le_bytes(bytes);
+                let expected = u32::from_le_bytes(bytes.map(UnsignedIsqrt::isqrt));
+                assert_eq!(
+                    isqrt_u8x4(packed),
+                    expected,
+                    "`isqrt_u8x4({packed:#010x})` should have the square roots of each byte."
+                );
+            }
+        }
+    }
+}
+
+#[cfg(all(
+    feature = "runtime-self-check",
+    feature = "algo-floating-karatsuba",
+    feature = "algo-original",
+    not(feature = "no-float")
+))]
+mod self_check {
+    use crate::self_check::sweep;
+
+    #[test]
+    fn correct_backends_pass() {
+        assert!(sweep(8, |n| crate::original::UnsignedIsqrt::isqrt(n as u8)
+            as u128));
+        assert!(sweep(
+            16,
+            |n| crate::original::UnsignedIsqrt::isqrt(n as u16) as u128
+        ));
+        assert!(sweep(
+            32,
+            |n| crate::original::UnsignedIsqrt::isqrt(n as u32) as u128
+        ));
+        assert!(sweep(
+            64,
+            |n| crate::original::UnsignedIsqrt::isqrt(n as u64) as u128
+        ));
+        assert!(sweep(128, crate::original::UnsignedIsqrt::isqrt));
+    }
+
+    #[test]
+    fn broken_backends_fail() {
+        // Off by one just below perfect squares, as a floating-point square root without correction would be.
+        assert!(!sweep(64, |n| ((n as f64).sqrt() + 0.5) as u128));
+        // Off by one only for a single large perfect square.
+        assert!(!sweep(64, |n| {
+            let s = crate::original::UnsignedIsqrt::isqrt(n as u64) as u128;
+            if n == (1 << 62) {
+                s - 1
+            } else {
+                s
+            }
+        }));
+    }
+
+    #[test]
+    fn first_call_checks() {
+        use crate::floating_point_and_karatsuba::UnsignedIsqrt;
+
+        assert_eq!(UnsignedIsqrt::isqrt(1_u64 << 62), 1 << 31);
+    }
+}
+
+#[cfg(feature = "algo-karatsuba")]
+mod isqrt_unsigned {
+    use crate::karatsuba::SignedIsqrt;
+
+    macro_rules! isqrt_unsigned_tests {
+        ($($SignedT:ident $RootT:ident),+) => {
+            $(
+                #[test]
+                fn $SignedT() {
+                    // The square root of the maximum value fits in the root type.
+                    let max_root: $RootT = SignedIsqrt::isqrt_unsigned($SignedT::MAX).unwrap();
+                    assert_eq!(max_root as $SignedT, SignedIsqrt::isqrt($SignedT::MAX));
+
+                    for n in ($SignedT::MIN..=$SignedT::MIN + 127)
+                        .chain(-128..=127)
+                        .chain($SignedT::MAX - 127..=$SignedT::MAX)
+                    {
+                        assert_eq!(
+                            SignedIsqrt::isqrt_unsigned(n),
+                            SignedIsqrt::checked_isqrt(n).map(|sqrt| sqrt as $RootT),
+                            "`({n}).isqrt_unsigned()` should match `({n}).checked_isqrt()`."
+                        );
+                    }
+                }
+            )*
+        };
+    }
+
+    isqrt_unsigned_tests!(i8 u8, i16 u8, i32 u16, i64 u32, i128 u64);
+}
+
+#[cfg(feature = "algo-karatsuba")]
+mod round_isqrt {
+    use crate::karatsuba::UnsignedIsqrt;
+
+    macro_rules! round_isqrt_tests {
+        ($($UnsignedT:ident),+) => {
+            $(
+                #[test]
+                fn $UnsignedT() {
+                    let max_sqrt: $UnsignedT = UnsignedIsqrt::isqrt($UnsignedT::MAX);
+                    for sqrt in (0..=15).chain(max_sqrt - 15..max_sqrt) {
+                        let square: $UnsignedT = sqrt * sqrt;
+
+                        // `sqrt^2 + sqrt` is the last number that rounds down.
+                        assert_eq!(UnsignedIsqrt::round_isqrt(square), sqrt);
+                        assert_eq!(UnsignedIsqrt::round_isqrt(square + sqrt), sqrt);
+                        assert_eq!(
+                            UnsignedIsqrt::round_isqrt(square + sqrt + 1),
+                            sqrt + 1,
+                            "`({}).round_isqrt()` should round up.",
+                            square + sqrt + 1
+                        );
+                    }
+
+                    assert_eq!(
+                        UnsignedIsqrt::round_isqrt($UnsignedT::MAX),
+                        UnsignedIsqrt::isqrt($UnsignedT::MAX) + 1
+                    );
+
+                    // The remainder is at most `2 * sqrt`, so it can only exceed a nonzero square root.
+                    for sqrt in (1..=15).chain(max_sqrt - 14..max_sqrt) {
+                        let square: $UnsignedT = sqrt * sqrt;
+
+                        // A remainder equal to the square root is the last one that doesn't round up.
+                        assert_eq!(UnsignedIsqrt::isqrt_round_info(square + sqrt), (sqrt, false));
+                        assert_eq!(
+                            UnsignedIsqrt::isqrt_round_info(square + sqrt + 1),
+                            (sqrt, true),
+                            "`({}).isqrt_round_info()` should report a remainder above the root.",
+                            square + sqrt + 1
+                        );
+                    }
+                }
+            )*
+        };
+    }
+
+    round_isqrt_tests!(u8, u16, u32, u64, u128);
+}
+
+#[cfg(feature = "algo-karatsuba")]
+#[test]
+fn ceil_isqrt_near_maximum() {
+    use crate::karatsuba::UnsignedIsqrt;
+
+    assert_eq!(UnsignedIsqrt::ceil_isqrt(u8::MAX), 16);
+    assert_eq!(UnsignedIsqrt::ceil_isqrt(u16::MAX), 256);
+    assert_eq!(UnsignedIsqrt::ceil_isqrt(u128::MAX), 1 << 64);
+}
+
+#[cfg(all(feature = "algo-karatsuba", feature = "algo-original"))]
+#[test]
+fn build_root_table() {
+    const TABLE: [u16; 4096] = crate::karatsuba::build_root_table();
+
+    for (n, &sqrt_n) in TABLE.iter().enumerate() {
+        assert_eq!(
+            sqrt_n,
+            crate::original::UnsignedIsqrt::isqrt(n as u16),
+            "Entry {n} of the root table should be the square root of {n}."
+        );
+    }
+}
+
+mod int_isqrt {
+    // Checks `IntIsqrt` against `SignedIsqrt` and `UnsignedIsqrt`, which must be imported where this is used.
+    macro_rules! matches_traits {
+        ($($SignedT:ident $UnsignedT:ident),+) => {
+            $(
+                for n in (0..=127).chain([$SignedT::MAX - 1, $SignedT::MAX]) {
+                    assert_isqrt(n, SignedIsqrt::checked_isqrt(n));
+                    assert_isqrt(n as $UnsignedT, Some(UnsignedIsqrt::isqrt(n as $UnsignedT)));
+                }
+                assert_isqrt($UnsignedT::MAX, Some(UnsignedIsqrt::isqrt($UnsignedT::MAX)));
+                assert_isqrt(-1 as $SignedT, None);
+                assert_isqrt($SignedT::MIN, None);
+            )+
+        };
+    }
+
+    macro_rules! int_isqrt_tests {
+        ($($module:ident),+) => {
+            $(
+                mod $module {
+                    use crate::$module::{IntIsqrt, SignedIsqrt, UnsignedIsqrt};
+                    use core::fmt::Debug;
+                    use core::num::{Saturating, Wrapping};
+                    use core::ops::{Add, Mul};
+
+                    // Generic over any integer with one bound, which is the point of `IntIsqrt`.
+                    fn roots<T: IntIsqrt + Copy>(ns: &[T]) -> Vec<Option<T>> {
+                        ns.iter().map(|&n| n.checked_isqrt()).collect()
+                    }
+
+                    fn assert_isqrt<T: IntIsqrt + Copy + Debug + PartialEq>(n: T, expected: Option<T>) {
+                        assert_eq!(IntIsqrt::checked_isqrt(n), expected, "`{n:?}.checked_isqrt()` should be {expected:?}.");
+                        if let Some(expected) = expected {
+                            assert_eq!(IntIsqrt::isqrt(n), expected, "`{n:?}.isqrt()` should be {expected:?}.");
+                        }
+                    }
+
+                    #[test]
+                    fn matches_signed_and_unsigned_traits() {
+                        matches_traits!(i8 u8, i16 u16, i32 u32, i64 u64, i128 u128, isize usize);
+                    }
+
+                    #[test]
+                    fn generic_over_signedness_and_wrappers() {
+                        assert_eq!(roots(&[0_u64, 15, 16]), [Some(0), Some(3), Some(4)]);
+                        assert_eq!(roots(&[-1_i64, 15, 16]), [None, Some(3), Some(4)]);
+                        assert_eq!(roots(&[Wrapping(-4_i8), Wrapping(4)]), [None, Some(Wrapping(2))]);
+                        assert_eq!(roots(&[Saturating(99_u16)]), [Some(Saturating(9))]);
+                    }
+
+                    // The remainder rebuilds `n` from its root, and is at most `2 * sqrt`, or `sqrt + 1` would fit.
+                    fn check_rem<T>(n: T)
+                    where
+                        T: IntIsqrt + Copy + Debug + PartialOrd + Add<Output = T> + Mul<Output = T>,
+                    {
+                        let (sqrt, rem) = IntIsqrt::checked_isqrt_rem(n)
+                            .unwrap_or_else(|| panic!("`{n:?}.checked_isqrt_rem()` should be `Some`."));
+                        assert_eq!(Some(sqrt), IntIsqrt::checked_isqrt(n), "`{n:?}.checked_isqrt_rem()` has the wrong root.");
+                        assert!(sqrt * sqrt + rem == n, "`{n:?}.checked_isqrt_rem()` is ({sqrt:?}, {rem:?}).");
+                        assert!(rem <= sqrt + sqrt, "`{n:?}.checked_isqrt_rem()` is ({sqrt:?}, {rem:?}).");
+                    }
+
+                    #[test]
+                    fn checked_isqrt_rem() {
+                        for n in (0..=1_000).chain([i64::MAX - 1, i64::MAX]) {
+                            check_rem(n);
+                            check_rem(n as u64);
+                        }
+                        check_rem(u64::MAX);
+
+                        assert_eq!(IntIsqrt::checked_isqrt_rem(-1_i64), None);
+                        assert_eq!(IntIsqrt::checked_isqrt_rem(i64::MIN), None);
+                        assert_eq!(IntIsqrt::checked_isqrt_rem(Wrapping(10_i8)), Some((Wrapping(3), Wrapping(1))));
+                    }
+
+                    #[test]
+                    #[cfg_attr(not(feature = "no-panic-signed"), should_panic)]
+                    fn negative() {
+                        let _ = IntIsqrt::isqrt(-1_i32);
+                    }
+                }
+            )+
+        };
+    }
+
+    #[cfg(all(feature = "algo-floating", not(feature = "no-float")))]
+
+    int_isqrt_tests!(floating_point);
+
+    #[cfg(all(feature = "algo-floating-karatsuba", not(feature = "no-float")))]
+
+    int_isqrt_tests!(floating_point_and_karatsuba);
+
+    int_isqrt_tests!(heron);
+
+    #[cfg(feature = "algo-karatsuba")]
+
+    int_isqrt_tests!(karatsuba);
+
+    #[cfg(feature = "algo-karatsuba-2")]
+
+    int_isqrt_tests!(karatsuba_2);
+
+    int_isqrt_tests!(newton);
+
+    #[cfg(feature = "algo-original")]
+
+    int_isqrt_tests!(original);
+
+    int_isqrt_tests!(table);
+}
+
+#[cfg(feature = "algo-karatsuba")]
+mod default {
+    use crate::default::{isqrt_u128, isqrt_u16, isqrt_u32, isqrt_u64, isqrt_u8};
+    use crate::karatsuba::{SignedIsqrt, UnsignedIsqrt};
+    use crate::DefaultIsqrt;
+    use rand::Rng;
+
+    // Checks the facade against `karatsuba`, which must agree with whichever module each width forwards to.
+    macro_rules! default_tests {
+        ($($SignedT:ident $UnsignedT:ident),+) => {
+            $(
+                mod $UnsignedT {
+                    use super::*;
+
+                    #[test]
+                    fn matches_karatsuba() {
+                        let mut rng = rand::thread_rng();
+                        for n in (0..=127)
+                            .chain([$SignedT::MAX - 1, $SignedT::MAX])
+                            .chain((0..1000).map(|_| rng.gen::<$SignedT>()))
+                        {
+                            assert_eq!(crate::checked_isqrt(n), SignedIsqrt::checked_isqrt(n), "{n}");
+                            let n = n as $UnsignedT;
+                            assert_eq!(crate::isqrt(n), UnsignedIsqrt::isqrt(n), "{n}");
+                            assert_eq!(DefaultIsqrt::checked_isqrt(n), Some(UnsignedIsqrt::isqrt(n)), "{n}");
+                        }
+                        assert_eq!(crate::isqrt($UnsignedT::MAX), UnsignedIsqrt::isqrt($UnsignedT::MAX));
+                    }
+
+                    #[test]
+                    #[cfg_attr(not(feature = "no-panic-signed"), should_panic)]
+                    fn negative() {
+                        assert_eq!(crate::isqrt(-1 as $SignedT), 0);
+                    }
+                }
+            )+
+        };
+    }
+
+    default_tests!(i8 u8, i16 u16, i32 u32, i64 u64, i128 u128, isize usize);
+
+    #[test]
+    fn width_functions() {
+        const ROOTS: (u8, u16, u128) = (
+            isqrt_u8(u8::MAX),
+            isqrt_u16(u16::MAX),
+            isqrt_u128(u128::MAX),
+        );
+        assert_eq!(ROOTS, (15, 255, u64::MAX as u128));
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..1000 {
+            let n: u128 = rng.gen();
+            assert_eq!(isqrt_u8(n as u8), UnsignedIsqrt::isqrt(n as u8), "{n}");
+            assert_eq!(isqrt_u16(n as u16), UnsignedIsqrt::isqrt(n as u16), "{n}");
+            assert_eq!(isqrt_u32(n as u32), UnsignedIsqrt::isqrt(n as u32), "{n}");
+            assert_eq!(isqrt_u64(n as u64), UnsignedIsqrt::isqrt(n as u64), "{n}");
+            assert_eq!(isqrt_u128(n), UnsignedIsqrt::isqrt(n), "{n}");
+        }
+    }
+}
+
+#[cfg(feature = "algo-karatsuba")]
+mod isqrt_max {
+    use core::num::{NonZeroU16, Wrapping};
+
+    #[test]
+    fn known_values() {
+        use crate::heron::{SignedIsqrt, UnsignedIsqrt};
+
+        assert_eq!(<u64 as UnsignedIsqrt>::ISQRT_MAX, 4_294_967_295);
+        assert_eq!(<i8 as SignedIsqrt>::ISQRT_MAX, 11);
+        assert_eq!(<i128 as SignedIsqrt>::ISQRT_MAX, 13_043_817_825_332_782_212);
+        assert_eq!(<NonZeroU16 as UnsignedIsqrt>::ISQRT_MAX.get(), 255);
+        assert_eq!(
+            <Wrapping<u32> as UnsignedIsqrt>::ISQRT_MAX,
+            Wrapping(65_535)
+        );
+    }
+
+    // The `assume` bounds all come from `IsqrtMax`, which uses its own bit-by-bit method, so check it against `std`
+    // rather than against any of the modules.
+    #[test]
+    fn matches_std() {
+        use crate::IsqrtMax;
+
+        macro_rules! check {
+            ($($type:ty),+) => {
+                $(
+                    assert_eq!(
+                        <$type as IsqrtMax>::ISQRT_MAX,
+                        <$type>::MAX.isqrt(),
+                        concat!("`", stringify!($type), "::ISQRT_MAX` should be the square root of `MAX`.")
+                    );
+                )+
+            };
+        }
+
+        check!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+    }
+
+    #[test]
+    fn sizes_a_buffer() {
+        use crate::karatsuba::UnsignedIsqrt;
+
+        let buffer = [0_u8; <u16 as UnsignedIsqrt>::ISQRT_MAX as usize + 1];
+        assert_eq!(buffer.len(), 256);
+    }
+}
+
+mod wrapper {
+    use core::num::{Saturating, Wrapping};
+
+    macro_rules! wrapper_tests {
+        ($($module:ident),+) => {
+            $(
+                mod $module {
+                    use super::*;
+                    use crate::$module::{SignedIsqrt, UnsignedIsqrt};
+
+                    #[test]
+                    fn wrapping() {
+                        assert_eq!(UnsignedIsqrt::isqrt(Wrapping(100_u32)), Wrapping(10_u32));
+                        assert_eq!(UnsignedIsqrt::ceil_isqrt(Wrapping(101_u64)), Wrapping(11_u64));
+                        assert_eq!(UnsignedIsqrt::isqrt_exact(Wrapping(17_u8)), None);
+                        assert_eq!(UnsignedIsqrt::isqrt_with_exactness(Wrapping(16_u8)), (Wrapping(4_u8), true));
+                        assert_eq!(UnsignedIsqrt::isqrt_floor(Wrapping(99_u16)), Wrapping(9_u16));
+                        assert_eq!(
+                            UnsignedIsqrt::overflowing_next_perfect_square(Wrapping(225_u8)),
+                            (Wrapping(0_u8), true)
+                        );
+                        assert_eq!(UnsignedIsqrt::isqrt(Wrapping(usize::MAX)), Wrapping(UnsignedIsqrt::isqrt(usize::MAX)));
+                        assert_eq!(SignedIsqrt::checked_isqrt(Wrapping(100_i128)), Some(Wrapping(10_i128)));
+                        assert_eq!(SignedIsqrt::checked_isqrt(Wrapping(-100_i16)), None);
+                    }
+
+                    #[test]
+                    fn saturating() {
+                        assert_eq!(UnsignedIsqrt::isqrt(Saturating(100_u32)), Saturating(10_u32));
+                        assert_eq!(UnsignedIsqrt::checked_next_perfect_square(Saturating(u16::MAX)), None);
+                        assert_eq!(SignedIsqrt::checked_isqrt(Saturating(i8::MAX)), Some(Saturating(11_i8)));
+                        // Like the plain impls, a negative argument has no square root.
+                        assert_eq!(SignedIsqrt::checked_isqrt(Saturating(-1_isize)), None);
+                        assert_eq!(SignedIsqrt::try_isqrt(Saturating(-1_i64)), Err(crate::NegativeInputError));
+                        assert_eq!(SignedIsqrt::isqrt_or(Saturating(-1_i32), Saturating(7)), Saturating(7));
+                        assert_eq!(SignedIsqrt::isqrt_or_zero(Wrapping(-1_i32)), Wrapping(0));
+                    }
+                }
+            )+
+        };
+    }
+
+    #[cfg(all(feature = "algo-floating", not(feature = "no-float")))]
+
+    wrapper_tests!(floating_point);
+
+    #[cfg(all(feature = "algo-floating-karatsuba", not(feature = "no-float")))]
+
+    wrapper_tests!(floating_point_and_karatsuba);
+
+    wrapper_tests!(heron);
+
+    #[cfg(feature = "algo-karatsuba")]
+
+    wrapper_tests!(karatsuba);
+
+    #[cfg(feature = "algo-karatsuba-2")]
+
+    wrapper_tests!(karatsuba_2);
+
+    wrapper_tests!(newton);
+
+    #[cfg(feature = "algo-original")]
+
+    wrapper_tests!(original);
+
+    wrapper_tests!(table);
+
+    #[test]
+    #[cfg(feature = "algo-karatsuba")]
+    fn wrapping_karatsuba_extras() {
+        use crate::karatsuba::{SignedIsqrt, UnsignedIsqrt};
+
+        assert_eq!(
+            UnsignedIsqrt::isqrt_rem(Wrapping(17_u32)),
+            (Wrapping(4), Wrapping(1))
+        );
+        assert_eq!(UnsignedIsqrt::round_isqrt(Wrapping(21_u32)), Wrapping(5));
+        assert_eq!(
+            SignedIsqrt::isqrt_unsigned(Wrapping(i64::MAX)),
+            Some(3_037_000_499_u32)
+        );
+    }
+}
+
+mod nonzero {
+    use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+    macro_rules! nonzero_tests {
+        ($module:ident ; $($NonZeroT:ident),+) => {
+            mod $module {
+                use super::*;
+                use crate::$module::UnsignedIsqrt;
+
+                // `NonZeroUsize` goes through `usize`, which forwards to the fixed-width type of the pointer width.
+                #[test]
+                #[cfg(target_pointer_width = "64")]
+                fn usize_on_64_bit_targets() {
+                    let n = NonZeroUsize::new(1000).unwrap();
+                    assert_eq!(UnsignedIsqrt::isqrt(n).get(), 31);
+                    assert_eq!(
+                        UnsignedIsqrt::isqrt(NonZeroUsize::MAX).get() as u64,
+                        UnsignedIsqrt::isqrt(NonZeroU64::MAX).get()
+                    );
+                }
+
+                #[test]
+                fn small_values() {
+                    assert_eq!(UnsignedIsqrt::isqrt(NonZeroU32::new(2).unwrap()), NonZeroU32::new(1).unwrap());
+                    assert_eq!(UnsignedIsqrt::ceil_isqrt(NonZeroU32::new(2).unwrap()), NonZeroU32::new(2).unwrap());
+                    assert!(UnsignedIsqrt::is_perfect_square(NonZeroU32::new(1).unwrap()));
+                }
+
+                #[test]
+                fn matches_inner_value() {
+                    $(
+                        for n in (1..=127).chain($NonZeroT::MAX.get() - 127..=$NonZeroT::MAX.get()) {
+                            let nonzero_n = $NonZeroT::new(n).unwrap();
+                            assert_eq!(UnsignedIsqrt::isqrt(nonzero_n).get(), UnsignedIsqrt::isqrt(n));
+                            assert_eq!(UnsignedIsqrt::ceil_isqrt(nonzero_n).get(), UnsignedIsqrt::ceil_isqrt(n));
+                            assert_eq!(
+                                UnsignedIsqrt::is_perfect_square(nonzero_n),
+                                UnsignedIsqrt::is_perfect_square(n)
+                            );
+                            assert_eq!(
+                                UnsignedIsqrt::prev_perfect_square(nonzero_n).get(),
+                                UnsignedIsqrt::prev_perfect_square(n)
+                            );
+                            assert_eq!(
+                                UnsignedIsqrt::isqrt_exact(nonzero_n).map($NonZeroT::get),
+                                UnsignedIsqrt::isqrt_exact(n)
+                            );
+                            let (sqrt, exact) = UnsignedIsqrt::isqrt_with_exactness(nonzero_n);
+                            assert_eq!((sqrt.get(), exact), UnsignedIsqrt::isqrt_with_exactness(n));
+                            assert_eq!(
+                                UnsignedIsqrt::checked_next_perfect_square(nonzero_n).map($NonZeroT::get),
+                                UnsignedIsqrt::checked_next_perfect_square(n)
+                            );
+                            let (square, overflowed) = UnsignedIsqrt::overflowing_next_perfect_square(nonzero_n);
+                            assert_eq!(overflowed, UnsignedIsqrt::overflowing_next_perfect_square(n).1);
+                            assert_eq!(square.get(), UnsignedIsqrt::checked_next_perfect_square(n).unwrap_or(1));
+                        }
+                    )*
+                }
+            }
+        };
+    }
+
+    #[cfg(all(feature = "algo-floating", not(feature = "no-float")))]
+    nonzero_tests!(floating_point; NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize);
+    #[cfg(all(feature = "algo-floating-karatsuba", not(feature = "no-float")))]
+    nonzero_tests!(floating_point_and_karatsuba; NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize);
+    #[cfg(feature = "algo-karatsuba")]
+    nonzero_tests!(karatsuba; NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize);
+    #[cfg(feature = "algo-karatsuba-2")]
+    nonzero_tests!(karatsuba_2; NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize);
+    nonzero_tests!(heron; NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize);
+    nonzero_tests!(newton; NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize);
+    nonzero_tests!(table; NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize);
+    #[cfg(feature = "algo-original")]
+    nonzero_tests!(original; NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize);
+
+    // The Karatsuba-based modules' `isqrt_rem` returns the inner type's remainder, as it can be zero.
+    #[cfg(any(
+        feature = "algo-karatsuba",
+        feature = "algo-karatsuba-2",
+        all(feature = "algo-floating-karatsuba", not(feature = "no-float"))
+    ))]
+    mod isqrt_rem {
+        use super::*;
+
+        macro_rules! isqrt_rem_tests {
+            ($module:ident ; $($NonZeroT:ident),+) => {
+                #[test]
+                fn $module() {
+                    use crate::$module::UnsignedIsqrt;
+
+                    $(
+                        for n in (1..=127).chain($NonZeroT::MAX.get() - 127..=$NonZeroT::MAX.get()) {
+                            let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem($NonZeroT::new(n).unwrap());
+                            assert_eq!((sqrt.get(), remainder), UnsignedIsqrt::isqrt_rem(n));
+                        }
+                    )+
+                }
+            };
+        }
+
+        #[cfg(all(feature = "algo-floating-karatsuba", not(feature = "no-float")))]
+        isqrt_rem_tests!(floating_point_and_karatsuba; NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize);
+        #[cfg(feature = "algo-karatsuba")]
+        isqrt_rem_tests!(karatsuba; NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize);
+        #[cfg(feature = "algo-karatsuba-2")]
+        isqrt_rem_tests!(karatsuba_2; NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize);
+    }
+
+    #[test]
+    #[cfg(feature = "algo-karatsuba")]
+    fn karatsuba_rounding_and_scaling() {
+        use crate::karatsuba::UnsignedIsqrt;
+
+        for n in 1..=u16::MAX {
+            let nonzero_n = NonZeroU16::new(n).unwrap();
+            assert_eq!(
+                UnsignedIsqrt::round_isqrt(nonzero_n).get(),
+                UnsignedIsqrt::round_isqrt(n)
+            );
+            let (sqrt, at_least_halfway) = UnsignedIsqrt::isqrt_round_info(nonzero_n);
+            assert_eq!(
+                (sqrt.get(), at_least_halfway),
+                UnsignedIsqrt::isqrt_round_info(n)
+            );
+            for output_bits in 0..=u16::BITS {
+                assert_eq!(
+                    UnsignedIsqrt::isqrt_scaled(nonzero_n, output_bits),
+                    UnsignedIsqrt::isqrt_scaled(n, output_bits)
+                );
+            }
+        }
+
+        // Scaled roots can be zero, so they're the inner type.
+        assert_eq!(UnsignedIsqrt::isqrt_scaled(NonZeroU16::MIN, 0), 0_u16);
+    }
+}
+
+mod select {
+    use crate::select::*;
+    use rand::Rng;
+
+    macro_rules! select_tests {
+        ($($name:ident: $algo:ident),+) => {
+            $(
+                #[test]
+                fn $name() {
+                    let mut rng = rand::thread_rng();
+                    for _ in 0..1000 {
+                        let n: u64 = rng.gen();
+                        assert_eq!(n.isqrt_with::<$algo>(), n.isqrt(), "{n}");
+                        assert_eq!((n as u8).isqrt_with::<$algo>(), (n as u8).isqrt(), "{n}");
+                        assert_eq!(isqrt_with::<$algo, _>(n as u128), (n as u128).isqrt(), "{n}");
+                        assert_eq!((n as i32 & i32::MAX).isqrt_with::<$algo>(), (n as i32 & i32::MAX).isqrt(), "{n}");
+                    }
+                    assert_eq!(usize::MAX.isqrt_with::<$algo>(), usize::MAX.isqrt());
+                }
+            )+
+        };
+    }
+
+    #[cfg(feature = "algo-original")]
+    select_tests!(original: ORIGINAL);
+    #[cfg(all(feature = "algo-floating", not(feature = "no-float")))]
+    select_tests!(floating_point: FLOATING_POINT);
+    #[cfg(feature = "algo-karatsuba")]
+    select_tests!(karatsuba: KARATSUBA);
+    #[cfg(feature = "algo-karatsuba-2")]
+    select_tests!(karatsuba_2: KARATSUBA_2);
+    #[cfg(all(feature = "algo-floating-karatsuba", not(feature = "no-float")))]
+    select_tests!(floating_point_and_karatsuba: FLOATING_POINT_AND_KARATSUBA);
+    select_tests!(heron: HERON, newton: NEWTON, table: TABLE);
+    #[cfg(feature = "libgmp")]
+    select_tests!(libgmp: LIBGMP);
+
+    #[test]
+    #[cfg_attr(not(feature = "no-panic-signed"), should_panic)]
+    fn negative() {
+        assert_eq!(isqrt_with::<HERON, _>(-1_i16), 0);
+    }
+}
+
+// `u8`'s largest perfect square is 225, so the next one after it, 256, overflows.
+#[test]
+fn overflowing_next_perfect_square_u8() {
+    use crate::heron::UnsignedIsqrt;
+
+    assert_eq!(
+        UnsignedIsqrt::overflowing_next_perfect_square(195_u8),
+        (196, false)
+    );
+    assert_eq!(
+        UnsignedIsqrt::overflowing_next_perfect_square(224_u8),
+        (225, false)
+    );
+    for n in 225..=u8::MAX {
+        assert_eq!(UnsignedIsqrt::overflowing_next_perfect_square(n), (0, true));
+    }
+}
+
+#[cfg(feature = "algo-karatsuba")]
+#[test]
+fn parse_and_isqrt() {
+    use crate::karatsuba::parse_and_isqrt;
+    use core::num::IntErrorKind;
+
+    assert_eq!(parse_and_isqrt("0"), Ok(0));
+    assert_eq!(parse_and_isqrt("1000000"), Ok(1000));
+    assert_eq!(parse_and_isqrt("+99"), Ok(9));
+    assert_eq!(
+        parse_and_isqrt("18446744073709551615"),
+        Ok(u64::from(u32::MAX))
+    );
+
+    assert_eq!(
+        parse_and_isqrt("18446744073709551616").unwrap_err().kind(),
+        &IntErrorKind::PosOverflow
+    );
+    assert_eq!(
+        parse_and_isqrt("-4").unwrap_err().kind(),
+        &IntErrorKind::InvalidDigit
+    );
+    assert_eq!(
+        parse_and_isqrt("4.0").unwrap_err().kind(),
+        &IntErrorKind::InvalidDigit
+    );
+    assert_eq!(
+        parse_and_isqrt(" 4").unwrap_err().kind(),
+        &IntErrorKind::InvalidDigit
+    );
+    assert_eq!(
+        parse_and_isqrt("").unwrap_err().kind(),
+        &IntErrorKind::Empty
+    );
+}
+
+#[cfg(feature = "algo-karatsuba")]
+#[test]
+fn prelude() {
+    use crate::prelude::*;
+
+    assert_eq!(DefaultUnsignedIsqrt::isqrt(99_u64), 9);
+    assert_eq!(DefaultSignedIsqrt::checked_isqrt(-1_i32), None);
+    assert_eq!(99_u8.ceil_isqrt(), 10);
+    assert_eq!(99_usize.round_isqrt(), 10);
+    assert_eq!((-4_i128).checked_ceil_isqrt(), None);
+    assert!(49_isize.is_perfect_square());
+}
+
+#[cfg(all(feature = "algo-floating", not(feature = "no-float")))]
+#[test]
+fn floating_point_u64_near_exact_limit() {
+    use crate::floating_point::UnsignedIsqrt;
+
+    // `u64` values below 2^52 skip the correction step, so check values on both sides of it, along with the perfect
+    // squares near it and their neighbors.
+    const LIMIT: u64 = 1 << 52;
+
+    let neighbors_of_squares = ((1 << 26) - 1024..=(1 << 26) + 1024)
+        .flat_map(|sqrt| [sqrt * sqrt - 1, sqrt * sqrt, sqrt * sqrt + 1]);
+    for n in (LIMIT - 65_536..=LIMIT + 65_536).chain(neighbors_of_squares) {
+        let sqrt_n = UnsignedIsqrt::isqrt(n);
+        assert!(
+            sqrt_n * sqrt_n <= n && n < (sqrt_n + 1) * (sqrt_n + 1),
+            "The integer square root of {n} should not be {sqrt_n} (the current return value of `{n}.isqrt()`)."
+        );
+    }
+}
+
+#[cfg(feature = "num-traits")]
+mod prim_int {
+    use crate::prim_int::isqrt;
+    use num_traits::PrimInt;
+
+    // Uses nothing but the `PrimInt` bound.
+    fn floor_hypotenuse<T: PrimInt>(a: T, b: T) -> T {
+        isqrt(a * a + b * b)
+    }
+
+    #[test]
+    fn generic_callers() {
+        assert_eq!(floor_hypotenuse(3_u8, 4), 5);
+        assert_eq!(floor_hypotenuse(5_i16, 12), 13);
+        assert_eq!(floor_hypotenuse(1_u32, 1), 1);
+        assert_eq!(floor_hypotenuse(20_i64, 21), 29);
+        assert_eq!(floor_hypotenuse(1_u128 << 60, 0), 1 << 60);
+        assert_eq!(floor_hypotenuse(3_usize, 3), 4);
+    }
+
+    #[test]
+    fn matches_karatsuba() {
+        use crate::karatsuba::{SignedIsqrt, UnsignedIsqrt};
+
+        for n in (0..=1024).chain(u64::MAX - 1024..=u64::MAX) {
+            assert_eq!(isqrt(n), UnsignedIsqrt::isqrt(n));
+        }
+        for n in (0..=1024).chain(i32::MAX - 1024..=i32::MAX) {
+            assert_eq!(isqrt(n), SignedIsqrt::isqrt(n));
+        }
+        assert_eq!(isqrt(u128::MAX), UnsignedIsqrt::isqrt(u128::MAX));
+        assert_eq!(isqrt(i8::MAX), SignedIsqrt::isqrt(i8::MAX));
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "no-panic-signed"), should_panic)]
+    fn negative() {
+        assert_eq!(isqrt(-1_i64), 0);
+    }
+}
+
+#[cfg(all(feature = "algo-floating", feature = "std", not(feature = "no-float")))]
+mod batch {
+    use crate::batch::{isqrt_slice_u32, isqrt_slice_u64};
+    use crate::karatsuba::UnsignedIsqrt;
+    use rand::{thread_rng, Rng};
+
+    macro_rules! batch_tests {
+        ($($isqrt_slice:ident $isqrt_in_place:ident $UnsignedT:ident),+) => {
+            $(
+                #[test]
+                fn $UnsignedT() {
+                    // An odd length leaves elements over after the vectors.
+                    let src: Vec<$UnsignedT> = (0..=127)
+                        .chain($UnsignedT::MAX - 127..=$UnsignedT::MAX)
+                        .chain((0..$UnsignedT::BITS).map(|exponent| (1 << exponent) - 1))
+                        .chain((0..1_000).map(|_| thread_rng().gen()))
+                        .chain([1])
+                        .collect();
+                    let mut dst = vec![0; src.len()];
+
+                    crate::batch::$isqrt_slice(&src, &mut dst);
+
+                    for (&n, &sqrt_n) in src.iter().zip(&dst) {
+                        assert_eq!(
+                            sqrt_n,
+                            UnsignedIsqrt::isqrt(n),
+                            "The batch integer square root of {n} should not be {sqrt_n}."
+                        );
+                    }
+
+                    let mut in_place = src.clone();
+                    crate::batch::$isqrt_in_place(&mut in_place);
+                    assert_eq!(in_place, dst, "In-place square roots should match out-of-place ones.");
+                }
+            )*
+        };
+    }
+
+    batch_tests!(
+        isqrt_slice_u8 isqrt_in_place_u8 u8,
+        isqrt_slice_u16 isqrt_in_place_u16 u16,
+        isqrt_slice_u32 isqrt_in_place_u32 u32,
+        isqrt_slice_u64 isqrt_in_place_u64 u64
+    );
+
+    #[test]
+    fn generic() {
+        fn check<
+            T: crate::batch::SimdIsqrt + UnsignedIsqrt + Copy + Default + core::fmt::Debug + PartialEq,
+        >(
+            src: &[T],
+        ) {
+            let mut dst = vec![T::default(); src.len()];
+            crate::batch::isqrt_slice(src, &mut dst);
+
+            let expected: Vec<T> = src.iter().map(|&n| UnsignedIsqrt::isqrt(n)).collect();
+            assert_eq!(dst, expected);
+
+            let mut in_place = src.to_vec();
+            crate::batch::isqrt_in_place(&mut in_place);
+            assert_eq!(in_place, expected);
+        }
+
+        // Longer than every type's vectors, and one more, so each one has a leftover element.
+        let src: Vec<u64> = (0..=64).map(|_| thread_rng().gen()).collect();
+        check(&src.iter().map(|&n| n as u8).collect::<Vec<_>>());
+        check(&src.iter().map(|&n| n as u16).collect::<Vec<_>>());
+        check(&src.iter().map(|&n| n as u32).collect::<Vec<_>>());
+        check(&src);
+    }
+
+    #[test]
+    fn u64_perfect_squares_and_neighbors() {
+        let src: Vec<u64> = (u32::MAX as u64 - 1_000..=u32::MAX as u64)
+            .chain((1 << 26) - 1_000..=(1 << 26) + 1_000)
+            .flat_map(|sqrt| [sqrt * sqrt - 1, sqrt * sqrt, sqrt * sqrt + 1])
+            .collect();
+        let mut dst = vec![0; src.len()];
+
+        isqrt_slice_u64(&src, &mut dst);
+
+        for (&n, &sqrt_n) in src.iter().zip(&dst) {
+            assert_eq!(
+                sqrt_n,
+                UnsignedIsqrt::isqrt(n),
+                "The batch integer square root of {n} should not be {sqrt_n}."
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic = "but have lengths 3 and 2"]
+    fn mismatched_lengths() {
+        isqrt_slice_u32(&[1, 4, 9], &mut [0; 2]);
+    }
+
+    #[test]
+    fn try_mismatched_lengths() {
+        use crate::batch::{try_isqrt_slice, SliceLenMismatch};
+
+        let mut dst = [7_u64; 2];
+        assert_eq!(
+            try_isqrt_slice(&[1, 4, 9], &mut dst),
+            Err(SliceLenMismatch {
+                src_len: 3,
+                dst_len: 2
+            })
+        );
+        assert_eq!(
+            dst,
+            [7, 7],
+            "Nothing should be written when the lengths differ."
+        );
+        assert_eq!(
+            try_isqrt_slice::<u8>(&[], &mut [0]).unwrap_err().to_string(),
+            "source and destination of batch integer square root must have the same length, but have lengths 0 and 1"
+        );
+    }
+
+    // Checks the versions written with each architecture's intrinsics.
+    macro_rules! arch_tests {
+        ($($target_arch:literal $arch:ident),+) => {
+            $(
+                #[cfg(target_arch = $target_arch)]
+                mod $arch {
+                    use super::*;
+                    use crate::batch::$arch::{isqrt_in_place_u32, isqrt_slice_u32};
+
+                    #[test]
+                    #[cfg(not(miri))]
+                    fn u32() {
+                        // Values at and above 2^31 are converted differently from those below by AVX2, and one less
+                        // than a perfect square is closest to rounding up. An odd length leaves elements over after the
+                        // vectors.
+                        let src: Vec<u32> = (0..=127)
+                            .chain(u32::MAX - 127..=u32::MAX)
+                            .chain((1 << 31) - 127..=(1 << 31) + 127)
+                            .chain((0..u32::BITS).map(|exponent| (1 << exponent) - 1))
+                            .chain((1..=u16::MAX as u32).step_by(7).flat_map(|sqrt| [sqrt * sqrt - 1, sqrt * sqrt]))
+                            .chain((0..1_000).map(|_| thread_rng().gen()))
+                            .chain([1])
+                            .collect();
+                        let mut dst = vec![0; src.len()];
+
+                        isqrt_slice_u32(&src, &mut dst);
+
+                        for (&n, &sqrt_n) in src.iter().zip(&dst) {
+                            assert_eq!(
+                                sqrt_n,
+                                UnsignedIsqrt::isqrt(n),
+                                "The {} batch integer square root of {n} should not be {sqrt_n}.",
+                                stringify!($arch)
+                            );
+                        }
+
+                        let mut in_place = src.clone();
+                        isqrt_in_place_u32(&mut in_place);
+                        assert_eq!(in_place, dst, "In-place square roots should match out-of-place ones.");
+                    }
+
+                    #[test]
+                    #[should_panic]
+                    fn mismatched_lengths() {
+                        isqrt_slice_u32(&[1, 4, 9], &mut [0; 2]);
+                    }
+                }
+            )+
+        };
+    }
+
+    arch_tests!("x86_64" avx2, "aarch64" neon);
+}
+
+#[cfg(feature = "algo-karatsuba")]
+mod iter {
+    use crate::iter::IsqrtIterator;
+    use crate::karatsuba::UnsignedIsqrt;
+    use core::num::Wrapping;
+
+    #[test]
+    fn matches_isqrt() {
+        let src: Vec<u64> = (0..=1_000).chain(u64::MAX - 1_000..=u64::MAX).collect();
+        let expected: Vec<u64> = src.iter().map(|&n| UnsignedIsqrt::isqrt(n)).collect();
+
+        assert_eq!(src.iter().copied().isqrt().collect::<Vec<_>>(), expected);
+        assert_eq!(
+            src.iter().copied().isqrt().rev().collect::<Vec<_>>(),
+            expected.iter().copied().rev().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn forwards_length() {
+        let mut roots = [0_u8, 1, 4, 9, 16, 255].into_iter().isqrt();
+        assert_eq!(roots.len(), 6);
+        assert_eq!(roots.size_hint(), (6, Some(6)));
+
+        assert_eq!(roots.next(), Some(0));
+        assert_eq!(roots.next_back(), Some(15));
+        assert_eq!(roots.len(), 4);
+        assert_eq!(roots.collect::<Vec<_>>(), [1, 2, 3, 4]);
+
+        assert_eq!((0_u32..).isqrt().size_hint(), (usize::MAX, None));
+        assert_eq!(
+            (0_u32..10).filter(|n| n % 2 == 0).isqrt().size_hint(),
+            (0, Some(10))
+        );
+    }
+
+    #[test]
+    fn wrapping_items() {
+        let roots: Vec<Wrapping<u32>> = (1..=9).map(Wrapping).isqrt().collect();
+        assert_eq!(
+            roots.iter().map(|sqrt| sqrt.0).collect::<Vec<_>>(),
+            [1, 1, 1, 2, 2, 2, 2, 2, 3]
+        );
+    }
+}
+
+#[cfg(feature = "algo-karatsuba")]
+mod references {
+    use crate::prelude::{DefaultSignedIsqrt, DefaultUnsignedIsqrt};
+
+    #[test]
+    fn method_calls_on_references() {
+        let ns = [0_u64, 1, 10, 100, u64::MAX];
+        assert_eq!(
+            ns.iter().map(|n| n.ceil_isqrt()).collect::<Vec<_>>(),
+            [0, 1, 4, 10, 1 << 32]
+        );
+        assert_eq!(
+            ns.iter()
+                .copied()
+                .map(DefaultUnsignedIsqrt::isqrt)
+                .collect::<Vec<_>>(),
+            [0, 1, 3, 10, u32::MAX as u64]
+        );
+
+        for (n, ceil_sqrt) in [(&-4_i32, None), (&0, Some(0)), (&10, Some(4))] {
+            assert_eq!(n.checked_ceil_isqrt(), ceil_sqrt);
+        }
+        let square = &100_u64;
+        assert!(square.is_perfect_square());
+    }
+}
+
+#[cfg(feature = "algo-karatsuba")]
+mod wide {
+    use crate::wide::isqrt_u256;
+    use rand::Rng;
+
+    // Returns `n^2` as a (high, low) pair.
+    fn square(n: u128) -> (u128, u128) {
+        const MASK: u128 = u64::MAX as u128;
+
+        let (n_hi, n_lo) = (n >> 64, n & MASK);
+        let lo_lo = n_lo * n_lo;
+        let cross = n_hi * n_lo;
+        let hi_hi = n_hi * n_hi;
+
+        // `n^2 = hi_hi * 2^128 + 2 * cross * 2^64 + lo_lo`.
+        let (lo, carry) = lo_lo.overflowing_add(cross << 65);
+        let hi = hi_hi + (cross >> 63) + carry as u128;
+        (hi, lo)
+    }
+
+    fn check(hi: u128, lo: u128) {
+        let sqrt = isqrt_u256(hi, lo);
+        assert!(
+            square(sqrt) <= (hi, lo),
+            "`isqrt_u256({hi}, {lo})` should be lower than {sqrt}."
+        );
+        assert!(
+            sqrt == u128::MAX || (hi, lo) < square(sqrt + 1),
+            "`isqrt_u256({hi}, {lo})` should be higher than {sqrt}."
+        );
+    }
+
+    #[test]
+    fn square_helper() {
+        assert_eq!(
+            square(u64::MAX as u128),
+            (0, (u64::MAX as u128) * (u64::MAX as u128))
+        );
+        assert_eq!(square(1 << 64), (1, 0));
+        assert_eq!(square(u128::MAX), (u128::MAX - 1, 1));
+    }
+
+    #[test]
+    fn maximum() {
+        assert_eq!(isqrt_u256(u128::MAX, u128::MAX), u128::MAX);
+    }
+
+    #[test]
+    fn perfect_squares() {
+        let mut rng = rand::thread_rng();
+        let roots = (1..=1024)
+            .chain(u128::MAX - 1024..=u128::MAX)
+            .chain((0..u128::BITS).map(|exponent| 1 << exponent))
+            .chain((0..u128::BITS).map(|exponent| u128::MAX >> exponent))
+            .chain((0..100_000).map(|_| rng.gen()));
+
+        for root in roots {
+            let (hi, lo) = square(root);
+            assert_eq!(
+                isqrt_u256(hi, lo),
+                root,
+                "`isqrt_u256` of the square of {root} should be {root}."
+            );
+
+            // One less than the square.
+            let (below_hi, below_lo) = if lo == 0 {
+                (hi - 1, u128::MAX)
+            } else {
+                (hi, lo - 1)
+            };
+            assert_eq!(
+                isqrt_u256(below_hi, below_lo),
+                root - 1,
+                "`isqrt_u256` of one less than the square of {root} should be {}.",
+                root - 1
+            );
+        }
+    }
+
+    #[test]
+    fn random_values() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100_000 {
+            check(rng.gen(), rng.gen());
+            check(rng.gen::<u128>() >> rng.gen_range(0..128), rng.gen());
+        }
+        for lo in (0..=1024).chain(u128::MAX - 1024..=u128::MAX) {
+            check(0, lo);
+            check(1, lo);
+            check(u128::MAX, lo);
+        }
+    }
+}
+
+#[cfg(feature = "algo-karatsuba")]
+mod triangular {
+    use crate::triangular::triangular_root;
+
+    // The largest `n` whose triangular number fits in a `u64`.
+    const MAX_N: u64 = 6_074_000_999;
+
+    fn triangular(n: u64) -> u64 {
+        ((n as u128 * (n as u128 + 1)) >> 1) as u64
+    }
+
+    #[test]
+    fn known_triangular_numbers() {
+        for (t, n) in [
+            (0, 0),
+            (1, 1),
+            (3, 2),
+            (6, 3),
+            (10, 4),
+            (5050, 100),
+            (18_446_744_070_963_499_500, MAX_N),
+        ] {
+            assert_eq!(
+                triangular_root(t),
+                Some(n),
+                "{t} should be the triangular number of {n}."
+            );
+        }
+
+        for n in (0..=10_000).chain(MAX_N - 10_000..=MAX_N) {
+            let t = triangular(n);
+            assert_eq!(
+                triangular_root(t),
+                Some(n),
+                "{t} should be the triangular number of {n}."
+            );
+        }
+    }
+
+    #[test]
+    fn non_triangular_numbers() {
+        for n in (0..=10_000).chain(MAX_N - 10_000..MAX_N) {
+            // Everything strictly between consecutive triangular numbers, sampled at both ends.
+            let (t, next_t) = (triangular(n), triangular(n + 1));
+            for non_triangular in [t + 1, next_t - 1] {
+                if non_triangular != t && non_triangular != next_t {
+                    assert_eq!(
+                        triangular_root(non_triangular),
+                        None,
+                        "{non_triangular} shouldn't be triangular."
+                    );
+                }
+            }
+        }
+
+        assert_eq!(triangular_root(2), None);
+        assert_eq!(triangular_root(u64::MAX), None);
+        assert_eq!(triangular_root(triangular(MAX_N) + 1), None);
+    }
+}
+
+#[cfg(feature = "exhaustive-tests")]
+mod exhaustive_u32 {
+    macro_rules! exhaustive_u32_tests {
+        ($($module:ident),+) => {
+            $(
+                #[test]
+                fn $module() {
+                    use crate::$module::UnsignedIsqrt;
+                    use rayon::prelude::*;
+
+                    let wrong = (0..=u32::MAX).into_par_iter().find_any(|&n| {
+                        let s = UnsignedIsqrt::isqrt(n) as u64;
+                        !(s * s <= n as u64 && (n as u64) < (s + 1) * (s + 1))
+                    });
+                    if let Some(n) = wrong {
+                        panic!("`{}`'s `u32` square root of {n} is wrong.", stringify!($module));
+                    }
+                }
+            )+
+        };
+    }
+
+    #[cfg(all(feature = "algo-floating", not(feature = "no-float")))]
+
+    exhaustive_u32_tests!(floating_point);
+
+    #[cfg(all(feature = "algo-floating-karatsuba", not(feature = "no-float")))]
+
+    exhaustive_u32_tests!(floating_point_and_karatsuba);
+
+    exhaustive_u32_tests!(heron);
+
+    #[cfg(feature = "algo-karatsuba")]
+
+    exhaustive_u32_tests!(karatsuba);
+
+    #[cfg(feature = "algo-karatsuba-2")]
+
+    exhaustive_u32_tests!(karatsuba_2);
+
+    exhaustive_u32_tests!(newton);
+
+    #[cfg(feature = "algo-original")]
+
+    exhaustive_u32_tests!(original);
+
+    exhaustive_u32_tests!(table);
+    #[cfg(feature = "libgmp")]
+    exhaustive_u32_tests!(libgmp);
+}
+
+// Runs `floating_point_and_karatsuba`'s `u64` square root with the SSE rounding mode set toward positive infinity, which
+// rounds both the conversion to `f64` and the square root up.
+#[cfg(all(
+    target_arch = "x86_64",
+    not(miri),
+    feature = "algo-floating-karatsuba",
+    not(feature = "no-float")
+))]
+mod rounding_mode {
+    use crate::floating_point_and_karatsuba::UnsignedIsqrt;
+    use core::arch::asm;
+    use rand::Rng;
+
+    const ROUNDING_CONTROL: u32 = 0b11 << 13;
+    const ROUND_UP: u32 = 0b10 << 13;
+
+    fn mxcsr() -> u32 {
+        let mut csr = 0_u32;
+        // SAFETY: `stmxcsr` only writes the four bytes of `csr`.
+        unsafe { asm!("stmxcsr [{}]", in(reg) &mut csr, options(nostack)) };
+        csr
+    }
+
+    fn set_mxcsr(csr: u32) {
+        // SAFETY: `ldmxcsr` only reads the four bytes of `csr`, and only rounding control is ever changed.
+        unsafe { asm!("ldmxcsr [{}]", in(reg) &csr, options(nostack, readonly)) };
+    }
+
+    // Restores the original rounding mode even if an assertion fails.
+    struct RestoreMxcsr(u32);
+
+    impl Drop for RestoreMxcsr {
+        fn drop(&mut self) {
+            set_mxcsr(self.0);
+        }
+    }
+
+    #[test]
+    fn toward_positive_infinity() {
+        let mut rng = rand::thread_rng();
+        // The largest inputs that take the floating-point path are just below `(2^32 - 2)^2`.
+        let max_sqrt = (1_u64 << 32) - 1;
+        let inputs: Vec<u64> = (max_sqrt - 10_000..=max_sqrt)
+            .flat_map(|sqrt| [sqrt * sqrt - 1, sqrt * sqrt, sqrt * sqrt + 1])
+            .chain([u64::MAX, (1 << 53) - 1, 1 << 53, (1 << 53) + 1])
+            .chain((0..10_000).map(|_| rng.gen::<u64>()))
+            .collect();
+
+        let original = mxcsr();
+        let _restore = RestoreMxcsr(original);
+        set_mxcsr((original & !ROUNDING_CONTROL) | ROUND_UP);
+
+        for n in inputs {
+            let sqrt = UnsignedIsqrt::isqrt(core::hint::black_box(n)) as u128;
+            assert!(
+                sqrt * sqrt <= n as u128 && (n as u128) < (sqrt + 1) * (sqrt + 1),
+                "`{n}.isqrt()` returned {sqrt} with the rounding mode set toward positive infinity."
+            );
+        }
+    }
+}
+
+#[cfg(feature = "algo-karatsuba")]
+mod negative_input_error {
+    use crate::karatsuba::SignedIsqrt;
+    use crate::NegativeInputError;
+
+    #[test]
+    fn message_matches_panic() {
+        assert_eq!(
+            NegativeInputError.to_string(),
+            "argument of integer square root must be non-negative"
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-panic-signed"))]
+    #[should_panic = "argument of integer square root must be non-negative"]
+    fn panic_message() {
+        let _ = SignedIsqrt::isqrt(-1_i64);
+    }
+
+    #[test]
+    fn propagates_with_question_mark() {
+        fn sum_of_roots(a: i64, b: i64) -> Result<i64, Box<dyn std::error::Error>> {
+            Ok(SignedIsqrt::try_isqrt(a)? + SignedIsqrt::try_isqrt(b)?)
+        }
+
+        assert_eq!(sum_of_roots(16, 25).unwrap(), 9);
+        assert!(sum_of_roots(16, -25)
+            .unwrap_err()
+            .downcast_ref::<NegativeInputError>()
+            .is_some());
+    }
+}
+
+#[cfg(not(miri))]
+mod proptests {
+    use proptest::prelude::*;
+
+    // Checks the postcondition of `isqrt`, `s * s <= n < (s + 1)^2`, where `(s + 1)^2` may overflow. The squares are
+    // checked so that a wrong result fails the property instead of panicking.
+    macro_rules! postcondition_tests {
+        ($($module:ident),+) => {
+            $(
+                mod $module {
+                    use super::*;
+                    use crate::$module::{SignedIsqrt, UnsignedIsqrt};
+
+                    postcondition_tests!(@types i8 u8, i16 u16, i32 u32, i64 u64, i128 u128, isize usize);
+                }
+            )+
+        };
+        (@types $($SignedT:ident $UnsignedT:ident),+) => {
+            proptest! {
+                $(
+                    #[test]
+                    fn $UnsignedT(n: $UnsignedT) {
+                        let sqrt = UnsignedIsqrt::isqrt(n);
+                        prop_assert!(sqrt.checked_mul(sqrt).is_some_and(|square| square <= n), "`{}.isqrt()` is {}, which is too big.", n, sqrt);
+                        prop_assert!(
+                            (sqrt + 1).checked_mul(sqrt + 1).map_or(true, |next_square| n < next_square),
+                            "`{}.isqrt()` is {}, which is too small.",
+                            n,
+                            sqrt
+                        );
+                    }
+
+                    #[test]
+                    fn $SignedT(n: $SignedT) {
+                        match SignedIsqrt::checked_isqrt(n) {
+                            None => prop_assert!(n < 0, "`{}.checked_isqrt()` shouldn't be `None`.", n),
+                            Some(sqrt) => {
+                                prop_assert!(n >= 0, "`({}).checked_isqrt()` should be `None`.", n);
+                                prop_assert!(sqrt.checked_mul(sqrt).is_some_and(|square| square <= n), "`{}.checked_isqrt()` is {}, which is too big.", n, sqrt);
+                                prop_assert!(
+                                    (sqrt + 1).checked_mul(sqrt + 1).map_or(true, |next_square| n < next_square),
+                                    "`{}.checked_isqrt()` is {}, which is too small.",
+                                    n,
+                                    sqrt
+                                );
+                            }
+                        }
+                    }
+                )+
+            }
+        };
+    }
+
+    #[cfg(all(feature = "algo-floating", not(feature = "no-float")))]
+
+    postcondition_tests!(floating_point);
+
+    #[cfg(all(feature = "algo-floating-karatsuba", not(feature = "no-float")))]
+
+    postcondition_tests!(floating_point_and_karatsuba);
+
+    postcondition_tests!(heron);
+
+    #[cfg(feature = "algo-karatsuba")]
+
+    postcondition_tests!(karatsuba);
+
+    #[cfg(feature = "algo-karatsuba-2")]
+
+    postcondition_tests!(karatsuba_2);
+
+    postcondition_tests!(newton);
+
+    #[cfg(feature = "algo-original")]
+
+    postcondition_tests!(original);
+
+    postcondition_tests!(table);
+
+    #[cfg(all(
+        feature = "algo-floating",
+        feature = "algo-floating-karatsuba",
+        feature = "algo-karatsuba",
+        feature = "algo-karatsuba-2",
+        feature = "algo-original",
+        not(feature = "no-float")
+    ))]
+    mod cross_module {
+        use super::*;
+
+        // Checks that every module gives the same result as the first.
+        macro_rules! all_agree {
+            ($n:expr, $Trait:ident::$method:ident) => {
+                all_agree!(@modules $n, $Trait::$method; floating_point, floating_point_and_karatsuba, heron, karatsuba, karatsuba_2, newton, original, table)
+            };
+            (@modules $n:expr, $Trait:ident::$method:ident; $first_module:ident $(, $module:ident)+) => {{
+                let first_result = {
+                    use crate::$first_module::$Trait;
+                    $Trait::$method($n)
+                };
+                $(
+                    let result = {
+                        use crate::$module::$Trait;
+                        $Trait::$method($n)
+                    };
+                    prop_assert_eq!(
+                        result,
+                        first_result,
+                        "`{}` and `{}` disagree on the square root of {}.",
+                        stringify!($module),
+                        stringify!($first_module),
+                        $n
+                    );
+                )+
+            }};
+        }
+
+        macro_rules! cross_module_tests {
+            ($($SignedT:ident $UnsignedT:ident),+) => {
+                proptest! {
+                    $(
+                        #[test]
+                        fn $UnsignedT(n: $UnsignedT) {
+                            all_agree!(n, UnsignedIsqrt::isqrt);
+                        }
+
+                        #[test]
+                        fn $SignedT(n: $SignedT) {
+                            all_agree!(n, SignedIsqrt::checked_isqrt);
+                        }
+                    )+
+                }
+            };
+        }
+
+        cross_module_tests!(i8 u8, i16 u16, i32 u32, i64 u64, i128 u128, isize usize);
+    }
+}
+
+#[cfg(all(
+    feature = "algo-floating",
+    feature = "algo-floating-karatsuba",
+    feature = "algo-karatsuba",
+    feature = "algo-karatsuba-2",
+    feature = "algo-original",
+    not(feature = "no-float")
+))]
+mod cross_module {
+    use core::fmt::{Debug, Display};
+    use rand::Rng;
+
+    // Takes the square root of `$n` with every module, paired with the module's name.
+    macro_rules! results {
+        ($n:expr, $Trait:ident::$method:ident) => {
+            results!(@modules $n, $Trait::$method; floating_point, floating_point_and_karatsuba, heron, karatsuba, karatsuba_2, newton, original, table)
+        };
+        (@modules $n:expr, $Trait:ident::$method:ident; $($module:ident),+) => {
+            [$((stringify!($module), {
+                use crate::$module::$Trait;
+                $Trait::$method($n)
+            })),+]
+        };
+    }
+
+    fn assert_all_agree<N: Display, T: Debug + PartialEq>(n: N, results: &[(&str, T)]) {
+        let (first_module, first_result) = &results[0];
+        for (module, result) in &results[1..] {
+            assert_eq!(
+                result, first_result,
+                "`{module}` and `{first_module}` disagree on the square root of {n}."
+            );
+        }
+    }
+
+    macro_rules! cross_module_tests {
+        ($($SignedT:ident $UnsignedT:ident),+) => {
+            $(
+                #[test]
+                #[cfg(not(miri))]
+                fn $SignedT() {
+                    // The edge cases from `test_isqrt`, the minimum value and negatives, and random values.
+                    let mut rng = rand::thread_rng();
+                    for n in (0..=127)
+                        .chain($SignedT::MAX - 127..=$SignedT::MAX)
+                        .chain((0..$SignedT::BITS - 1).map(|exponent| (1 << exponent) - 1))
+                        .chain((0..$SignedT::BITS - 1).map(|exponent| 1 << exponent))
+                        .chain([$SignedT::MIN, -1])
+                        .chain((0..10_000).map(|_| rng.gen::<$SignedT>()))
+                    {
+                        assert_all_agree(n, &results!(n, SignedIsqrt::checked_isqrt));
+                    }
+                }
+
+                #[test]
+                #[cfg(not(miri))]
+                fn $UnsignedT() {
+                    // The edge cases from `test_isqrt`, and random values.
+                    let mut rng = rand::thread_rng();
+                    for n in (0..=127)
+                        .chain($UnsignedT::MAX - 127..=$UnsignedT::MAX)
+                        .chain((0..$UnsignedT::BITS).map(|exponent| (1 << exponent) - 1))
+                        .chain((0..$UnsignedT::BITS).map(|exponent| 1 << exponent))
+                        .chain((0..10_000).map(|_| rng.gen::<$UnsignedT>()))
+                    {
+                        assert_all_agree(n, &results!(n, UnsignedIsqrt::isqrt));
+                    }
+                }
+            )+
+        };
+    }
+
+    cross_module_tests!(i8 u8, i16 u16, i32 u32, i64 u64, i128 u128, isize usize);
+}
@@ -0,0 +1,9 @@
+use crate::karatsuba::UnsignedIsqrt;
+
+/// Returns the `n` for which `t == n * (n + 1) / 2`, or `None` if `t` isn't a triangular number.
+pub fn triangular_root(t: u64) -> Option<u64> {
+    // `t` is triangular exactly when `8 * t + 1` is a perfect square, which is then `(2 * n + 1)^2`. That can take 67
+    // bits, so compute it in a `u128`.
+    let (sqrt, remainder) = UnsignedIsqrt::isqrt_rem(8 * t as u128 + 1);
+    (remainder == 0).then(|| ((sqrt - 1) >> 1) as u64)
+}
@@ -0,0 +1,59 @@
+use crate::karatsuba::UnsignedIsqrt;
+
+/// Returns the integer square root of the 256-bit integer `hi * 2^128 + lo`.
+///
+/// This is one more level of the Karatsuba square root from the `karatsuba` module, using its 128-bit `isqrt_rem` for
+/// the high half. The square root always fits in a `u128`.
+pub fn isqrt_u256(hi: u128, lo: u128) -> u128 {
+    // Performs a Karatsuba square root.
+    // https://web.archive.org/web/20230511212802/https://inria.hal.science/inria-00072854v1/file/RR-3805.pdf
+
+    const HALF_BITS: u32 = u128::BITS;
+    const QUARTER_BITS: u32 = u128::BITS >> 1;
+    const EIGHTH_BITS: u32 = u128::BITS >> 2;
+
+    if hi == 0 {
+        return UnsignedIsqrt::isqrt(lo);
+    }
+
+    // Either the most-significant bit or its neighbor must be a one, so we shift left to make that happen.
+    let precondition_shift = hi.leading_zeros() & (HALF_BITS - 2);
+    let (hi, lo) = if precondition_shift == 0 {
+        (hi, lo)
+    } else {
+        (
+            (hi << precondition_shift) | (lo >> (HALF_BITS - precondition_shift)),
+            lo << precondition_shift,
+        )
+    };
+
+    let (s_prime, r_prime) = UnsignedIsqrt::isqrt_rem(hi);
+
+    // The numerator, `r_prime * 2^64 + (lo >> 64)`, can take 129 bits, as `r_prime` can be as big as `2 * s_prime`. The
+    // denominator takes at most 65 bits, so divide in two 32-bit steps, whose partial numerators fit in a `u128`.
+    let numerator_lo = lo >> QUARTER_BITS;
+    let denominator = s_prime << 1;
+
+    let partial = (r_prime << EIGHTH_BITS) | (numerator_lo >> EIGHTH_BITS);
+    let (q_hi, u_hi) = (partial / denominator, partial % denominator);
+    let partial = (u_hi << EIGHTH_BITS) | (numerator_lo & ((1 << EIGHTH_BITS) - 1));
+    let (q_lo, u) = (partial / denominator, partial % denominator);
+
+    let q = (q_hi << EIGHTH_BITS) + q_lo;
+
+    // Before the correction, `s` can be `2^128`, but the corrected square root fits, so wrapping gives the right result.
+    let mut s = (s_prime << QUARTER_BITS).wrapping_add(q);
+
+    // Compare `u * 2^64 + (lo mod 2^64)` with `q^2` as (high, low) pairs, as both can exceed 128 bits. `q` is at most
+    // `2^64 + 1`, so `q^2` overflows by less than one bit.
+    let lhs = (
+        u >> QUARTER_BITS,
+        (u << QUARTER_BITS) | (lo & ((1 << QUARTER_BITS) - 1)),
+    );
+    let (q_squared_lo, q_squared_overflowed) = q.overflowing_mul(q);
+    if lhs < (q_squared_overflowed as u128, q_squared_lo) {
+        s = s.wrapping_sub(1);
+    }
+
+    s >> (precondition_shift >> 1)
+}
@@ -0,0 +1,22 @@
+// Locks in the `#[must_use]` on the trait methods, so that `n.isqrt();` written in the hope of updating `n` in place
+// is reported.
+#[test]
+fn unused_square_roots_are_reported() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/unused_isqrt.rs");
+}
+
+// The traits are sealed, as their implementations make promises to the optimizer that outside ones couldn't keep.
+#[test]
+fn traits_are_sealed() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/sealed.rs");
+}
+
+// The `const` signed functions panic during constant evaluation, so a negative argument is a compile error.
+#[test]
+#[cfg(all(feature = "algo-original", not(feature = "no-panic-signed")))]
+fn negative_const_square_roots_are_reported() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/const_negative_isqrt.rs");
+}
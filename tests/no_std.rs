@@ -0,0 +1,21 @@
+use std::process::Command;
+
+// Without the `std` feature, the crate must be `no_std`, so embedded users don't pull in `std` through it. The
+// `no-std-check` crate uses it from a `no_std` binary with its own panic handler, which clashes with `std`'s if the
+// crate links `std`.
+#[test]
+#[cfg(not(miri))]
+fn builds_without_std() {
+    let status = Command::new(env!("CARGO"))
+        .args(["check", "--quiet", "--manifest-path"])
+        .arg(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/no-std-check/Cargo.toml"
+        ))
+        .status()
+        .expect("cargo should run");
+    assert!(
+        status.success(),
+        "the crate should build in a `no_std` binary"
+    );
+}
@@ -0,0 +1,7 @@
+use isqrt::original::isqrt_i32;
+
+const ROOT: i32 = isqrt_i32(-4);
+
+fn main() {
+    let _ = ROOT;
+}
@@ -0,0 +1,48 @@
+use isqrt::heron::UnsignedIsqrt;
+
+#[derive(Clone, Copy)]
+struct Wrapper(u32);
+
+impl UnsignedIsqrt for Wrapper {
+    const ISQRT_MAX: Self = Wrapper(65_535);
+
+    fn isqrt(self) -> Self {
+        Wrapper(UnsignedIsqrt::isqrt(self.0))
+    }
+
+    fn ceil_isqrt(self) -> Self {
+        Wrapper(UnsignedIsqrt::ceil_isqrt(self.0))
+    }
+
+    fn is_perfect_square(self) -> bool {
+        UnsignedIsqrt::is_perfect_square(self.0)
+    }
+
+    fn prev_perfect_square(self) -> Self {
+        Wrapper(UnsignedIsqrt::prev_perfect_square(self.0))
+    }
+
+    fn next_perfect_square(self) -> Self {
+        Wrapper(UnsignedIsqrt::next_perfect_square(self.0))
+    }
+
+    fn checked_next_perfect_square(self) -> Option<Self> {
+        UnsignedIsqrt::checked_next_perfect_square(self.0).map(Wrapper)
+    }
+
+    fn overflowing_next_perfect_square(self) -> (Self, bool) {
+        let (square, overflowed) = UnsignedIsqrt::overflowing_next_perfect_square(self.0);
+        (Wrapper(square), overflowed)
+    }
+
+    fn isqrt_exact(self) -> Option<Self> {
+        UnsignedIsqrt::isqrt_exact(self.0).map(Wrapper)
+    }
+
+    fn isqrt_with_exactness(self) -> (Self, bool) {
+        let (sqrt, exact) = UnsignedIsqrt::isqrt_with_exactness(self.0);
+        (Wrapper(sqrt), exact)
+    }
+}
+
+fn main() {}
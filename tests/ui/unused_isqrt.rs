@@ -0,0 +1,14 @@
+#![deny(unused_must_use)]
+
+// `heron`, like `newton` and `table`, is compiled with every set of features, so these method names are never unique to
+// one module, and rustc prints their full paths in every configuration.
+use isqrt::heron::{SignedIsqrt, UnsignedIsqrt};
+
+fn main() {
+    let n = 16_u32;
+    UnsignedIsqrt::isqrt(n);
+    UnsignedIsqrt::ceil_isqrt(n);
+
+    let m = -16_i32;
+    SignedIsqrt::checked_isqrt(m);
+}
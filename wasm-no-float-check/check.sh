@@ -0,0 +1,16 @@
+#!/bin/sh
+# Builds this crate for `wasm32-unknown-unknown` and fails if the module has any `f32` or `f64` instructions.
+#
+# Needs the `wasm32-unknown-unknown` target (`rustup target add wasm32-unknown-unknown`), `wasm-tools`
+# (`cargo install wasm-tools`), and a nightly toolchain for the `nightly` feature.
+set -eu
+
+cd "$(dirname "$0")"
+cargo +nightly build --release --target wasm32-unknown-unknown
+
+module=target/wasm32-unknown-unknown/release/isqrt_wasm_no_float_check.wasm
+if wasm-tools print "$module" | grep -E '\bf(32|64)\.'; then
+    echo "$module has floating-point instructions" >&2
+    exit 1
+fi
+echo "$module has no floating-point instructions"
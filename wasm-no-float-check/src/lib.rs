@@ -0,0 +1,32 @@
+#![no_std]
+
+use isqrt::floating_point_and_karatsuba::{SignedIsqrt, UnsignedIsqrt};
+
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
+
+macro_rules! exports {
+    ($($name:ident: $trait:ident, $type:ty),+) => {
+        $(
+            #[no_mangle]
+            pub extern "C" fn $name(n: $type) -> $type {
+                $trait::isqrt(n)
+            }
+        )+
+    };
+}
+
+exports!(
+    isqrt_u8: UnsignedIsqrt, u8,
+    isqrt_u16: UnsignedIsqrt, u16,
+    isqrt_u32: UnsignedIsqrt, u32,
+    isqrt_u64: UnsignedIsqrt, u64,
+    isqrt_u128: UnsignedIsqrt, u128,
+    isqrt_i8: SignedIsqrt, i8,
+    isqrt_i16: SignedIsqrt, i16,
+    isqrt_i32: SignedIsqrt, i32,
+    isqrt_i64: SignedIsqrt, i64,
+    isqrt_i128: SignedIsqrt, i128
+);